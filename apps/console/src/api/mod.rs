@@ -172,6 +172,21 @@ impl ThinWaistClient {
         self.send_plain(builder).await
     }
 
+    pub async fn list_ace_cycles<TQuery, TRes>(
+        &self,
+        query: &TQuery,
+        tenant_override: Option<&str>,
+    ) -> ClientResult<TRes>
+    where
+        TQuery: Serialize + ?Sized,
+        TRes: DeserializeOwned,
+    {
+        let builder = self
+            .request(Method::GET, "ace/cycles", tenant_override)?
+            .query(query);
+        self.send_plain(builder).await
+    }
+
     pub async fn get_cycle_snapshot<TRes>(
         &self,
         cycle_id: &str,
@@ -404,6 +419,107 @@ impl ThinWaistClient {
         self.send(builder).await
     }
 
+    /// 暂停自主延续会话，当前周期执行完毕后不再调度新周期
+    pub async fn post_autonomous_pause<TRes>(
+        &self,
+        tenant_id: &str,
+        session_id: &str,
+    ) -> ClientResult<ApiEnvelope<TRes>>
+    where
+        TRes: DeserializeOwned,
+    {
+        let path = format!("tenants/{tenant_id}/autonomous/sessions/{session_id}/pause");
+        let builder = self.request(Method::POST, &path, Some(tenant_id))?;
+        self.send(builder).await
+    }
+
+    /// 恢复已暂停的自主延续会话
+    pub async fn post_autonomous_resume<TRes>(
+        &self,
+        tenant_id: &str,
+        session_id: &str,
+    ) -> ClientResult<ApiEnvelope<TRes>>
+    where
+        TRes: DeserializeOwned,
+    {
+        let path = format!("tenants/{tenant_id}/autonomous/sessions/{session_id}/resume");
+        let builder = self.request(Method::POST, &path, Some(tenant_id))?;
+        self.send(builder).await
+    }
+
+    /// 向运行中的会话追加议程项
+    pub async fn post_autonomous_agenda<TReq, TRes>(
+        &self,
+        tenant_id: &str,
+        session_id: &str,
+        payload: &TReq,
+    ) -> ClientResult<ApiEnvelope<TRes>>
+    where
+        TReq: Serialize + ?Sized,
+        TRes: DeserializeOwned,
+    {
+        let path = format!("tenants/{tenant_id}/autonomous/sessions/{session_id}/agenda");
+        let builder = self
+            .request(Method::POST, &path, Some(tenant_id))?
+            .json(payload);
+        self.send(builder).await
+    }
+
+    /// 修改单个议程项（优先级、跳过）
+    pub async fn patch_autonomous_agenda_item<TReq, TRes>(
+        &self,
+        tenant_id: &str,
+        session_id: &str,
+        item_id: &str,
+        payload: &TReq,
+    ) -> ClientResult<ApiEnvelope<TRes>>
+    where
+        TReq: Serialize + ?Sized,
+        TRes: DeserializeOwned,
+    {
+        let path = format!("tenants/{tenant_id}/autonomous/sessions/{session_id}/agenda/{item_id}");
+        let builder = self
+            .request(Method::PATCH, &path, Some(tenant_id))?
+            .json(payload);
+        self.send(builder).await
+    }
+
+    /// 调整待执行议程项的顺序
+    pub async fn put_autonomous_agenda_order<TReq, TRes>(
+        &self,
+        tenant_id: &str,
+        session_id: &str,
+        payload: &TReq,
+    ) -> ClientResult<ApiEnvelope<TRes>>
+    where
+        TReq: Serialize + ?Sized,
+        TRes: DeserializeOwned,
+    {
+        let path = format!("tenants/{tenant_id}/autonomous/sessions/{session_id}/agenda/order");
+        let builder = self
+            .request(Method::PUT, &path, Some(tenant_id))?
+            .json(payload);
+        self.send(builder).await
+    }
+
+    /// 调整运行中会话的终止条件上限
+    pub async fn patch_autonomous_config<TReq, TRes>(
+        &self,
+        tenant_id: &str,
+        session_id: &str,
+        payload: &TReq,
+    ) -> ClientResult<ApiEnvelope<TRes>>
+    where
+        TReq: Serialize + ?Sized,
+        TRes: DeserializeOwned,
+    {
+        let path = format!("tenants/{tenant_id}/autonomous/sessions/{session_id}/config");
+        let builder = self
+            .request(Method::PATCH, &path, Some(tenant_id))?
+            .json(payload);
+        self.send(builder).await
+    }
+
     /// 获取场景栈状态
     pub async fn get_autonomous_scenario_stack<TRes>(
         &self,
@@ -506,6 +622,24 @@ impl ThinWaistClient {
         self.send(builder).await
     }
 
+    /// 按周期或指纹查询决策列表
+    pub async fn get_dfr_decisions<TQuery, TRes>(
+        &self,
+        tenant_id: &str,
+        query: Option<&TQuery>,
+    ) -> ClientResult<ApiEnvelope<TRes>>
+    where
+        TQuery: Serialize + ?Sized,
+        TRes: DeserializeOwned,
+    {
+        let path = format!("tenants/{tenant_id}/dfr/decisions");
+        let mut builder = self.request(Method::GET, &path, Some(tenant_id))?;
+        if let Some(q) = query {
+            builder = builder.query(q);
+        }
+        self.send(builder).await
+    }
+
     /// 获取决策指纹列表
     pub async fn get_dfr_fingerprints<TQuery, TRes>(
         &self,
@@ -2,12 +2,16 @@ use std::collections::HashMap;
 
 use dioxus::prelude::*;
 
-use crate::api::AwarenessQuery;
+use futures::StreamExt;
+use reqwest::StatusCode;
+use time::OffsetDateTime;
+
+use crate::api::{AwarenessQuery, ClientError, ClientResult, ThinWaistClient};
 use crate::models::{
-    AceCycleStatus, AceCycleSummary, AceLane, AwarenessEvent, AwarenessEventType,
-    CycleSnapshotView, OutboxMessageView,
+    AceCycleListQuery, AceCycleListResponse, AceCycleStatus, AceCycleSummary, AceLane,
+    AwarenessEvent, AwarenessEventType, CycleSnapshotView, OutboxMessageView,
 };
-use crate::state::{use_app_actions, use_app_state, AppActions, AppSignal};
+use crate::state::{use_app_actions, use_app_state, AceCycleFilter, AppActions, AppSignal};
 use crate::{API_CLIENT, APP_CONFIG};
 
 pub fn use_ace_cycles() {
@@ -19,6 +23,7 @@ pub fn use_ace_cycles() {
     let session = snapshot.session_id.clone();
     let tenant_for_details = tenant.clone();
     let selected_cycle_id = snapshot.ace.selected_cycle_id.clone();
+    let filter = snapshot.ace.filter.clone();
     drop(snapshot);
 
     let actions_for_cycles = actions.clone();
@@ -34,8 +39,8 @@ pub fn use_ace_cycles() {
     {
         let actions = actions.clone();
         let mut loader = cycles_loader;
-        use_effect(use_reactive!(|(tenant, session)| {
-            let _ = session;
+        use_effect(use_reactive!(|(tenant, session, filter)| {
+            let _ = (session, filter);
             if tenant.is_some() {
                 loader.restart();
             } else {
@@ -83,11 +88,25 @@ async fn load_ace_cycles(actions: AppActions, state: AppSignal) {
         None => {
             actions.set_ace_cycles(Vec::new());
             actions.select_ace_cycle(None);
+            actions.set_ace_listing(None, false);
             actions.set_ace_loading(false);
             return;
         }
     };
 
+    if let Some(cycles) = reload_ace_cycles(&actions, &state, &tenant).await {
+        actions.select_ace_cycle(cycles.first().map(|cycle| cycle.cycle_id.clone()));
+    }
+}
+
+/// 按当前筛选条件重新拉取第一页周期，不改变选中项；失败时错误已写入状态并返回 None
+pub(crate) async fn reload_ace_cycles(
+    actions: &AppActions,
+    state: &AppSignal,
+    tenant: &str,
+) -> Option<Vec<AceCycleSummary>> {
+    let filter = state.read().ace.filter.clone();
+
     actions.set_ace_loading(true);
     actions.set_ace_error(None);
 
@@ -96,94 +115,187 @@ async fn load_ace_cycles(actions: AppActions, state: AppSignal) {
         None => {
             actions.set_ace_error(Some("Thin-Waist 客户端未初始化".into()));
             actions.set_ace_loading(false);
-            return;
+            return None;
         }
     };
 
-    match client
-        .get_awareness_events::<_, Vec<AwarenessEvent>>(&tenant, &AwarenessQuery { limit: 200 })
-        .await
-    {
-        Ok(env) => {
-            if let Some(events) = env.data {
-                tracing::info!("load_ace_cycles: received {} events from API", events.len());
-                let mut grouped: HashMap<String, Vec<AwarenessEvent>> = HashMap::new();
-                for event in events {
-                    // Store cycle_id as numeric u64 string instead of Base36 for compatibility with backend
-                    let cycle_id = event.awareness_cycle_id.as_u64().to_string();
-                    grouped.entry(cycle_id).or_default().push(event);
-                }
-                tracing::info!("load_ace_cycles: grouped into {} cycles", grouped.len());
-
-                let mut summaries_with_ts: Vec<(i64, AceCycleSummary)> = grouped
-                    .into_iter()
-                    .map(|(cycle_id, mut items)| {
-                        items.sort_by_key(|evt| evt.occurred_at_ms);
-                        let latest_ts = items
-                            .iter()
-                            .map(|evt| evt.occurred_at_ms)
-                            .max()
-                            .unwrap_or_default();
-                        // 将 core model 的 AwarenessAnchor 转换为 Value
-                        let anchor = items
-                            .first()
-                            .and_then(|evt| serde_json::to_value(&evt.anchor).ok());
-                        let lane = detect_lane(&items);
-                        let status = detect_status(&items);
-
-                        tracing::info!(
-                            "Creating cycle summary: cycle_id={}, status={:?}, events={}",
-                            cycle_id,
-                            status,
-                            items.len()
-                        );
-
-                        (
-                            latest_ts,
-                            AceCycleSummary {
-                                cycle_id,
-                                lane,
-                                status,
-                                anchor,
-                                budget: None,
-                                latest_sync_point: None,
-                                pending_injections: Vec::new(),
-                                decision_path: None,
-                                metadata: None,
-                            },
-                        )
-                    })
-                    .collect();
-
-                summaries_with_ts.sort_by_key(|(ts, _)| *ts);
-                summaries_with_ts.reverse();
-
-                let summaries: Vec<AceCycleSummary> = summaries_with_ts
-                    .into_iter()
-                    .map(|(_, summary)| summary)
-                    .collect();
-
-                if let Some(first) = summaries.first().cloned() {
-                    actions.select_ace_cycle(Some(first.cycle_id.clone()));
-                } else {
-                    actions.select_ace_cycle(None);
-                }
-
-                actions.set_ace_cycles(summaries);
-            } else {
-                actions.set_ace_cycles(Vec::new());
-                actions.select_ace_cycle(None);
-            }
-            actions.set_ace_loading(false);
+    match fetch_ace_cycle_page(&client, tenant, &filter, None).await {
+        Ok(page) => {
+            actions.set_ace_cycles(page.cycles.clone());
+            actions.set_ace_listing(page.next_cursor, page.from_fallback);
+            Some(page.cycles)
         }
         Err(err) => {
-            tracing::error!("awareness events fetch failed: {err}");
+            tracing::error!("ace cycles fetch failed: {err}");
             actions.set_ace_error(Some(format!("ACE 数据加载失败: {err}")));
             actions.set_ace_loading(false);
+            None
+        }
+    }
+}
+
+/// 使用 `next_cursor` 追加下一页周期
+pub async fn load_more_ace_cycles(actions: AppActions, state: AppSignal) {
+    let snapshot = state.read();
+    let tenant = snapshot.tenant_id.clone().or_else(|| {
+        APP_CONFIG
+            .get()
+            .and_then(|cfg| cfg.default_tenant_id.clone())
+    });
+    let filter = snapshot.ace.filter.clone();
+    let cursor = snapshot.ace.next_cursor.clone();
+    let busy = snapshot.ace.loading_more || snapshot.ace.is_loading;
+    drop(snapshot);
+
+    let (Some(tenant), Some(cursor)) = (tenant, cursor) else {
+        return;
+    };
+    if busy {
+        return;
+    }
+
+    let Some(client) = API_CLIENT.get().cloned() else {
+        actions.set_ace_error(Some("Thin-Waist 客户端未初始化".into()));
+        return;
+    };
+
+    actions.set_ace_loading_more(true);
+    match fetch_ace_cycle_page(&client, &tenant, &filter, Some(cursor)).await {
+        Ok(page) => actions.append_ace_cycles(page.cycles, page.next_cursor),
+        Err(err) => {
+            tracing::error!("ace cycles next page fetch failed: {err}");
+            actions.set_ace_loading_more(false);
+            actions.set_ace_error(Some(format!("ACE 周期分页加载失败: {err}")));
         }
     }
 }
 
+pub(crate) struct AceCyclePage {
+    pub cycles: Vec<AceCycleSummary>,
+    pub next_cursor: Option<String>,
+    pub from_fallback: bool,
+}
+
+const ACE_CYCLE_PAGE_SIZE: u32 = 50;
+
+/// 优先使用 `ace/cycles` 列表接口；后端尚未提供该接口时回退到 awareness 事件推断
+pub(crate) async fn fetch_ace_cycle_page(
+    client: &ThinWaistClient,
+    tenant: &str,
+    filter: &AceCycleFilter,
+    cursor: Option<String>,
+) -> ClientResult<AceCyclePage> {
+    let (since_ms, until_ms) = filter.time_bounds(now_unix_ms());
+    let query = AceCycleListQuery {
+        lane: filter.lane.clone(),
+        status: filter.status.clone(),
+        since_ms,
+        until_ms,
+        cursor,
+        limit: Some(ACE_CYCLE_PAGE_SIZE),
+    };
+
+    match client
+        .list_ace_cycles::<_, AceCycleListResponse>(&query, Some(tenant))
+        .await
+    {
+        Ok(response) => Ok(AceCyclePage {
+            cycles: response.cycles,
+            next_cursor: response.next_cursor,
+            from_fallback: false,
+        }),
+        Err(err) if is_listing_unavailable(&err) => {
+            tracing::warn!(
+                "ace cycle listing unavailable, falling back to awareness events: {err}"
+            );
+            let env = client
+                .get_awareness_events::<_, Vec<AwarenessEvent>>(
+                    tenant,
+                    &AwarenessQuery { limit: 200 },
+                )
+                .await?;
+            let cycles = summarize_cycles_from_events(env.data.unwrap_or_default())
+                .into_iter()
+                .filter(|(latest_ts, cycle)| {
+                    filter.lane.as_ref().is_none_or(|lane| &cycle.lane == lane)
+                        && filter
+                            .status
+                            .as_ref()
+                            .is_none_or(|status| &cycle.status == status)
+                        && since_ms.is_none_or(|since| *latest_ts >= since)
+                        && until_ms.is_none_or(|until| *latest_ts <= until)
+                })
+                .map(|(_, cycle)| cycle)
+                .collect();
+            Ok(AceCyclePage {
+                cycles,
+                next_cursor: None,
+                from_fallback: true,
+            })
+        }
+        Err(err) => Err(err),
+    }
+}
+
+/// 列表类接口尚未由后端提供（路由不存在或未实现）
+pub(crate) fn is_listing_unavailable(err: &ClientError) -> bool {
+    matches!(
+        err.status(),
+        Some(StatusCode::NOT_FOUND | StatusCode::METHOD_NOT_ALLOWED | StatusCode::NOT_IMPLEMENTED)
+    )
+}
+
+fn now_unix_ms() -> i64 {
+    (OffsetDateTime::now_utc().unix_timestamp_nanos() / 1_000_000) as i64
+}
+
+/// 回退路径：按周期聚合 awareness 事件并推断 lane/status，结果按最近事件时间倒序
+fn summarize_cycles_from_events(events: Vec<AwarenessEvent>) -> Vec<(i64, AceCycleSummary)> {
+    let mut grouped: HashMap<String, Vec<AwarenessEvent>> = HashMap::new();
+    for event in events {
+        // Store cycle_id as numeric u64 string instead of Base36 for compatibility with backend
+        let cycle_id = event.awareness_cycle_id.as_u64().to_string();
+        grouped.entry(cycle_id).or_default().push(event);
+    }
+
+    let mut summaries_with_ts: Vec<(i64, AceCycleSummary)> = grouped
+        .into_iter()
+        .map(|(cycle_id, mut items)| {
+            items.sort_by_key(|evt| evt.occurred_at_ms);
+            let latest_ts = items
+                .iter()
+                .map(|evt| evt.occurred_at_ms)
+                .max()
+                .unwrap_or_default();
+            // 将 core model 的 AwarenessAnchor 转换为 Value
+            let anchor = items
+                .first()
+                .and_then(|evt| serde_json::to_value(&evt.anchor).ok());
+            let lane = detect_lane(&items);
+            let status = detect_status(&items);
+
+            (
+                latest_ts,
+                AceCycleSummary {
+                    cycle_id,
+                    lane,
+                    status,
+                    anchor,
+                    budget: None,
+                    latest_sync_point: None,
+                    pending_injections: Vec::new(),
+                    decision_path: None,
+                    metadata: None,
+                },
+            )
+        })
+        .collect();
+
+    summaries_with_ts.sort_by_key(|(ts, _)| *ts);
+    summaries_with_ts.reverse();
+    summaries_with_ts
+}
+
 async fn load_cycle_snapshot(actions: AppActions, state: AppSignal) {
     let snapshot = state.read();
     let tenant = snapshot.tenant_id.clone().or_else(|| {
@@ -265,6 +377,106 @@ async fn load_cycle_snapshot(actions: AppActions, state: AppSignal) {
     actions.set_ace_snapshot_loading(false);
 }
 
+/// 谱系视图中按需拉取缺失的祖先周期快照（不拉取 Outbox）
+pub async fn load_cycle_ancestor(actions: AppActions, state: AppSignal, cycle_id: String) {
+    let snapshot = state.read();
+    let tenant = snapshot.tenant_id.clone().or_else(|| {
+        APP_CONFIG
+            .get()
+            .and_then(|cfg| cfg.default_tenant_id.clone())
+    });
+    let already_loading = snapshot.ace.lineage_pending.contains(&cycle_id);
+    drop(snapshot);
+
+    if already_loading {
+        return;
+    }
+
+    let Some(tenant) = tenant else {
+        actions.set_ace_lineage_error(cycle_id, "请先选择租户".into());
+        return;
+    };
+
+    let Some(client) = API_CLIENT.get().cloned() else {
+        actions.set_ace_lineage_error(cycle_id, "Thin-Waist 客户端未初始化".into());
+        return;
+    };
+
+    actions.set_ace_lineage_pending(cycle_id.clone(), true);
+
+    match client
+        .get_cycle_snapshot::<CycleSnapshotView>(&cycle_id, Some(&tenant))
+        .await
+    {
+        Ok(snapshot) => actions.store_ace_lineage_snapshot(cycle_id, snapshot),
+        Err(err) => {
+            tracing::warn!("lineage ancestor {cycle_id} fetch failed: {err}");
+            actions.set_ace_lineage_error(cycle_id, format!("快照加载失败: {err}"));
+        }
+    }
+}
+
+/// 批量补齐快照时同时进行的请求上限
+const SNAPSHOT_BATCH_CONCURRENCY: usize = 4;
+
+/// 批量补齐缺失的周期快照（不拉取 Outbox），已缓存或正在加载的周期会被跳过
+pub async fn load_cycle_snapshots(actions: AppActions, state: AppSignal, cycle_ids: Vec<String>) {
+    let snapshot = state.read();
+    let tenant = snapshot.tenant_id.clone().or_else(|| {
+        APP_CONFIG
+            .get()
+            .and_then(|cfg| cfg.default_tenant_id.clone())
+    });
+    let cycle_ids: Vec<String> = cycle_ids
+        .into_iter()
+        .filter(|cycle_id| {
+            !snapshot.ace.snapshots.contains_key(cycle_id)
+                && !snapshot.ace.batch_pending.contains(cycle_id)
+        })
+        .collect();
+    drop(snapshot);
+
+    if cycle_ids.is_empty() {
+        return;
+    }
+
+    let Some(tenant) = tenant else {
+        for cycle_id in cycle_ids {
+            actions.set_ace_batch_error(cycle_id, "请先选择租户".into());
+        }
+        return;
+    };
+
+    let Some(client) = API_CLIENT.get().cloned() else {
+        for cycle_id in cycle_ids {
+            actions.set_ace_batch_error(cycle_id, "Thin-Waist 客户端未初始化".into());
+        }
+        return;
+    };
+
+    actions.queue_ace_snapshot_batch(cycle_ids.clone());
+
+    let client = &client;
+    let tenant = tenant.as_str();
+    futures::stream::iter(cycle_ids)
+        .for_each_concurrent(SNAPSHOT_BATCH_CONCURRENCY, |cycle_id| {
+            let actions = actions.clone();
+            async move {
+                match client
+                    .get_cycle_snapshot::<CycleSnapshotView>(&cycle_id, Some(tenant))
+                    .await
+                {
+                    Ok(snapshot) => actions.store_ace_batch_snapshot(cycle_id, snapshot),
+                    Err(err) => {
+                        tracing::warn!("batch snapshot {cycle_id} fetch failed: {err}");
+                        actions.set_ace_batch_error(cycle_id, format!("快照加载失败: {err}"));
+                    }
+                }
+            }
+        })
+        .await;
+}
+
 fn detect_lane(events: &[AwarenessEvent]) -> AceLane {
     for event in events.iter().rev() {
         if let Some(lane) = event.payload.get("lane").and_then(|value| value.as_str()) {
@@ -289,20 +501,6 @@ fn detect_status(events: &[AwarenessEvent]) -> AceCycleStatus {
         .iter()
         .any(|event| matches!(event.event_type, AwarenessEventType::Rejected));
 
-    // 列出所有事件类型以便调试
-    let event_types: Vec<String> = events
-        .iter()
-        .map(|e| format!("{:?}", e.event_type))
-        .collect();
-
-    tracing::info!(
-        "detect_status: events={}, has_finalized={}, has_rejected={}, event_types={:?}",
-        events.len(),
-        has_finalized,
-        has_rejected,
-        event_types
-    );
-
     if has_finalized {
         AceCycleStatus::Completed
     } else if has_rejected {
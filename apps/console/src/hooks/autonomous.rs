@@ -5,11 +5,15 @@
 use dioxus::prelude::*;
 use gloo_timers::future::TimeoutFuture;
 
+use crate::api::{ApiEnvelope, ClientResult, ThinWaistClient};
+use crate::hooks::cycle_runner::record_client_error;
 use crate::models::{
-    AutonomousSessionListResponse, AutonomousSessionResponse, AutonomousSessionSummary,
-    AutonomousStatus, ScenarioStackState, StartAutonomousRequest, TerminationResult,
+    AddAgendaItemsRequest, AgendaItemInput, AgendaItemStatus, AutonomousConfig,
+    AutonomousSessionListResponse, AutonomousSessionResponse, AutonomousSessionState,
+    AutonomousSessionSummary, AutonomousStatus, ReorderAgendaRequest, ScenarioStackEntry,
+    ScenarioStackState, StartAutonomousRequest, TerminationResult, UpdateAgendaItemRequest,
 };
-use crate::state::use_app_state;
+use crate::state::{use_app_actions, use_app_state, OperationKind, OperationStageKind};
 use crate::{API_CLIENT, APP_CONFIG};
 
 /// 自主延续会话状态
@@ -92,43 +96,259 @@ pub fn use_autonomous_session() -> Signal<AutonomousHookState> {
     state
 }
 
+/// 会话监控的轮询间隔
+const MONITOR_POLL_MS: u32 = 3000;
+
+/// 单个自主会话的实时状态
+#[derive(Clone, Debug, Default)]
+pub struct AutonomousMonitorState {
+    pub loading: bool,
+    pub error: Option<String>,
+    pub session: Option<AutonomousSessionState>,
+    pub last_refreshed_at_ms: Option<i64>,
+}
+
+/// 会话是否仍需轮询
+pub fn is_autonomous_active(status: AutonomousStatus) -> bool {
+    matches!(
+        status,
+        AutonomousStatus::Starting | AutonomousStatus::Running | AutonomousStatus::Stopping
+    )
+}
+
+/// 单会话监控 Hook，会话处于活动状态时持续轮询，结束后停止；`refresh` 变化时立即重新拉取
+pub fn use_autonomous_monitor(
+    orchestration_id: Option<String>,
+    refresh: u32,
+) -> Signal<AutonomousMonitorState> {
+    let state_store = use_app_state();
+    let mut state = use_signal(AutonomousMonitorState::default);
+
+    let tenant_id = state_store.read().tenant_id.clone();
+
+    use_future(use_reactive!(|(tenant_id, orchestration_id, refresh)| {
+        async move {
+            TimeoutFuture::new(0).await;
+            let _ = refresh;
+
+            let Some(orchestration_id) = orchestration_id else {
+                *state.write() = AutonomousMonitorState::default();
+                return;
+            };
+
+            // 切换到其它会话时不保留旧数据
+            let switched = state
+                .read()
+                .session
+                .as_ref()
+                .is_some_and(|session| session.orchestration_id != orchestration_id);
+            if switched {
+                *state.write() = AutonomousMonitorState::default();
+            }
+
+            let tenant = tenant_id.clone().or_else(|| {
+                APP_CONFIG
+                    .get()
+                    .and_then(|cfg| cfg.default_tenant_id.clone())
+            });
+
+            let Some(tenant) = tenant else {
+                state.write().error = Some("请先选择租户".into());
+                return;
+            };
+
+            let Some(client) = API_CLIENT.get().cloned() else {
+                state.write().error = Some("API 客户端未初始化".into());
+                return;
+            };
+
+            loop {
+                if state.read().session.is_none() {
+                    state.write().loading = true;
+                }
+
+                let active = match client
+                    .get_autonomous_session::<AutonomousSessionState>(&tenant, &orchestration_id)
+                    .await
+                {
+                    Ok(env) => {
+                        let mut current = state.write();
+                        let active = env
+                            .data
+                            .as_ref()
+                            .is_some_and(|session| is_autonomous_active(session.status));
+                        current.session = env.data;
+                        current.error = None;
+                        current.last_refreshed_at_ms = Some(web_sys::js_sys::Date::now() as i64);
+                        active
+                    }
+                    Err(err) => {
+                        tracing::error!("自主会话状态加载失败: {err}");
+                        state.write().error = Some(format!("加载失败: {err}"));
+                        // 瞬时错误不应中断仍在运行的会话的监控
+                        state
+                            .read()
+                            .session
+                            .as_ref()
+                            .is_some_and(|session| is_autonomous_active(session.status))
+                    }
+                };
+
+                state.write().loading = false;
+
+                if !active {
+                    break;
+                }
+                TimeoutFuture::new(MONITOR_POLL_MS).await;
+            }
+        }
+    }));
+
+    state
+}
+
 /// 自主延续控制结果
 #[derive(Clone, Debug)]
 pub enum AutonomousControlResult {
     Started(String),
     Terminated(TerminationResult),
+    /// 运行中调整成功，附带调整描述
+    Adjusted(String),
     Error(String),
 }
 
+/// 对运行中会话的调整
+#[derive(Clone, Debug)]
+pub enum AutonomousAdjustment {
+    Pause,
+    Resume,
+    AddAgenda(Vec<AgendaItemInput>),
+    SetPriority {
+        item_id: String,
+        priority: u8,
+    },
+    Skip {
+        item_id: String,
+    },
+    /// 全部待执行项的新顺序
+    Reorder(Vec<String>),
+    UpdateConfig(AutonomousConfig),
+}
+
+impl AutonomousAdjustment {
+    pub fn label(&self) -> String {
+        match self {
+            Self::Pause => "暂停".into(),
+            Self::Resume => "恢复".into(),
+            Self::AddAgenda(items) => format!("追加 {} 个议程项", items.len()),
+            Self::SetPriority { item_id, priority } => {
+                format!("议程 {item_id} 优先级调整为 {priority}")
+            }
+            Self::Skip { item_id } => format!("跳过议程 {item_id}"),
+            Self::Reorder(item_ids) => format!("重排 {} 个待执行议程", item_ids.len()),
+            Self::UpdateConfig(_) => "调整终止条件".into(),
+        }
+    }
+
+    async fn submit(
+        &self,
+        client: &ThinWaistClient,
+        tenant: &str,
+        orchestration_id: &str,
+    ) -> ClientResult<ApiEnvelope<AutonomousSessionState>> {
+        match self {
+            Self::Pause => client.post_autonomous_pause(tenant, orchestration_id).await,
+            Self::Resume => {
+                client
+                    .post_autonomous_resume(tenant, orchestration_id)
+                    .await
+            }
+            Self::AddAgenda(items) => {
+                let payload = AddAgendaItemsRequest {
+                    items: items.clone(),
+                };
+                client
+                    .post_autonomous_agenda(tenant, orchestration_id, &payload)
+                    .await
+            }
+            Self::SetPriority { item_id, priority } => {
+                let payload = UpdateAgendaItemRequest {
+                    priority: Some(*priority),
+                    ..UpdateAgendaItemRequest::default()
+                };
+                client
+                    .patch_autonomous_agenda_item(tenant, orchestration_id, item_id, &payload)
+                    .await
+            }
+            Self::Skip { item_id } => {
+                let payload = UpdateAgendaItemRequest {
+                    status: Some(AgendaItemStatus::Skipped),
+                    ..UpdateAgendaItemRequest::default()
+                };
+                client
+                    .patch_autonomous_agenda_item(tenant, orchestration_id, item_id, &payload)
+                    .await
+            }
+            Self::Reorder(item_ids) => {
+                let payload = ReorderAgendaRequest {
+                    item_ids: item_ids.clone(),
+                };
+                client
+                    .put_autonomous_agenda_order(tenant, orchestration_id, &payload)
+                    .await
+            }
+            Self::UpdateConfig(config) => {
+                client
+                    .patch_autonomous_config(tenant, orchestration_id, config)
+                    .await
+            }
+        }
+    }
+}
+
 /// 自主延续控制 Hook 返回结构体
 pub struct AutonomousControl {
     pub starting: Signal<bool>,
     pub terminating: Signal<bool>,
+    pub adjusting: Signal<bool>,
+    /// 每次调整成功后递增，供监控视图立即刷新
+    pub revision: Signal<u32>,
     pub last_result: Signal<Option<AutonomousControlResult>>,
     pub start: Box<dyn Fn(StartAutonomousRequest) + 'static>,
     pub terminate: Box<dyn Fn(String, String) + 'static>,
+    pub adjust: Box<dyn Fn(String, AutonomousAdjustment) + 'static>,
 }
 
 /// 自主延续控制 Hook (用于启动和终止)
 pub fn use_autonomous_control() -> AutonomousControl {
     let starting = use_signal(|| false);
     let terminating = use_signal(|| false);
+    let adjusting = use_signal(|| false);
+    let revision = use_signal(|| 0u32);
     let last_result = use_signal(|| None::<AutonomousControlResult>);
 
     let state = use_app_state();
+    let actions = use_app_actions();
 
     let start = {
         let mut starting = starting.clone();
         let mut last_result = last_result.clone();
         let state = state.clone();
+        let actions = actions.clone();
 
         move |request: StartAutonomousRequest| {
             let mut starting = starting.clone();
             let mut last_result = last_result.clone();
             let state = state.clone();
+            let actions = actions.clone();
 
             spawn(async move {
                 starting.set(true);
+                let actions = actions.operation_begin(OperationKind::AutonomousStart);
+                actions.set_operation_context(Some(format!(
+                    "启动自主延续 @ 会话 {}",
+                    request.session_id
+                )));
 
                 let snapshot = state.read();
                 let tenant = snapshot.tenant_id.clone().or_else(|| {
@@ -139,6 +359,7 @@ pub fn use_autonomous_control() -> AutonomousControl {
                 drop(snapshot);
 
                 let Some(tenant) = tenant else {
+                    actions.set_operation_error("请先选择租户".into());
                     last_result.set(Some(AutonomousControlResult::Error(
                         "请先选择租户".into(),
                     )));
@@ -147,6 +368,7 @@ pub fn use_autonomous_control() -> AutonomousControl {
                 };
 
                 let Some(client) = API_CLIENT.get().cloned() else {
+                    actions.set_operation_error("API 客户端未初始化".into());
                     last_result.set(Some(AutonomousControlResult::Error(
                         "API 客户端未初始化".into(),
                     )));
@@ -154,6 +376,11 @@ pub fn use_autonomous_control() -> AutonomousControl {
                     return;
                 };
 
+                actions.operation_stage_start(
+                    OperationStageKind::AutonomousSubmit,
+                    format!("提交 {} 个议程项", request.agenda_items.len()),
+                );
+
                 match client
                     .post_autonomous_start::<StartAutonomousRequest, AutonomousSessionResponse>(
                         &tenant, &request,
@@ -161,14 +388,36 @@ pub fn use_autonomous_control() -> AutonomousControl {
                     .await
                 {
                     Ok(env) => {
+                        actions.set_operation_trace(env.trace_id.clone());
                         if let Some(data) = env.data {
+                            actions.operation_stage_complete(
+                                OperationStageKind::AutonomousSubmit,
+                                Some(format!("编排 {}", data.orchestration_id)),
+                            );
+                            actions.set_operation_success(format!(
+                                "自主延续 {} 已启动",
+                                data.orchestration_id
+                            ));
                             last_result.set(Some(AutonomousControlResult::Started(
                                 data.orchestration_id,
                             )));
+                        } else {
+                            actions.operation_stage_fail(
+                                OperationStageKind::AutonomousSubmit,
+                                Some("响应为空".into()),
+                            );
+                            actions.set_operation_error("启动自主延续返回空数据".into());
                         }
                     }
                     Err(err) => {
                         tracing::error!("启动自主延续失败: {err}");
+                        record_client_error(
+                            &actions,
+                            &err,
+                            "post_autonomous_start",
+                            "启动自主延续失败",
+                            Some(OperationStageKind::AutonomousSubmit),
+                        );
                         last_result.set(Some(AutonomousControlResult::Error(format!(
                             "启动失败: {err}"
                         ))));
@@ -184,14 +433,18 @@ pub fn use_autonomous_control() -> AutonomousControl {
         let mut terminating = terminating.clone();
         let mut last_result = last_result.clone();
         let state = state.clone();
+        let actions = actions.clone();
 
         move |orchestration_id: String, reason: String| {
             let mut terminating = terminating.clone();
             let mut last_result = last_result.clone();
             let state = state.clone();
+            let actions = actions.clone();
 
             spawn(async move {
                 terminating.set(true);
+                let actions = actions.operation_begin(OperationKind::AutonomousStop);
+                actions.set_operation_context(Some(format!("终止自主延续 {orchestration_id}")));
 
                 let snapshot = state.read();
                 let tenant = snapshot.tenant_id.clone().or_else(|| {
@@ -202,6 +455,11 @@ pub fn use_autonomous_control() -> AutonomousControl {
                 drop(snapshot);
 
                 let Some(tenant) = tenant else {
+                    actions.set_operation_error("请先选择租户".into());
+                    actions.settle_guardrail_termination(
+                        &orchestration_id,
+                        Err("请先选择租户".into()),
+                    );
                     last_result.set(Some(AutonomousControlResult::Error(
                         "请先选择租户".into(),
                     )));
@@ -210,6 +468,11 @@ pub fn use_autonomous_control() -> AutonomousControl {
                 };
 
                 let Some(client) = API_CLIENT.get().cloned() else {
+                    actions.set_operation_error("API 客户端未初始化".into());
+                    actions.settle_guardrail_termination(
+                        &orchestration_id,
+                        Err("API 客户端未初始化".into()),
+                    );
                     last_result.set(Some(AutonomousControlResult::Error(
                         "API 客户端未初始化".into(),
                     )));
@@ -217,6 +480,11 @@ pub fn use_autonomous_control() -> AutonomousControl {
                     return;
                 };
 
+                actions.operation_stage_start(
+                    OperationStageKind::AutonomousSubmit,
+                    format!("提交终止请求（{reason}）"),
+                );
+
                 #[derive(serde::Serialize)]
                 struct TerminateRequest {
                     reason: String,
@@ -231,12 +499,41 @@ pub fn use_autonomous_control() -> AutonomousControl {
                     .await
                 {
                     Ok(env) => {
+                        actions.set_operation_trace(env.trace_id.clone());
                         if let Some(data) = env.data {
+                            actions.operation_stage_complete(
+                                OperationStageKind::AutonomousSubmit,
+                                Some(format!("原因 {:?}", data.reason)),
+                            );
+                            actions.set_operation_success(format!(
+                                "自主延续 {} 已终止",
+                                data.orchestration_id
+                            ));
+                            actions.settle_guardrail_termination(&orchestration_id, Ok(()));
                             last_result.set(Some(AutonomousControlResult::Terminated(data)));
+                        } else {
+                            actions.operation_stage_fail(
+                                OperationStageKind::AutonomousSubmit,
+                                Some("响应为空".into()),
+                            );
+                            actions.set_operation_error("终止自主延续返回空数据".into());
+                            actions.settle_guardrail_termination(
+                                &orchestration_id,
+                                Err("响应为空".into()),
+                            );
                         }
                     }
                     Err(err) => {
                         tracing::error!("终止自主延续失败: {err}");
+                        record_client_error(
+                            &actions,
+                            &err,
+                            "post_autonomous_terminate",
+                            "终止自主延续失败",
+                            Some(OperationStageKind::AutonomousSubmit),
+                        );
+                        actions
+                            .settle_guardrail_termination(&orchestration_id, Err(err.to_string()));
                         last_result.set(Some(AutonomousControlResult::Error(format!(
                             "终止失败: {err}"
                         ))));
@@ -248,28 +545,193 @@ pub fn use_autonomous_control() -> AutonomousControl {
         }
     };
 
+    let adjust = {
+        let state = state.clone();
+        let actions = actions.clone();
+
+        move |orchestration_id: String, adjustment: AutonomousAdjustment| {
+            let mut adjusting = adjusting;
+            let mut revision = revision;
+            let mut last_result = last_result;
+            let state = state.clone();
+            let actions = actions.clone();
+
+            spawn(async move {
+                adjusting.set(true);
+                let label = adjustment.label();
+                let actions = actions.operation_begin(OperationKind::AutonomousAdjust);
+                actions.set_operation_context(Some(format!("{label} @ {orchestration_id}")));
+
+                let snapshot = state.read();
+                let tenant = snapshot.tenant_id.clone().or_else(|| {
+                    APP_CONFIG
+                        .get()
+                        .and_then(|cfg| cfg.default_tenant_id.clone())
+                });
+                drop(snapshot);
+
+                let Some(tenant) = tenant else {
+                    actions.set_operation_error("请先选择租户".into());
+                    last_result.set(Some(AutonomousControlResult::Error("请先选择租户".into())));
+                    adjusting.set(false);
+                    return;
+                };
+
+                let Some(client) = API_CLIENT.get().cloned() else {
+                    actions.set_operation_error("API 客户端未初始化".into());
+                    last_result.set(Some(AutonomousControlResult::Error(
+                        "API 客户端未初始化".into(),
+                    )));
+                    adjusting.set(false);
+                    return;
+                };
+
+                actions.operation_stage_start(OperationStageKind::AutonomousSubmit, label.clone());
+
+                match adjustment.submit(&client, &tenant, &orchestration_id).await {
+                    Ok(env) => {
+                        actions.set_operation_trace(env.trace_id.clone());
+                        actions.operation_stage_complete(
+                            OperationStageKind::AutonomousSubmit,
+                            env.data
+                                .as_ref()
+                                .map(|data| format!("状态 {:?}", data.status)),
+                        );
+                        actions.set_operation_success(format!("{orchestration_id}：{label}"));
+                        last_result.set(Some(AutonomousControlResult::Adjusted(label)));
+                        let next = revision.peek().wrapping_add(1);
+                        revision.set(next);
+                    }
+                    Err(err) => {
+                        tracing::error!("调整自主延续失败: {err}");
+                        record_client_error(
+                            &actions,
+                            &err,
+                            "adjust_autonomous",
+                            "调整自主延续失败",
+                            Some(OperationStageKind::AutonomousSubmit),
+                        );
+                        last_result.set(Some(AutonomousControlResult::Error(format!(
+                            "{label}失败: {err}"
+                        ))));
+                    }
+                }
+
+                adjusting.set(false);
+            });
+        }
+    };
+
     AutonomousControl {
         starting,
         terminating,
+        adjusting,
+        revision,
         last_result,
         start: Box::new(start),
         terminate: Box::new(terminate),
+        adjust: Box::new(adjust),
     }
 }
 
-/// 场景栈 Hook
-pub fn use_scenario_stack() -> Signal<Option<ScenarioStackState>> {
+/// 控制台观测到的一段场景栈区间，`exited_at_ms` 为空表示仍在栈中
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScenarioSpan {
+    pub scenario: String,
+    pub trigger_event_id: String,
+    pub depth: u32,
+    pub entered_at_ms: i64,
+    /// 出栈时间取首次观测到条目消失的轮询时刻
+    pub exited_at_ms: Option<i64>,
+}
+
+impl ScenarioSpan {
+    fn matches(&self, entry: &ScenarioStackEntry) -> bool {
+        self.scenario == entry.scenario
+            && self.entered_at_ms == entry.entered_at_ms
+            && self.trigger_event_id == entry.trigger_event_id
+            && self.depth == entry.depth
+    }
+}
+
+/// 场景栈状态，`spans` 由多次轮询的栈快照累积而来
+#[derive(Clone, Debug, Default)]
+pub struct ScenarioStackHookState {
+    pub loading: bool,
+    pub error: Option<String>,
+    pub stack: Option<ScenarioStackState>,
+    pub spans: Vec<ScenarioSpan>,
+    pub last_refreshed_at_ms: Option<i64>,
+}
+
+impl ScenarioStackHookState {
+    /// 对比新快照：新条目记为入栈，消失的条目记为出栈
+    fn observe(&mut self, stack: ScenarioStackState, now_ms: i64) {
+        for span in self
+            .spans
+            .iter_mut()
+            .filter(|span| span.exited_at_ms.is_none())
+        {
+            if !stack.stack.iter().any(|entry| span.matches(entry)) {
+                span.exited_at_ms = Some(now_ms);
+            }
+        }
+        for entry in stack.stack.iter() {
+            let known = self
+                .spans
+                .iter()
+                .any(|span| span.exited_at_ms.is_none() && span.matches(entry));
+            if !known {
+                self.spans.push(ScenarioSpan {
+                    scenario: entry.scenario.clone(),
+                    trigger_event_id: entry.trigger_event_id.clone(),
+                    depth: entry.depth,
+                    entered_at_ms: entry.entered_at_ms,
+                    exited_at_ms: None,
+                });
+            }
+        }
+        self.spans
+            .sort_by_key(|span| (span.entered_at_ms, span.depth));
+        self.stack = Some(stack);
+        self.last_refreshed_at_ms = Some(now_ms);
+    }
+}
+
+/// 场景栈 Hook，`active` 为真时按监控间隔轮询以记录入栈/出栈；`refresh` 变化时立即重新拉取
+pub fn use_scenario_stack(
+    orchestration_id: Option<String>,
+    active: bool,
+    refresh: u32,
+) -> Signal<ScenarioStackHookState> {
     let state_store = use_app_state();
-    let mut stack = use_signal(|| None::<ScenarioStackState>);
+    let mut state = use_signal(ScenarioStackHookState::default);
+    let mut cached_target = use_signal(|| None::<(Option<String>, String)>);
 
-    let snapshot = state_store.read();
-    let tenant_id = snapshot.tenant_id.clone();
-    let session_id = snapshot.session_id.clone();
-    drop(snapshot);
+    let tenant_id = state_store.read().tenant_id.clone();
 
-    use_future(use_reactive!(|(tenant_id, session_id)| {
+    use_future(use_reactive!(|(
+        tenant_id,
+        orchestration_id,
+        active,
+        refresh,
+    )| {
         async move {
             TimeoutFuture::new(0).await;
+            let _ = refresh;
+
+            let Some(orchestration_id) = orchestration_id else {
+                *state.write() = ScenarioStackHookState::default();
+                cached_target.set(None);
+                return;
+            };
+
+            // 切换租户或会话时不保留旧区间
+            let target = (tenant_id.clone(), orchestration_id.clone());
+            if cached_target.peek().as_ref() != Some(&target) {
+                *state.write() = ScenarioStackHookState::default();
+                cached_target.set(Some(target));
+            }
 
             let tenant = tenant_id.clone().or_else(|| {
                 APP_CONFIG
@@ -278,31 +740,46 @@ pub fn use_scenario_stack() -> Signal<Option<ScenarioStackState>> {
             });
 
             let Some(tenant) = tenant else {
-                return;
-            };
-
-            let Some(sid) = session_id.clone() else {
-                stack.set(None);
+                state.write().error = Some("请先选择租户".into());
                 return;
             };
 
             let Some(client) = API_CLIENT.get().cloned() else {
+                state.write().error = Some("API 客户端未初始化".into());
                 return;
             };
 
-            match client
-                .get_autonomous_scenario_stack::<ScenarioStackState>(&tenant, &sid)
-                .await
-            {
-                Ok(env) => {
-                    stack.set(env.data);
+            loop {
+                if state.read().stack.is_none() {
+                    state.write().loading = true;
                 }
-                Err(err) => {
-                    tracing::error!("场景栈加载失败: {err}");
+
+                match client
+                    .get_autonomous_scenario_stack::<ScenarioStackState>(&tenant, &orchestration_id)
+                    .await
+                {
+                    Ok(env) => {
+                        let mut current = state.write();
+                        if let Some(stack) = env.data {
+                            current.observe(stack, web_sys::js_sys::Date::now() as i64);
+                        }
+                        current.error = None;
+                    }
+                    Err(err) => {
+                        tracing::error!("场景栈加载失败: {err}");
+                        state.write().error = Some(format!("加载失败: {err}"));
+                    }
                 }
+
+                state.write().loading = false;
+
+                if !active {
+                    break;
+                }
+                TimeoutFuture::new(MONITOR_POLL_MS).await;
             }
         }
     }));
 
-    stack
+    state
 }
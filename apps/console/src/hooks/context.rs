@@ -1,8 +1,14 @@
 use dioxus::prelude::*;
 use gloo_timers::future::TimeoutFuture;
 
-use crate::models::{ContextBundleView, ExplainIndices};
-use crate::state::{use_app_actions, use_app_state};
+use crate::api::ThinWaistClient;
+use crate::hooks::cycle_runner::record_client_error;
+use crate::models::{
+    ContextBundleView, ContextCompactRequest, ContextCompactResponse, ExplainIndices,
+};
+use crate::state::{
+    use_app_actions, use_app_state, AppActions, AppSignal, OperationKind, OperationStageKind,
+};
 use crate::{API_CLIENT, APP_CONFIG};
 
 /// 加载最新 ContextBundle 与 Explain 指纹。
@@ -87,3 +93,162 @@ pub fn use_context_bundle() {
         }
     }));
 }
+
+/// 重新拉取 ContextBundle 与 Explain 指纹，对应 `ContextSync` 阶段
+pub(crate) async fn sync_context_bundle(
+    actions: &AppActions,
+    client: &ThinWaistClient,
+    tenant: &str,
+) -> bool {
+    let mut failure: Option<String> = None;
+
+    actions.operation_stage_start(OperationStageKind::ContextSync, "同步上下文");
+    actions.set_context_loading(true);
+    actions.set_context_error(None);
+    let bundle_res = client
+        .get_context_bundle::<(), ContextBundleView>(tenant, None)
+        .await;
+    let explain_res = client.get_explain_indices::<ExplainIndices>(tenant).await;
+
+    match (bundle_res, explain_res) {
+        (Ok(bundle_env), Ok(explain_env)) => {
+            if bundle_env.data.is_none() {
+                actions.set_context_error(Some("ContextBundle 为空".into()));
+                actions.set_context_loading(false);
+                failure = Some("ContextBundle 为空".into());
+            } else {
+                actions.set_context_bundle(bundle_env.data, explain_env.data);
+            }
+        }
+        (bundle, explain) => {
+            let mut message = String::new();
+            if let Err(err) = bundle {
+                record_client_error(
+                    actions,
+                    &err,
+                    "context_bundle",
+                    "上下文加载失败",
+                    Some(OperationStageKind::ContextSync),
+                );
+                message.push_str(&format!("上下文加载失败: {err}"));
+            }
+            if let Err(err) = explain {
+                record_client_error(
+                    actions,
+                    &err,
+                    "explain_indices",
+                    "Explain 指纹加载失败",
+                    Some(OperationStageKind::ContextSync),
+                );
+                if !message.is_empty() {
+                    message.push_str("；");
+                }
+                message.push_str(&format!("Explain 指纹加载失败: {err}"));
+            }
+            actions.set_context_error(Some(message.clone()));
+            actions.set_context_loading(false);
+            failure = Some(message);
+        }
+    }
+
+    match failure {
+        Some(message) => {
+            actions.operation_stage_fail(OperationStageKind::ContextSync, Some(message));
+            false
+        }
+        None => {
+            actions.operation_stage_complete(OperationStageKind::ContextSync, None);
+            true
+        }
+    }
+}
+
+/// 提交上下文压缩，记录为 `Compaction` 操作：先提交压缩请求，再同步新的 Manifest，
+/// 新 digest 会进入 manifest 历史，便于与压缩前版本对比。
+pub async fn compact_context(
+    actions: AppActions,
+    state: AppSignal,
+    request: ContextCompactRequest,
+) {
+    let actions = actions.operation_begin(OperationKind::Compaction);
+    actions.set_operation_trace(None);
+    actions.set_operation_diagnostics(Vec::new(), None);
+    let context_label = format!("上下文压缩 · 目标 {} tokens", request.target_tokens);
+    actions.set_operation_context(Some(context_label.clone()));
+
+    let snapshot = state.read();
+    let tenant = snapshot.tenant_id.clone().or_else(|| {
+        APP_CONFIG
+            .get()
+            .and_then(|cfg| cfg.default_tenant_id.clone())
+    });
+    let digest_before = snapshot.context.active_manifest_digest.clone();
+    drop(snapshot);
+
+    let Some(tenant) = tenant else {
+        actions.set_operation_error("请先选择租户后再压缩上下文".into());
+        return;
+    };
+    let Some(client) = API_CLIENT.get().cloned() else {
+        actions.set_operation_error("Thin-Waist 客户端未初始化".into());
+        return;
+    };
+
+    let scope_label = if request.partitions.is_empty() {
+        "全部分区".to_string()
+    } else {
+        request.partitions.join(", ")
+    };
+    actions.operation_stage_start(
+        OperationStageKind::CompactionSubmit,
+        format!("提交压缩（{scope_label}）"),
+    );
+
+    match client
+        .post_context_compact::<_, ContextCompactResponse>(&tenant, &request)
+        .await
+    {
+        Ok(envelope) => {
+            actions.set_operation_trace(envelope.trace_id.clone());
+            let response = envelope.data.unwrap_or_default();
+            let detail = match (response.tokens_before, response.tokens_after) {
+                (Some(before), Some(after)) => format!(
+                    "{before} → {after} tokens，释放 {}",
+                    before.saturating_sub(after)
+                ),
+                _ => response
+                    .manifest_digest
+                    .clone()
+                    .map(|digest| format!("新 Manifest {digest}"))
+                    .unwrap_or_else(|| "压缩请求已受理".into()),
+            };
+            actions.operation_stage_complete(OperationStageKind::CompactionSubmit, Some(detail));
+        }
+        Err(err) => {
+            record_client_error(
+                &actions,
+                &err,
+                "context_compact",
+                "上下文压缩失败",
+                Some(OperationStageKind::CompactionSubmit),
+            );
+            return;
+        }
+    }
+
+    if !sync_context_bundle(&actions, &client, &tenant).await {
+        actions.set_operation_error("压缩已提交，但同步新的上下文失败".into());
+        return;
+    }
+
+    let digest_after = state.read().context.active_manifest_digest.clone();
+    let message = match (digest_before, digest_after) {
+        (Some(before), Some(after)) if before != after => {
+            format!("上下文压缩完成：Manifest {before} → {after}")
+        }
+        (_, Some(after)) => format!("上下文压缩完成，Manifest 未变化（{after}）"),
+        _ => "上下文压缩完成".to_string(),
+    };
+    actions.set_operation_success(message);
+    actions.set_operation_context(Some(context_label));
+}
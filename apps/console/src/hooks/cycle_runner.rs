@@ -1,19 +1,21 @@
 use dioxus::prelude::*;
 use serde_json::Value;
-use std::collections::HashMap;
 use soulseed_agi_core_models::dialogue_event::DialogueEvent as ThinDialogueEvent;
 use soulseed_agi_core_models::{AccessClass, ConversationScenario, Subject, SubjectRef};
 
-use crate::api::{AwarenessQuery, ClientError};
+use crate::api::ClientError;
+#[cfg(target_arch = "wasm32")]
+use crate::hooks::ace::reload_ace_cycles;
+#[cfg(target_arch = "wasm32")]
+use crate::hooks::context::sync_context_bundle;
 #[cfg(target_arch = "wasm32")]
 use crate::models::CycleTriggerResponse;
 #[cfg(target_arch = "wasm32")]
-use crate::models::{
-    AceCycleStatus, AceCycleSummary, AceLane, AwarenessEvent, AwarenessEventType,
-    ContextBundleView, CycleSnapshotView, ExplainIndices, OutboxMessageView, TimelinePayload,
-};
+use crate::models::{CycleSnapshotView, OutboxMessageView, TimelinePayload};
 use crate::services::dialogue::{build_message_event, MessageEventDraft};
-use crate::state::{use_app_actions, use_app_state, AppActions, AppSignal, OperationStageKind};
+use crate::state::{
+    use_app_actions, use_app_state, AppActions, AppSignal, OperationKind, OperationStageKind,
+};
 #[cfg(target_arch = "wasm32")]
 use crate::API_CLIENT;
 use crate::APP_CONFIG;
@@ -62,6 +64,18 @@ impl CycleRunnerHandle {
             params,
         );
     }
+
+    /// 在 `last_cycle_id` 上从失败阶段恢复，而不是重新发送消息创建新周期
+    pub fn resume_stage(&self, stage: OperationStageKind) {
+        resume_stage_impl(
+            &self.actions,
+            &self.app_state,
+            self.is_running.clone(),
+            #[cfg(target_arch = "wasm32")]
+            self.stream_handle.clone(),
+            stage,
+        );
+    }
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -98,7 +112,7 @@ fn trigger_cycle_impl(
     params: CycleTriggerParams,
 ) {
     let Some(config) = APP_CONFIG.get() else {
-        actions.set_operation_error("缺少 Thin-Waist 配置".into());
+        actions.operation_reject(OperationKind::Trigger, "缺少 Thin-Waist 配置".into());
         return;
     };
 
@@ -110,7 +124,7 @@ fn trigger_cycle_impl(
             .or_else(|| config.default_tenant_id.clone())
     };
     let Some(tenant_id) = tenant_id else {
-        actions.set_operation_error("请先选择租户".into());
+        actions.operation_reject(OperationKind::Trigger, "请先选择租户".into());
         return;
     };
 
@@ -122,13 +136,10 @@ fn trigger_cycle_impl(
             .or_else(|| config.default_session_id.clone())
     };
     let Some(session_id) = session_id else {
-        actions.set_operation_error("请先选择会话".into());
+        actions.operation_reject(OperationKind::Trigger, "请先选择会话".into());
         return;
     };
 
-    actions.operation_stage_reset();
-    actions.set_operation_diagnostics(Vec::new(), None);
-
     let draft = MessageEventDraft {
         tenant_id: tenant_id.as_str(),
         session_id: session_id.as_str(),
@@ -148,11 +159,14 @@ fn trigger_cycle_impl(
     let message_event = match build_message_event(draft) {
         Ok(event) => event,
         Err(err) => {
-            actions.set_operation_error(format!("构造事件失败: {err}"));
+            actions.operation_reject(OperationKind::Trigger, format!("构造事件失败: {err}"));
             return;
         }
     };
 
+    let actions = &actions.operation_begin(OperationKind::Trigger);
+    actions.set_operation_diagnostics(Vec::new(), None);
+
     let thin_event: ThinDialogueEvent = message_event.clone().into();
     actions.append_timeline(vec![message_event.clone()], Vec::new(), None);
 
@@ -218,27 +232,12 @@ fn trigger_cycle_impl(
                         );
                         actions_async.set_operation_diagnostics(Vec::new(), None);
 
-                        // 将 Base36 字符串转换为 u64
-                        use soulseed_agi_core_models::AwarenessCycleId;
-                        use std::str::FromStr;
-
                         let cycle_id_label = data.cycle_id.clone();
-                        let cycle_id = match AwarenessCycleId::from_str(&data.cycle_id) {
-                            Ok(id) => id.as_u64(),
-                            Err(_) => {
-                                // 如果解析失败，可能已经是 u64 字符串
-                                match data.cycle_id.parse::<u64>() {
-                                    Ok(id) => id,
-                                    Err(_) => {
-                                        actions_async.set_operation_error(format!(
-                                            "无效的周期 ID: {}",
-                                            data.cycle_id
-                                        ));
-                                        is_running_async.set(false);
-                                        return;
-                                    }
-                                }
-                            }
+                        let Some(cycle_id) = parse_cycle_id(&data.cycle_id) else {
+                            actions_async
+                                .set_operation_error(format!("无效的周期 ID: {}", data.cycle_id));
+                            is_running_async.set(false);
+                            return;
                         };
                         actions_async.operation_stage_start(
                             OperationStageKind::StreamAwait,
@@ -298,6 +297,142 @@ fn trigger_cycle_impl(
     }
 }
 
+/// 周期 ID 可能是 Base36 字符串，也可能已经是 u64 字符串
+#[cfg(target_arch = "wasm32")]
+fn parse_cycle_id(label: &str) -> Option<u64> {
+    use soulseed_agi_core_models::AwarenessCycleId;
+    use std::str::FromStr;
+
+    AwarenessCycleId::from_str(label)
+        .map(|id| id.as_u64())
+        .ok()
+        .or_else(|| label.parse::<u64>().ok())
+}
+
+/// 可在现有周期上原地恢复的阶段；`TriggerSubmit` 失败时只能重新触发
+pub fn is_resumable_stage(kind: &OperationStageKind) -> bool {
+    matches!(
+        kind,
+        OperationStageKind::StreamAwait
+            | OperationStageKind::SnapshotRefresh
+            | OperationStageKind::ContextSync
+            | OperationStageKind::OutboxReady
+    )
+}
+
+fn resume_stage_impl(
+    actions: &AppActions,
+    app_state: &AppSignal,
+    #[allow(unused_mut)] mut is_running: Signal<bool>,
+    #[cfg(target_arch = "wasm32")] stream_handle: Signal<Option<SseHandle>>,
+    stage: OperationStageKind,
+) {
+    let cycle_label = app_state.read().operation.last_cycle_id.clone();
+    let Some(cycle_label) = cycle_label else {
+        actions.set_operation_error("当前操作没有关联周期，无法恢复".into());
+        return;
+    };
+
+    if *is_running.read() {
+        actions.set_operation_error(format!("周期 {cycle_label} 仍在处理中，请稍候再恢复"));
+        return;
+    }
+
+    if !is_resumable_stage(&stage) {
+        actions.set_operation_error(format!("阶段 {stage:?} 不支持原地恢复，请重新触发"));
+        return;
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    {
+        let tenant_id = app_state.read().tenant_id.clone().or_else(|| {
+            APP_CONFIG
+                .get()
+                .and_then(|cfg| cfg.default_tenant_id.clone())
+        });
+        let Some(tenant) = tenant_id else {
+            actions.set_operation_error("请先选择租户".into());
+            return;
+        };
+
+        let resume_context = format!("恢复觉知周期 #{cycle_label}");
+        actions.set_operation_context(Some(resume_context.clone()));
+
+        match stage {
+            OperationStageKind::StreamAwait => {
+                let Some(config) = APP_CONFIG.get() else {
+                    actions.set_operation_error("缺少 Thin-Waist 配置".into());
+                    return;
+                };
+                let Some(cycle_id) = parse_cycle_id(&cycle_label) else {
+                    actions.set_operation_error(format!("无效的周期 ID: {cycle_label}"));
+                    return;
+                };
+                actions.operation_stage_start(
+                    OperationStageKind::StreamAwait,
+                    format!("重新订阅周期 #{cycle_label}"),
+                );
+                // set_operation_success 会清空上下文，需在其后重新设置
+                actions.set_operation_success(format!("正在重新订阅周期 {cycle_label} 的事件流"));
+                actions.set_operation_context(Some(resume_context));
+                is_running.set(true);
+                start_cycle_stream(
+                    cycle_id,
+                    cycle_label,
+                    config.stream_endpoint(),
+                    actions.clone(),
+                    is_running,
+                    stream_handle,
+                    app_state.clone(),
+                );
+            }
+            OperationStageKind::SnapshotRefresh => {
+                let status = app_state
+                    .read()
+                    .operation
+                    .last_outcome
+                    .as_ref()
+                    .map(|outcome| outcome.status.clone())
+                    .unwrap_or_else(|| "resumed".into());
+                wasm_bindgen_futures::spawn_local(refresh_after_cycle(
+                    actions.clone(),
+                    app_state.clone(),
+                    cycle_label,
+                    status,
+                ));
+            }
+            OperationStageKind::ContextSync => {
+                let actions = actions.clone();
+                wasm_bindgen_futures::spawn_local(async move {
+                    let Some(client) = API_CLIENT.get().cloned() else {
+                        actions.set_operation_error("Thin-Waist 客户端未初始化".into());
+                        return;
+                    };
+                    if sync_context_bundle(&actions, &client, &tenant).await {
+                        actions
+                            .set_operation_success(format!("周期 {cycle_label} 上下文已重新同步"));
+                        actions.set_operation_context(Some(resume_context));
+                    }
+                });
+            }
+            OperationStageKind::OutboxReady => {
+                wasm_bindgen_futures::spawn_local(reload_cycle_outbox(
+                    actions.clone(),
+                    tenant,
+                    cycle_label,
+                ));
+            }
+            _ => {}
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let _ = cycle_label;
+        actions.set_operation_error("当前运行环境不支持恢复周期阶段".into());
+    }
+}
+
 #[cfg(target_arch = "wasm32")]
 fn start_cycle_stream(
     cycle_id: u64,
@@ -632,7 +767,7 @@ fn extract_schedule_status(payload: &str) -> Option<String> {
         })
 }
 
-fn record_client_error(
+pub(crate) fn record_client_error(
     actions: &AppActions,
     err: &ClientError,
     context: &str,
@@ -714,7 +849,8 @@ async fn refresh_after_cycle(
         return;
     };
 
-    let mut refresh_ok = true;
+    // 任一环节失败都记录原因，最终据此结束 SnapshotRefresh 阶段
+    let mut refresh_failure: Option<String> = None;
 
     actions.set_timeline_loading(true);
     actions.operation_stage_start(
@@ -742,7 +878,7 @@ async fn refresh_after_cycle(
                 actions.reset_timeline();
                 actions.set_timeline_error(Some("时间线返回空数据".into()));
                 actions.set_timeline_loading(false);
-                refresh_ok = false;
+                refresh_failure = Some("时间线返回空数据".into());
             }
         }
         Err(err) => {
@@ -754,56 +890,12 @@ async fn refresh_after_cycle(
                 Some(OperationStageKind::SnapshotRefresh),
             );
             actions.set_timeline_loading(false);
-            refresh_ok = false;
+            refresh_failure = Some(format!("刷新时间线失败: {err}"));
         }
     }
 
-    actions.set_context_loading(true);
-    actions.set_context_error(None);
-    let bundle_res = client
-        .get_context_bundle::<(), ContextBundleView>(&tenant, None)
-        .await;
-    let explain_res = client.get_explain_indices::<ExplainIndices>(&tenant).await;
-
-    match (bundle_res, explain_res) {
-        (Ok(bundle_env), Ok(explain_env)) => {
-            if bundle_env.data.is_none() {
-                actions.set_context_error(Some("ContextBundle 为空".into()));
-                actions.set_context_loading(false);
-                refresh_ok = false;
-            } else {
-                actions.set_context_bundle(bundle_env.data, explain_env.data);
-            }
-        }
-        (bundle, explain) => {
-            let mut message = String::new();
-            if let Err(err) = bundle {
-                record_client_error(
-                    &actions,
-                    &err,
-                    "context_bundle",
-                    "上下文加载失败",
-                    Some(OperationStageKind::SnapshotRefresh),
-                );
-                message.push_str(&format!("上下文加载失败: {err}"));
-            }
-            if let Err(err) = explain {
-                record_client_error(
-                    &actions,
-                    &err,
-                    "explain_indices",
-                    "Explain 指纹加载失败",
-                    Some(OperationStageKind::SnapshotRefresh),
-                );
-                if !message.is_empty() {
-                    message.push_str("；");
-                }
-                message.push_str(&format!("Explain 指纹加载失败: {err}"));
-            }
-            actions.set_context_error(Some(message));
-            actions.set_context_loading(false);
-            refresh_ok = false;
-        }
+    if !sync_context_bundle(&actions, &client, &tenant).await {
+        refresh_failure.get_or_insert_with(|| "上下文同步失败".into());
     }
 
     actions.set_ace_snapshot_loading(true);
@@ -866,156 +958,67 @@ async fn refresh_after_cycle(
                 OperationStageKind::OutboxReady,
                 Some(combined_message.clone()),
             );
-            actions
-                .operation_stage_fail(OperationStageKind::SnapshotRefresh, Some(combined_message));
-            refresh_ok = false;
+            refresh_failure.get_or_insert(combined_message);
         }
     }
 
     actions.set_ace_snapshot_loading(false);
 
-    if refresh_ok {
-        actions.operation_stage_complete(
-            OperationStageKind::SnapshotRefresh,
-            Some(format!("周期 {cycle_label} 状态 {status}")),
-        );
-        actions.set_operation_success(format!("周期 {cycle_label} 完成: {status}，视图已更新"));
-        actions.set_operation_cycle(Some(cycle_label.clone()));
-        actions.set_operation_context(Some(format!("觉知周期 #{cycle_label}")));
+    match refresh_failure {
+        None => {
+            actions.operation_stage_complete(
+                OperationStageKind::SnapshotRefresh,
+                Some(format!("周期 {cycle_label} 状态 {status}")),
+            );
+            actions.set_operation_success(format!("周期 {cycle_label} 完成: {status}，视图已更新"));
+            actions.set_operation_cycle(Some(cycle_label.clone()));
+            actions.set_operation_context(Some(format!("觉知周期 #{cycle_label}")));
+        }
+        Some(detail) => {
+            actions.operation_stage_fail(OperationStageKind::SnapshotRefresh, Some(detail));
+        }
     }
 
     // 刷新 ACE 周期列表，以便显示新的 Finalized 事件
-    refresh_ace_cycles_list(actions, tenant).await;
+    reload_ace_cycles(&actions, &app_state, &tenant).await;
 }
 
+/// 仅重新拉取已有周期的 Outbox，对应 `OutboxReady` 阶段
 #[cfg(target_arch = "wasm32")]
-async fn refresh_ace_cycles_list(actions: AppActions, tenant: String) {
-    actions.set_ace_loading(true);
-    actions.set_ace_error(None);
-
-    let client = match API_CLIENT.get().cloned() {
-        Some(client) => client,
-        None => {
-            actions.set_ace_error(Some("Thin-Waist 客户端未初始化".into()));
-            actions.set_ace_loading(false);
-            return;
-        }
+async fn reload_cycle_outbox(actions: AppActions, tenant: String, cycle_label: String) {
+    let Some(client) = API_CLIENT.get().cloned() else {
+        actions.set_operation_error("Thin-Waist 客户端未初始化".into());
+        return;
     };
 
+    actions.operation_stage_start(
+        OperationStageKind::OutboxReady,
+        format!("重新加载 Outbox #{cycle_label}"),
+    );
+
     match client
-        .get_awareness_events::<_, Vec<AwarenessEvent>>(&tenant, &AwarenessQuery { limit: 200 })
+        .get_cycle_outbox::<Vec<OutboxMessageView>>(&cycle_label, Some(&tenant))
         .await
     {
-        Ok(env) => {
-            if let Some(events) = env.data {
-                tracing::info!("refresh_ace_cycles_list: received {} events from API", events.len());
-                let mut grouped: HashMap<String, Vec<AwarenessEvent>> = HashMap::new();
-                for event in events {
-                    // Store cycle_id as numeric u64 string instead of Base36 for compatibility with backend
-                    let cycle_id = event.awareness_cycle_id.as_u64().to_string();
-                    grouped.entry(cycle_id).or_default().push(event);
-                }
-                tracing::info!("refresh_ace_cycles_list: grouped into {} cycles", grouped.len());
-
-                let mut summaries_with_ts: Vec<(i64, AceCycleSummary)> = grouped
-                    .into_iter()
-                    .map(|(cycle_id, mut items)| {
-                        items.sort_by_key(|evt| evt.occurred_at_ms);
-                        let latest_ts = items
-                            .iter()
-                            .map(|evt| evt.occurred_at_ms)
-                            .max()
-                            .unwrap_or_default();
-                        let anchor = items
-                            .first()
-                            .and_then(|evt| serde_json::to_value(&evt.anchor).ok());
-                        let lane = detect_lane(&items);
-                        let status = detect_status(&items);
-
-                        (
-                            latest_ts,
-                            AceCycleSummary {
-                                cycle_id,
-                                lane,
-                                status,
-                                anchor,
-                                budget: None,
-                                latest_sync_point: None,
-                                pending_injections: Vec::new(),
-                                decision_path: None,
-                                metadata: None,
-                            },
-                        )
-                    })
-                    .collect();
-
-                summaries_with_ts.sort_by_key(|(ts, _)| *ts);
-                summaries_with_ts.reverse();
-
-                let summaries: Vec<AceCycleSummary> = summaries_with_ts
-                    .into_iter()
-                    .map(|(_, summary)| summary)
-                    .collect();
-
-                actions.set_ace_cycles(summaries);
-            } else {
-                actions.set_ace_cycles(Vec::new());
-            }
-            actions.set_ace_loading(false);
+        Ok(outbox) => {
+            let outbox_count = outbox.len();
+            actions.store_ace_outbox(cycle_label.clone(), outbox);
+            actions.operation_stage_complete(
+                OperationStageKind::OutboxReady,
+                Some(format!("Outbox {} 条", outbox_count)),
+            );
+            actions.set_operation_success(format!("周期 {cycle_label} Outbox 已重新加载"));
         }
         Err(err) => {
-            tracing::error!("awareness events fetch failed: {err}");
-            actions.set_ace_error(Some(format!("ACE 数据加载失败: {err}")));
-            actions.set_ace_loading(false);
-        }
-    }
-}
-
-#[cfg(target_arch = "wasm32")]
-fn detect_lane(events: &[AwarenessEvent]) -> AceLane {
-    for event in events.iter().rev() {
-        if let Some(lane) = event.payload.get("lane").and_then(|value| value.as_str()) {
-            match lane {
-                "tool" | "tool_lane" => return AceLane::Tool,
-                "self_reason" | "self" => return AceLane::SelfReason,
-                "collab" | "collaboration" => return AceLane::Collab,
-                _ => return AceLane::Clarify,
-            }
+            record_client_error(
+                &actions,
+                &err,
+                "cycle_outbox",
+                "Outbox 加载失败",
+                Some(OperationStageKind::OutboxReady),
+            );
         }
     }
-    AceLane::Clarify
-}
-
-#[cfg(target_arch = "wasm32")]
-fn detect_status(events: &[AwarenessEvent]) -> AceCycleStatus {
-    let has_finalized = events
-        .iter()
-        .any(|event| matches!(event.event_type, AwarenessEventType::Finalized));
-
-    let has_rejected = events
-        .iter()
-        .any(|event| matches!(event.event_type, AwarenessEventType::Rejected));
-
-    let event_types: Vec<String> = events
-        .iter()
-        .map(|e| format!("{:?}", e.event_type))
-        .collect();
-
-    tracing::info!(
-        "detect_status (cycle_runner): events={}, has_finalized={}, has_rejected={}, event_types={:?}",
-        events.len(),
-        has_finalized,
-        has_rejected,
-        event_types
-    );
-
-    if has_finalized {
-        AceCycleStatus::Completed
-    } else if has_rejected {
-        AceCycleStatus::Failed
-    } else {
-        AceCycleStatus::Running
-    }
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -1120,7 +1123,7 @@ async fn verify_cycle_after_sse_disconnect(
                         Some("SSE 连接中断，周期仍在运行".into()),
                     );
                     actions.set_operation_error(format!(
-                        "周期 {} 仍在运行，但连接已断开。请刷新页面查看最新状态。",
+                        "周期 {} 仍在运行，但连接已断开。可在阶段列表中重新订阅。",
                         cycle_label
                     ));
                 }
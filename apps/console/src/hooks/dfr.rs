@@ -1,11 +1,18 @@
 //! DFR 决策增强 Hooks
 //!
-//! 提供 DFR 决策相关的 hooks，包括决策详情、指纹匹配等
+//! 提供 DFR 决策相关的 hooks，包括决策详情、决策检索、指纹匹配等
+
+use std::collections::HashMap;
 
 use dioxus::prelude::*;
 use gloo_timers::future::TimeoutFuture;
+use reqwest::StatusCode;
 
-use crate::models::{DecisionDetail, FingerprintListResponse, FingerprintMatchResult};
+use crate::hooks::ace::is_listing_unavailable;
+use crate::models::{
+    CycleSnapshotView, DecisionDetail, DecisionListQuery, DecisionListResponse, DecisionSummary,
+    FingerprintListResponse, FingerprintMatchResult,
+};
 use crate::state::use_app_state;
 use crate::{API_CLIENT, APP_CONFIG};
 
@@ -19,8 +26,8 @@ pub struct DfrState {
     pub match_result: Option<FingerprintMatchResult>,
 }
 
-/// DFR 决策详情 Hook
-pub fn use_dfr_decision(decision_id: String) -> Signal<DfrState> {
+/// DFR 决策详情 Hook，`decision_id` 变化时重新拉取
+pub fn use_dfr_decision(decision_id: Option<String>) -> Signal<DfrState> {
     let state_store = use_app_state();
     let mut state = use_signal(DfrState::default);
 
@@ -28,8 +35,7 @@ pub fn use_dfr_decision(decision_id: String) -> Signal<DfrState> {
     let tenant_id = snapshot.tenant_id.clone();
     drop(snapshot);
 
-    use_future(use_reactive!(|(tenant_id,)| {
-        let decision_id = decision_id.clone();
+    use_future(use_reactive!(|(tenant_id, decision_id)| {
         async move {
             TimeoutFuture::new(0).await;
 
@@ -44,18 +50,26 @@ pub fn use_dfr_decision(decision_id: String) -> Signal<DfrState> {
                 return;
             };
 
-            if decision_id.is_empty() {
-                state.write().decision = None;
+            let Some(decision_id) = decision_id.filter(|id| !id.is_empty()) else {
+                let mut current = state.write();
+                current.decision = None;
+                current.error = None;
+                current.loading = false;
                 return;
-            }
+            };
 
             let Some(client) = API_CLIENT.get().cloned() else {
                 state.write().error = Some("API 客户端未初始化".into());
                 return;
             };
 
-            state.write().loading = true;
-            state.write().error = None;
+            {
+                let mut current = state.write();
+                current.loading = true;
+                current.error = None;
+                // 不展示上一条决策的详情
+                current.decision = None;
+            }
 
             match client
                 .get_dfr_decision::<DecisionDetail>(&tenant, &decision_id)
@@ -77,6 +91,186 @@ pub fn use_dfr_decision(decision_id: String) -> Signal<DfrState> {
     state
 }
 
+/// 决策检索条件
+#[derive(Clone, Debug, PartialEq)]
+pub enum DecisionLookup {
+    /// 某个 ACE 周期产生的决策
+    Cycle(String),
+    /// 使用某个指纹的决策，快照中的解释指纹可能记录 ID 或哈希
+    Fingerprint {
+        fingerprint_id: String,
+        hash: String,
+    },
+}
+
+impl DecisionLookup {
+    fn query(&self) -> DecisionListQuery {
+        match self {
+            DecisionLookup::Cycle(cycle_id) => DecisionListQuery {
+                cycle_id: Some(cycle_id.clone()),
+                ..Default::default()
+            },
+            DecisionLookup::Fingerprint { fingerprint_id, .. } => DecisionListQuery {
+                fingerprint_id: Some(fingerprint_id.clone()),
+                limit: Some(DECISION_LOOKUP_LIMIT),
+                ..Default::default()
+            },
+        }
+    }
+
+    /// 列表接口不可用时的候选决策 ID：取周期 ID 本身，或解释指纹匹配的已加载周期，
+    /// 之后逐个按 ID 拉取详情校验
+    fn fallback_candidates(&self, snapshots: &HashMap<String, CycleSnapshotView>) -> Vec<String> {
+        let mut candidates: Vec<String> = match self {
+            DecisionLookup::Cycle(cycle_id) => vec![cycle_id.clone()],
+            DecisionLookup::Fingerprint {
+                fingerprint_id,
+                hash,
+            } => snapshots
+                .iter()
+                .filter(|(_, snapshot)| {
+                    snapshot
+                        .schedule
+                        .explain_fingerprint
+                        .as_ref()
+                        .is_some_and(|value| value == fingerprint_id || value == hash)
+                })
+                .map(|(cycle_id, _)| cycle_id.clone())
+                .collect(),
+        };
+        candidates.sort();
+        candidates
+    }
+
+    fn matches(&self, detail: &DecisionDetail) -> bool {
+        match self {
+            DecisionLookup::Cycle(cycle_id) => detail.cycle_id == *cycle_id,
+            DecisionLookup::Fingerprint {
+                fingerprint_id,
+                hash,
+            } => {
+                detail.fingerprint.fingerprint_id == *fingerprint_id
+                    || detail.fingerprint.hash == *hash
+            }
+        }
+    }
+}
+
+/// 按指纹检索时的决策条数上限
+const DECISION_LOOKUP_LIMIT: u32 = 20;
+
+/// 决策检索状态
+#[derive(Clone, Debug, Default)]
+pub struct DfrDecisionListState {
+    pub loading: bool,
+    pub error: Option<String>,
+    pub decisions: Vec<DecisionSummary>,
+    /// 后端未提供决策列表接口，结果仅来自已加载的 ACE 快照
+    pub from_fallback: bool,
+}
+
+/// 按周期或指纹检索决策 Hook，`lookup` 为空时不发请求。
+/// 后端尚未提供决策列表接口时，回退到已加载快照中的候选并按 ID 逐个校验
+pub fn use_dfr_decision_list(lookup: Option<DecisionLookup>) -> Signal<DfrDecisionListState> {
+    let state_store = use_app_state();
+    let mut state = use_signal(DfrDecisionListState::default);
+    let mut listing_unsupported = use_signal(|| false);
+
+    let snapshot = state_store.read();
+    let tenant_id = snapshot.tenant_id.clone();
+    let candidates = lookup
+        .as_ref()
+        .map(|lookup| lookup.fallback_candidates(&snapshot.ace.snapshots))
+        .unwrap_or_default();
+    drop(snapshot);
+
+    use_future(use_reactive!(|(tenant_id, lookup, candidates)| {
+        async move {
+            TimeoutFuture::new(0).await;
+
+            let Some(lookup) = lookup else {
+                state.set(DfrDecisionListState::default());
+                return;
+            };
+
+            let tenant = tenant_id.clone().or_else(|| {
+                APP_CONFIG
+                    .get()
+                    .and_then(|cfg| cfg.default_tenant_id.clone())
+            });
+
+            let Some(tenant) = tenant else {
+                state.write().error = Some("请先选择租户".into());
+                return;
+            };
+
+            let Some(client) = API_CLIENT.get().cloned() else {
+                state.write().error = Some("API 客户端未初始化".into());
+                return;
+            };
+
+            state.set(DfrDecisionListState {
+                loading: true,
+                ..Default::default()
+            });
+
+            if !*listing_unsupported.peek() {
+                match client
+                    .get_dfr_decisions::<DecisionListQuery, DecisionListResponse>(
+                        &tenant,
+                        Some(&lookup.query()),
+                    )
+                    .await
+                {
+                    Ok(env) => {
+                        state.set(DfrDecisionListState {
+                            decisions: env.data.map(|data| data.decisions).unwrap_or_default(),
+                            ..Default::default()
+                        });
+                        return;
+                    }
+                    Err(err) if is_listing_unavailable(&err) => {
+                        tracing::warn!("决策列表接口不可用，回退到已加载快照: {err}");
+                        listing_unsupported.set(true);
+                    }
+                    Err(err) => {
+                        tracing::error!("决策检索失败: {err}");
+                        state.set(DfrDecisionListState {
+                            error: Some(format!("加载失败: {err}")),
+                            ..Default::default()
+                        });
+                        return;
+                    }
+                }
+            }
+
+            let mut decisions = Vec::new();
+            for candidate in candidates {
+                match client
+                    .get_dfr_decision::<DecisionDetail>(&tenant, &candidate)
+                    .await
+                {
+                    Ok(env) => {
+                        if let Some(detail) = env.data.filter(|detail| lookup.matches(detail)) {
+                            decisions.push(DecisionSummary::from(&detail));
+                        }
+                    }
+                    Err(err) if err.status() == Some(StatusCode::NOT_FOUND) => {}
+                    Err(err) => tracing::warn!("候选决策 {candidate} 校验失败: {err}"),
+                }
+            }
+
+            state.set(DfrDecisionListState {
+                decisions,
+                from_fallback: true,
+                ..Default::default()
+            });
+        }
+    }));
+
+    state
+}
+
 /// 指纹列表 Hook
 pub fn use_fingerprint_list() -> Signal<Option<FingerprintListResponse>> {
     let state_store = use_app_state();
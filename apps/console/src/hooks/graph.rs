@@ -2,8 +2,8 @@ use dioxus::prelude::*;
 use gloo_timers::future::TimeoutFuture;
 use serde::Serialize;
 
-use crate::models::CausalGraphView;
-use crate::state::{use_app_actions, use_app_state};
+use crate::models::{CausalGraphView, RecallResultView};
+use crate::state::{use_app_actions, use_app_state, AppActions, AppSignal, RecallQuery};
 use crate::{API_CLIENT, APP_CONFIG};
 
 pub fn use_graph_insights() {
@@ -14,24 +14,35 @@ pub fn use_graph_insights() {
     let tenant = snapshot.tenant_id.clone();
     let session = snapshot.session_id.clone();
     let root = snapshot.graph.query.root_event_id;
+    let depth = snapshot.graph.query.effective_depth();
+    let direction = snapshot.graph.query.direction;
     drop(snapshot);
 
-    use_future(use_reactive!(|(tenant, session, root)| {
+    use_future(use_reactive!(|(
+        tenant,
+        session,
+        root,
+        depth,
+        direction,
+    )| {
         let actions = actions.clone();
         async move {
             tracing::info!(
-                "graph loader triggered: tenant={:?}, session={:?}, root={:?}",
+                "graph loader triggered: tenant={:?}, session={:?}, root={:?}, depth={}, direction={:?}",
                 tenant,
                 session,
-                root
+                root,
+                depth,
+                direction
             );
             if tenant.is_none() || session.is_none() {
-                actions.set_graph_data(None, Vec::new());
+                actions.set_graph_data(None);
+                actions.set_recall_results(Vec::new());
                 return;
             }
 
             let Some(root_id) = root else {
-                actions.set_graph_data(None, Vec::new());
+                actions.set_graph_data(None);
                 return;
             };
 
@@ -63,20 +74,12 @@ pub fn use_graph_insights() {
                 return;
             };
 
-            let (depth, scenario_filter) = {
-                let snapshot = state.read();
-                let depth = if snapshot.graph.query.depth == 0 {
-                    Some(3)
-                } else {
-                    Some(snapshot.graph.query.depth)
-                };
-                (depth, snapshot.scenario_filter.clone())
-            };
+            let scenario_filter = state.read().scenario_filter.clone();
 
             let query = CausalQuery {
                 root_event_id: root_id,
-                direction: "both",
-                depth,
+                direction: direction.as_str(),
+                depth: Some(depth),
                 scenario: scenario_filter.as_ref(),
             };
 
@@ -89,7 +92,7 @@ pub fn use_graph_insights() {
                 {
                     Ok(env) => {
                         if let Some(causal) = env.data {
-                            actions.set_graph_data(Some(causal), Vec::new());
+                            actions.set_graph_data(Some(causal));
                         } else {
                             actions.set_graph_error(Some("因果链路返回空数据".into()));
                             actions.set_graph_loading(false);
@@ -108,3 +111,73 @@ pub fn use_graph_insights() {
         }
     }));
 }
+
+/// 发起一次语义召回，结果写入 `GraphState::recall`
+pub async fn fetch_recall(actions: AppActions, state: AppSignal, query: RecallQuery) {
+    #[derive(Serialize)]
+    struct RecallParams<'a> {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        query: Option<&'a str>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        event_id: Option<u64>,
+        top_k: u16,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        scenario: Option<&'a soulseed_agi_core_models::ConversationScenario>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        session_id: Option<&'a str>,
+    }
+
+    if query.is_empty() {
+        actions.set_recall_error(Some("请输入召回文本或选择锚点事件".into()));
+        return;
+    }
+
+    let snapshot = state.read();
+    let tenant = snapshot.tenant_id.clone().or_else(|| {
+        APP_CONFIG
+            .get()
+            .and_then(|cfg| cfg.default_tenant_id.clone())
+    });
+    let session = snapshot.session_id.clone();
+    drop(snapshot);
+
+    let Some(tenant_id) = tenant else {
+        actions.set_recall_error(Some("请先选择租户".into()));
+        return;
+    };
+    let Some(client) = API_CLIENT.get().cloned() else {
+        actions.set_recall_error(Some("Thin-Waist 客户端未初始化".into()));
+        return;
+    };
+
+    actions.set_recall_query(query.clone());
+    actions.set_recall_loading(true);
+    actions.set_recall_error(None);
+
+    let params = RecallParams {
+        query: query
+            .text
+            .as_deref()
+            .map(str::trim)
+            .filter(|text| !text.is_empty()),
+        event_id: query.anchor_event_id,
+        top_k: query.effective_top_k(),
+        scenario: query.scenario.as_ref(),
+        session_id: session.as_deref(),
+    };
+
+    match client
+        .get_recall::<_, Vec<RecallResultView>>(&tenant_id, &params)
+        .await
+    {
+        Ok(env) => {
+            let mut results = env.data.unwrap_or_default();
+            results.sort_by(|a, b| b.score.total_cmp(&a.score));
+            actions.set_recall_results(results);
+        }
+        Err(err) => {
+            tracing::error!("recall fetch failed: {err}");
+            actions.set_recall_error(Some(format!("召回失败: {err}")));
+        }
+    }
+}
@@ -2,34 +2,62 @@
 //!
 //! 提供元认知分析相关的 hooks
 
+use std::collections::HashMap;
+
 use dioxus::prelude::*;
 use gloo_timers::future::TimeoutFuture;
 
-use crate::models::AnalysisResultResponse;
+use crate::models::{
+    AnalysisResultResponse, CausalChainQuery, CausalChainResponse, DecisionAuditResponse,
+    MetacognitionAnalysisQuery, PatternDetectionQuery, PatternDetectionResponse,
+    PerformanceProfileResponse,
+};
 use crate::state::use_app_state;
 use crate::{API_CLIENT, APP_CONFIG};
 
+/// 分析历史最多保留的记录数
+const ANALYSIS_HISTORY_LIMIT: usize = 10;
+
+/// 一次已完成的元认知分析
+#[derive(Clone, Debug)]
+pub struct AnalysisRun {
+    pub run_id: u32,
+    pub query: MetacognitionAnalysisQuery,
+    pub requested_at_ms: i64,
+    pub result: AnalysisResultResponse,
+}
+
 /// 元认知分析状态
 #[derive(Clone, Debug, Default)]
 pub struct MetacognitionState {
     pub loading: bool,
     pub error: Option<String>,
     pub analysis: Option<AnalysisResultResponse>,
+    /// 按时间顺序排列，最新的在末尾
+    pub history: Vec<AnalysisRun>,
 }
 
-/// 元认知分析 Hook
-pub fn use_metacognition_analysis() -> Signal<MetacognitionState> {
+/// 元认知分析 Hook，查询条件变化或 `run` 递增时重新分析
+pub fn use_metacognition_analysis(
+    query: MetacognitionAnalysisQuery,
+    run: u32,
+) -> Signal<MetacognitionState> {
     let state_store = use_app_state();
     let mut state = use_signal(MetacognitionState::default);
 
-    let snapshot = state_store.read();
-    let tenant_id = snapshot.tenant_id.clone();
-    let session_id = snapshot.session_id.clone();
-    drop(snapshot);
+    let tenant_id = state_store.read().tenant_id.clone();
+    let mut cached_tenant = use_signal(|| tenant_id.clone());
 
-    use_future(use_reactive!(|(tenant_id, session_id)| {
+    use_future(use_reactive!(|(tenant_id, query, run)| {
         async move {
             TimeoutFuture::new(0).await;
+            let _ = run;
+
+            // 不同租户的分析结果不做对比
+            if *cached_tenant.read() != tenant_id {
+                *state.write() = MetacognitionState::default();
+                cached_tenant.set(tenant_id.clone());
+            }
 
             let tenant = tenant_id.clone().or_else(|| {
                 APP_CONFIG
@@ -50,13 +78,29 @@ pub fn use_metacognition_analysis() -> Signal<MetacognitionState> {
             state.write().loading = true;
             state.write().error = None;
 
-            // 简化查询 - 不传递额外参数
+            let requested_at_ms = web_sys::js_sys::Date::now() as i64;
             match client
-                .get_metacognition_analysis::<(), AnalysisResultResponse>(&tenant, &())
+                .get_metacognition_analysis::<_, AnalysisResultResponse>(&tenant, &query)
                 .await
             {
                 Ok(env) => {
-                    state.write().analysis = env.data;
+                    let mut current = state.write();
+                    if let Some(ref result) = env.data {
+                        let run_id = current
+                            .history
+                            .last()
+                            .map(|entry| entry.run_id + 1)
+                            .unwrap_or(1);
+                        current.history.push(AnalysisRun {
+                            run_id,
+                            query: query.clone(),
+                            requested_at_ms,
+                            result: result.clone(),
+                        });
+                        let overflow = current.history.len().saturating_sub(ANALYSIS_HISTORY_LIMIT);
+                        current.history.drain(..overflow);
+                    }
+                    current.analysis = env.data;
                 }
                 Err(err) => {
                     tracing::error!("元认知分析加载失败: {err}");
@@ -70,3 +114,389 @@ pub fn use_metacognition_analysis() -> Signal<MetacognitionState> {
 
     state
 }
+
+/// 因果链查询状态
+#[derive(Clone, Debug, Default)]
+pub struct CausalChainState {
+    pub loading: bool,
+    pub error: Option<String>,
+    pub chain: Option<CausalChainResponse>,
+}
+
+/// 因果链 Hook，事件、方向或深度变化时重新加载
+pub fn use_causal_chain(
+    event_id: Option<String>,
+    direction: String,
+    max_depth: u32,
+) -> Signal<CausalChainState> {
+    let state_store = use_app_state();
+    let mut state = use_signal(CausalChainState::default);
+
+    let tenant_id = state_store.read().tenant_id.clone();
+
+    use_future(use_reactive!(|(
+        tenant_id,
+        event_id,
+        direction,
+        max_depth,
+    )| {
+        async move {
+            TimeoutFuture::new(0).await;
+
+            let Some(event_id) = event_id else {
+                *state.write() = CausalChainState::default();
+                return;
+            };
+
+            let tenant = tenant_id.clone().or_else(|| {
+                APP_CONFIG
+                    .get()
+                    .and_then(|cfg| cfg.default_tenant_id.clone())
+            });
+
+            let Some(tenant) = tenant else {
+                state.write().error = Some("请先选择租户".into());
+                return;
+            };
+
+            let Some(client) = API_CLIENT.get().cloned() else {
+                state.write().error = Some("API 客户端未初始化".into());
+                return;
+            };
+
+            state.write().loading = true;
+            state.write().error = None;
+
+            let query = CausalChainQuery {
+                direction: Some(direction.clone()),
+                max_depth: Some(max_depth),
+            };
+
+            match client
+                .get_metacognition_causal_chain::<CausalChainResponse>(
+                    &tenant,
+                    &event_id,
+                    Some(&query),
+                )
+                .await
+            {
+                Ok(env) => {
+                    state.write().chain = env.data;
+                }
+                Err(err) => {
+                    tracing::error!("因果链加载失败: {err}");
+                    let mut current = state.write();
+                    current.chain = None;
+                    current.error = Some(format!("加载失败: {err}"));
+                }
+            }
+
+            state.write().loading = false;
+        }
+    }));
+
+    state
+}
+
+/// 单个 AC 的性能剖析加载结果
+#[derive(Clone, Debug, Default)]
+pub struct ProfileLoad {
+    pub loading: bool,
+    pub error: Option<String>,
+    pub profile: Option<PerformanceProfileResponse>,
+}
+
+impl ProfileLoad {
+    /// 已成功加载，可直接复用；加载中或失败的条目在下次请求时重新拉取
+    fn is_cached(&self) -> bool {
+        !self.loading && self.error.is_none()
+    }
+}
+
+/// 性能剖析 Hook，按 AC ID 缓存成功的结果，只请求尚未加载成功的条目；
+/// `refresh` 变化时丢弃缓存全部重新拉取
+pub fn use_performance_profiles(
+    ac_ids: Vec<String>,
+    refresh: u32,
+) -> Signal<HashMap<String, ProfileLoad>> {
+    let state_store = use_app_state();
+    let mut profiles = use_signal(HashMap::<String, ProfileLoad>::new);
+
+    let tenant_id = state_store.read().tenant_id.clone();
+    let mut cached_tenant = use_signal(|| tenant_id.clone());
+    let mut cached_refresh = use_signal(|| refresh);
+
+    use_future(use_reactive!(|(tenant_id, ac_ids, refresh)| {
+        async move {
+            TimeoutFuture::new(0).await;
+
+            // 切换租户或手动刷新后缓存失效
+            if *cached_tenant.peek() != tenant_id || *cached_refresh.peek() != refresh {
+                profiles.write().clear();
+                cached_tenant.set(tenant_id.clone());
+                cached_refresh.set(refresh);
+            }
+
+            let tenant = tenant_id.clone().or_else(|| {
+                APP_CONFIG
+                    .get()
+                    .and_then(|cfg| cfg.default_tenant_id.clone())
+            });
+
+            for ac_id in ac_ids {
+                // 上一轮被中断的加载与失败结果都不算缓存，需要重新请求
+                if profiles
+                    .peek()
+                    .get(&ac_id)
+                    .is_some_and(ProfileLoad::is_cached)
+                {
+                    continue;
+                }
+
+                let Some(tenant) = tenant.clone() else {
+                    profiles.write().insert(
+                        ac_id,
+                        ProfileLoad {
+                            error: Some("请先选择租户".into()),
+                            ..ProfileLoad::default()
+                        },
+                    );
+                    continue;
+                };
+
+                let Some(client) = API_CLIENT.get().cloned() else {
+                    profiles.write().insert(
+                        ac_id,
+                        ProfileLoad {
+                            error: Some("API 客户端未初始化".into()),
+                            ..ProfileLoad::default()
+                        },
+                    );
+                    continue;
+                };
+
+                profiles.write().insert(
+                    ac_id.clone(),
+                    ProfileLoad {
+                        loading: true,
+                        ..ProfileLoad::default()
+                    },
+                );
+
+                let result = match client
+                    .get_metacognition_performance_profile::<PerformanceProfileResponse>(
+                        &tenant, &ac_id,
+                    )
+                    .await
+                {
+                    Ok(env) => ProfileLoad {
+                        profile: env.data,
+                        ..ProfileLoad::default()
+                    },
+                    Err(err) => {
+                        tracing::error!("性能剖析加载失败: {err}");
+                        ProfileLoad {
+                            error: Some(format!("加载失败: {err}")),
+                            ..ProfileLoad::default()
+                        }
+                    }
+                };
+                profiles.write().insert(ac_id, result);
+            }
+        }
+    }));
+
+    profiles
+}
+
+/// 决策审计状态
+#[derive(Clone, Debug, Default)]
+pub struct DecisionAuditState {
+    pub loading: bool,
+    pub error: Option<String>,
+    pub audit: Option<DecisionAuditResponse>,
+}
+
+/// 决策审计 Hook
+pub fn use_decision_audit(decision_id: Option<String>) -> Signal<DecisionAuditState> {
+    let state_store = use_app_state();
+    let mut state = use_signal(DecisionAuditState::default);
+
+    let tenant_id = state_store.read().tenant_id.clone();
+
+    use_future(use_reactive!(|(tenant_id, decision_id)| {
+        async move {
+            TimeoutFuture::new(0).await;
+
+            let Some(decision_id) = decision_id else {
+                *state.write() = DecisionAuditState::default();
+                return;
+            };
+
+            let tenant = tenant_id.clone().or_else(|| {
+                APP_CONFIG
+                    .get()
+                    .and_then(|cfg| cfg.default_tenant_id.clone())
+            });
+
+            let Some(tenant) = tenant else {
+                state.write().error = Some("请先选择租户".into());
+                return;
+            };
+
+            let Some(client) = API_CLIENT.get().cloned() else {
+                state.write().error = Some("API 客户端未初始化".into());
+                return;
+            };
+
+            state.write().loading = true;
+            state.write().error = None;
+
+            match client
+                .get_metacognition_decision_audit::<DecisionAuditResponse>(&tenant, &decision_id)
+                .await
+            {
+                Ok(env) => {
+                    state.write().audit = env.data;
+                }
+                Err(err) => {
+                    tracing::error!("决策审计加载失败: {err}");
+                    let mut current = state.write();
+                    current.audit = None;
+                    current.error = Some(format!("加载失败: {err}"));
+                }
+            }
+
+            state.write().loading = false;
+        }
+    }));
+
+    state
+}
+
+/// 健康度历史最多保留的采样数
+const HEALTH_HISTORY_LIMIT: usize = 30;
+
+/// 单次模式检测的健康度采样
+#[derive(Clone, Debug, PartialEq)]
+pub struct HealthSample {
+    pub recorded_at_ms: i64,
+    /// 采样时的筛选条件，只有条件相同的采样才可比较
+    pub filter_key: String,
+    pub health_score: f32,
+    pub total_patterns: u32,
+    pub total_anomalies: u32,
+}
+
+/// 模式检测状态
+#[derive(Clone, Debug, Default)]
+pub struct PatternDetectionState {
+    pub loading: bool,
+    pub error: Option<String>,
+    pub response: Option<PatternDetectionResponse>,
+    pub health_history: Vec<HealthSample>,
+}
+
+/// 模式检测筛选条件的稳定键
+pub fn pattern_filter_key(pattern_type: Option<&str>, session_id: Option<&str>) -> String {
+    format!(
+        "{}|{}",
+        pattern_type.unwrap_or("*"),
+        session_id.unwrap_or("*")
+    )
+}
+
+/// 模式检测 Hook，`run` 变化时强制重新检测，每次成功都会追加一条健康度采样
+pub fn use_pattern_detection(
+    pattern_type: Option<String>,
+    session_id: Option<String>,
+    limit: u32,
+    run: u32,
+) -> Signal<PatternDetectionState> {
+    let state_store = use_app_state();
+    let mut state = use_signal(PatternDetectionState::default);
+
+    let tenant_id = state_store.read().tenant_id.clone();
+    let mut cached_tenant = use_signal(|| tenant_id.clone());
+
+    use_future(use_reactive!(|(
+        tenant_id,
+        pattern_type,
+        session_id,
+        limit,
+        run,
+    )| {
+        async move {
+            TimeoutFuture::new(0).await;
+            let _ = run;
+
+            // 不同租户的健康度不可比较
+            if *cached_tenant.read() != tenant_id {
+                *state.write() = PatternDetectionState::default();
+                cached_tenant.set(tenant_id.clone());
+            }
+
+            let tenant = tenant_id.clone().or_else(|| {
+                APP_CONFIG
+                    .get()
+                    .and_then(|cfg| cfg.default_tenant_id.clone())
+            });
+
+            let Some(tenant) = tenant else {
+                state.write().error = Some("请先选择租户".into());
+                return;
+            };
+
+            let Some(client) = API_CLIENT.get().cloned() else {
+                state.write().error = Some("API 客户端未初始化".into());
+                return;
+            };
+
+            state.write().loading = true;
+            state.write().error = None;
+
+            let query = PatternDetectionQuery {
+                pattern_types: pattern_type.clone(),
+                session_id: session_id.clone(),
+                limit: Some(limit),
+            };
+
+            match client
+                .get_metacognition_patterns::<_, PatternDetectionResponse>(&tenant, Some(&query))
+                .await
+            {
+                Ok(env) => {
+                    let mut current = state.write();
+                    if let Some(ref response) = env.data {
+                        current.health_history.push(HealthSample {
+                            recorded_at_ms: web_sys::js_sys::Date::now() as i64,
+                            filter_key: pattern_filter_key(
+                                pattern_type.as_deref(),
+                                session_id.as_deref(),
+                            ),
+                            health_score: response.summary.health_score,
+                            total_patterns: response.summary.total_patterns,
+                            total_anomalies: response.summary.total_anomalies,
+                        });
+                        let overflow = current
+                            .health_history
+                            .len()
+                            .saturating_sub(HEALTH_HISTORY_LIMIT);
+                        current.health_history.drain(..overflow);
+                    }
+                    current.response = env.data;
+                }
+                Err(err) => {
+                    tracing::error!("模式检测加载失败: {err}");
+                    let mut current = state.write();
+                    current.response = None;
+                    current.error = Some(format!("加载失败: {err}"));
+                }
+            }
+
+            state.write().loading = false;
+        }
+    }));
+
+    state
+}
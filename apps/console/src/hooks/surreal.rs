@@ -5,10 +5,11 @@
 use dioxus::prelude::*;
 use gloo_timers::future::TimeoutFuture;
 
+use crate::hooks::cycle_runner::record_client_error;
 use crate::models::{
     IndexContentRequest, IndexContentResponse, TimeSeriesAggregateResponse, VectorSearchResponse,
 };
-use crate::state::use_app_state;
+use crate::state::{use_app_state, AppActions, AppSignal, OperationKind, OperationStageKind};
 use crate::{API_CLIENT, APP_CONFIG};
 
 /// 向量搜索状态
@@ -218,11 +219,13 @@ pub fn use_content_indexer() -> ContentIndexer {
     let indexing = use_signal(|| false);
     let error = use_signal(|| None::<String>);
     let last_result = use_signal(|| None::<IndexContentResponse>);
+    let app_state = use_app_state();
 
     ContentIndexer {
         indexing,
         error,
         last_result,
+        app_state,
     }
 }
 
@@ -232,6 +235,7 @@ pub struct ContentIndexer {
     pub indexing: Signal<bool>,
     pub error: Signal<Option<String>>,
     pub last_result: Signal<Option<IndexContentResponse>>,
+    app_state: AppSignal,
 }
 
 impl ContentIndexer {
@@ -267,6 +271,14 @@ impl ContentIndexer {
         self.indexing.set(true);
         self.error.set(None);
 
+        let actions = AppActions::from_signal(self.app_state);
+        let actions = actions.operation_begin(OperationKind::Index);
+        actions.set_operation_context(Some(format!("内容索引 {source_type}/{source_id}")));
+        actions.operation_stage_start(
+            OperationStageKind::IndexSubmit,
+            format!("索引 {} 字符", content.chars().count()),
+        );
+
         let request = IndexContentRequest {
             content,
             source_type,
@@ -283,10 +295,31 @@ impl ContentIndexer {
             .await
         {
             Ok(env) => {
+                actions.set_operation_trace(env.trace_id.clone());
+                if let Some(data) = env.data.as_ref() {
+                    actions.operation_stage_complete(
+                        OperationStageKind::IndexSubmit,
+                        Some(format!("chunk {} · {}", data.chunk_id, data.status)),
+                    );
+                    actions.set_operation_success(format!("内容已索引为 {}", data.chunk_id));
+                } else {
+                    actions.operation_stage_fail(
+                        OperationStageKind::IndexSubmit,
+                        Some("响应为空".into()),
+                    );
+                    actions.set_operation_error("内容索引返回空数据".into());
+                }
                 self.last_result.set(env.data);
             }
             Err(err) => {
                 tracing::error!("内容索引失败: {err}");
+                record_client_error(
+                    &actions,
+                    &err,
+                    "post_surreal_index_content",
+                    "内容索引失败",
+                    Some(OperationStageKind::IndexSubmit),
+                );
                 self.error.set(Some(format!("索引失败: {err}")));
             }
         }
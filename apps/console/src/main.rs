@@ -18,12 +18,13 @@ use state::AppState;
 use tracing::{error, info};
 use ui::ace::AcePanel;
 use ui::autonomous::AutonomousPanel;
+use ui::budget::BudgetPanel;
 use ui::context::ContextPanel;
 use ui::dfr::DfrPanel;
 use ui::evolution::EvolutionPanel;
 use ui::explain::ExplainDiagnosticPanel;
 use ui::graph::GraphPanel;
-use ui::graph_enhanced::GraphEnhancedPanel;
+use ui::graph_enhanced::{GraphEdgeBrowser, GraphEnhancedPanel};
 use ui::interaction::InteractionPanel;
 use ui::metacognition::MetacognitionPanel;
 use ui::notifications::NotificationCenter;
@@ -127,6 +128,7 @@ fn Dashboard() -> Element {
             TimelineView {}
             GraphPanel {}
             AcePanel {}
+            BudgetPanel {}
             ToolTracePanel {}
             ContextPanel {}
             ExplainDiagnosticPanel {}
@@ -156,6 +158,10 @@ fn Dashboard() -> Element {
                     div { class: "bg-white rounded-lg p-4 shadow",
                         GraphEnhancedPanel {}
                     }
+                    // 图谱边浏览
+                    div { class: "bg-white rounded-lg p-4 shadow",
+                        GraphEdgeBrowser {}
+                    }
                     // 演化事件
                     div { class: "bg-white rounded-lg p-4 shadow",
                         EvolutionPanel {}
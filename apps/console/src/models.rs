@@ -137,6 +137,31 @@ pub struct AceCycleSummary {
     pub metadata: Option<Value>,
 }
 
+/// `GET ace/cycles` 的查询参数，未设置的字段不会序列化
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct AceCycleListQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lane: Option<AceLane>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<AceCycleStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub since_ms: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub until_ms: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AceCycleListResponse {
+    #[serde(default)]
+    pub cycles: Vec<AceCycleSummary>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct CycleOutcomeSummary {
     #[serde(deserialize_with = "deserialize_string_or_number")]
@@ -409,6 +434,28 @@ pub struct BundleBudget {
     pub projected_tokens: u32,
 }
 
+/// `context/manifest/compact` 请求：压缩到目标 Token，仅作用于指定分区（为空表示全部）
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ContextCompactRequest {
+    pub target_tokens: u32,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub partitions: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub manifest_digest: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ContextCompactResponse {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub manifest_digest: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tokens_before: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tokens_after: Option<u32>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub compacted_partitions: Vec<String>,
+}
+
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct ExplainIndices {
     pub graph: ExplainSection,
@@ -570,7 +617,7 @@ pub struct PaginatedResponse<T> {
 // ---------------------- 元认知分析 ----------------------
 
 /// 元认知分析查询
-#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct MetacognitionAnalysisQuery {
     pub mode: Option<String>,
     pub ac_id: Option<String>,
@@ -897,7 +944,7 @@ pub struct AutonomousSessionSummary {
 }
 
 /// 执行日志条目
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ExecutionLogEntry {
     /// 周期 ID
     pub cycle_id: u64,
@@ -990,6 +1037,27 @@ pub struct AcStats {
     pub total_tokens: u64,
 }
 
+/// 追加议程项请求
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AddAgendaItemsRequest {
+    pub items: Vec<AgendaItemInput>,
+}
+
+/// 修改议程项请求，未设置的字段保持不变
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct UpdateAgendaItemRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<AgendaItemStatus>,
+}
+
+/// 重排议程请求，按新顺序列出全部待执行项
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ReorderAgendaRequest {
+    pub item_ids: Vec<String>,
+}
+
 /// 停止自主延续请求
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct StopAutonomousRequest {
@@ -1172,12 +1240,15 @@ pub struct GraphEdgeRef {
 }
 
 /// 图边查询
-#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct GraphEdgesQuery {
     pub edge_type: Option<String>,
     pub from_node: Option<String>,
     pub to_node: Option<String>,
     pub limit: Option<u32>,
+    /// 分页偏移，配合 `limit` 逐页加载
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub offset: Option<u32>,
 }
 
 /// 图边详情
@@ -1217,6 +1288,52 @@ pub struct DecisionDetail {
     pub outcome: Option<DecisionOutcome>,
 }
 
+/// 决策列表查询，按周期或指纹过滤
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct DecisionListQuery {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycle_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fingerprint_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+}
+
+/// 决策列表响应
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DecisionListResponse {
+    #[serde(default)]
+    pub decisions: Vec<DecisionSummary>,
+    #[serde(default)]
+    pub total: u32,
+}
+
+/// 决策摘要
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DecisionSummary {
+    pub decision_id: String,
+    #[serde(default)]
+    pub cycle_id: String,
+    #[serde(default)]
+    pub path: String,
+    #[serde(default)]
+    pub fingerprint_id: String,
+    #[serde(default)]
+    pub decided_at_ms: i64,
+}
+
+impl From<&DecisionDetail> for DecisionSummary {
+    fn from(detail: &DecisionDetail) -> Self {
+        Self {
+            decision_id: detail.decision_id.clone(),
+            cycle_id: detail.cycle_id.clone(),
+            path: detail.path.clone(),
+            fingerprint_id: detail.fingerprint.fingerprint_id.clone(),
+            decided_at_ms: detail.decided_at_ms,
+        }
+    }
+}
+
 /// 粘性决策信息
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct StickyDecisionInfo {
@@ -10,10 +10,10 @@ use wasm_bindgen::JsValue;
 use wasm_bindgen_futures::spawn_local;
 
 use crate::models::{
-    AceCycleStatus, AceCycleSummary, AceLane, AwarenessEvent, CausalGraphView, ContextBundleView,
-    ConversationScenario, CycleOutcomeSummary, CycleSnapshotView, DialogueEvent, ExplainIndices,
-    HitlInjection, ManifestDigestRecord, OutboxMessageView, RecallResultView, TenantWorkspace,
-    WorkspaceSession,
+    AceCycleStatus, AceCycleSummary, AceLane, AutonomousSessionSummary, AutonomousStatus,
+    AwarenessEvent, CausalGraphView, ContextBundleView, ConversationScenario, CycleOutcomeSummary,
+    CycleSnapshotView, DialogueEvent, ExecutionLogEntry, ExplainIndices, HitlInjection,
+    ManifestDigestRecord, OutboxMessageView, RecallResultView, TenantWorkspace, WorkspaceSession,
 };
 
 pub type AppSignal = Signal<AppState>;
@@ -60,6 +60,9 @@ pub struct TimelineState {
     pub filters: TimelineFilters,
     #[serde(default)]
     pub tags: HashMap<u64, Vec<String>>,
+    /// 由外部面板（如 Outbox 检查器）请求定位的事件；筛选条件变化时清空，避免其一直绕过筛选
+    #[serde(default)]
+    pub focused_event_id: Option<u64>,
 }
 
 impl TimelineState {
@@ -71,6 +74,7 @@ impl TimelineState {
         self.is_loading = false;
         self.query.reset_cursor();
         self.tags.clear();
+        self.focused_event_id = None;
     }
 }
 
@@ -326,6 +330,68 @@ impl ContextState {
     }
 }
 
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum AceTimeWindow {
+    LastHour,
+    LastDay,
+    LastWeek,
+}
+
+impl AceTimeWindow {
+    pub fn label(&self) -> &'static str {
+        match self {
+            AceTimeWindow::LastHour => "最近 1 小时",
+            AceTimeWindow::LastDay => "最近 24 小时",
+            AceTimeWindow::LastWeek => "最近 7 天",
+        }
+    }
+
+    pub fn duration_ms(&self) -> i64 {
+        match self {
+            AceTimeWindow::LastHour => 60 * 60 * 1000,
+            AceTimeWindow::LastDay => 24 * 60 * 60 * 1000,
+            AceTimeWindow::LastWeek => 7 * 24 * 60 * 60 * 1000,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AceCycleFilter {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lane: Option<AceLane>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<AceCycleStatus>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub window: Option<AceTimeWindow>,
+    /// 自定义区间起点（UTC 毫秒），仅在未选择预设窗口时生效
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub range_start_ms: Option<i64>,
+    /// 自定义区间终点（UTC 毫秒），仅在未选择预设窗口时生效
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub range_end_ms: Option<i64>,
+}
+
+impl AceCycleFilter {
+    pub fn is_empty(&self) -> bool {
+        self.lane.is_none()
+            && self.status.is_none()
+            && self.window.is_none()
+            && !self.has_custom_range()
+    }
+
+    pub fn has_custom_range(&self) -> bool {
+        self.range_start_ms.is_some() || self.range_end_ms.is_some()
+    }
+
+    /// 换算为查询用的 (since_ms, until_ms)：预设窗口以当前时间为终点，否则使用自定义区间
+    pub fn time_bounds(&self, now_ms: i64) -> (Option<i64>, Option<i64>) {
+        match self.window {
+            Some(window) => (Some(now_ms - window.duration_ms()), None),
+            None => (self.range_start_ms, self.range_end_ms),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct AceState {
     pub cycles: Vec<AceCycleSummary>,
@@ -340,6 +406,26 @@ pub struct AceState {
     pub snapshot_loading: bool,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub snapshot_error: Option<String>,
+    /// 谱系视图按需拉取祖先快照时正在加载的周期
+    #[serde(default)]
+    pub lineage_pending: BTreeSet<String>,
+    #[serde(default)]
+    pub lineage_errors: HashMap<String, String>,
+    /// 批量补齐快照时排队或正在加载的周期
+    #[serde(default)]
+    pub batch_pending: BTreeSet<String>,
+    #[serde(default)]
+    pub batch_errors: HashMap<String, String>,
+    #[serde(default)]
+    pub filter: AceCycleFilter,
+    /// 服务端列表接口返回的下一页游标；回退到事件推断时始终为空
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
+    #[serde(default)]
+    pub loading_more: bool,
+    /// 周期列表是否来自 awareness 事件推断（列表接口不可用）
+    #[serde(default)]
+    pub listing_fallback: bool,
 }
 
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
@@ -356,12 +442,93 @@ pub struct WorkspaceState {
     pub error: Option<String>,
 }
 
+/// 因果图默认展开深度，`GraphQuery::depth` 为 0 时使用
+pub const DEFAULT_GRAPH_DEPTH: u8 = 3;
+
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct GraphQuery {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub root_event_id: Option<u64>,
     #[serde(default)]
     pub depth: u8,
+    #[serde(default)]
+    pub direction: GraphDirection,
+}
+
+impl GraphQuery {
+    pub fn effective_depth(&self) -> u8 {
+        if self.depth == 0 {
+            DEFAULT_GRAPH_DEPTH
+        } else {
+            self.depth
+        }
+    }
+}
+
+pub const DEFAULT_RECALL_TOP_K: u16 = 10;
+
+/// 语义召回查询：文本与锚点事件至少提供其一
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct RecallQuery {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub anchor_event_id: Option<u64>,
+    #[serde(default)]
+    pub top_k: u16,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scenario: Option<ConversationScenario>,
+}
+
+impl RecallQuery {
+    pub fn effective_top_k(&self) -> u16 {
+        if self.top_k == 0 {
+            DEFAULT_RECALL_TOP_K
+        } else {
+            self.top_k
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.anchor_event_id.is_none()
+            && self
+                .text
+                .as_deref()
+                .is_none_or(|text| text.trim().is_empty())
+    }
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum GraphDirection {
+    Both,
+    Upstream,
+    Downstream,
+}
+
+impl Default for GraphDirection {
+    fn default() -> Self {
+        GraphDirection::Both
+    }
+}
+
+impl GraphDirection {
+    /// 因果链查询参数 `direction` 的取值
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GraphDirection::Both => "both",
+            GraphDirection::Upstream => "upstream",
+            GraphDirection::Downstream => "downstream",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            GraphDirection::Both => "双向",
+            GraphDirection::Upstream => "仅上游",
+            GraphDirection::Downstream => "仅下游",
+        }
+    }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
@@ -373,6 +540,9 @@ pub enum OperationStageKind {
     OutboxReady,
     HitlSubmit,
     ContextSync,
+    AutonomousSubmit,
+    IndexSubmit,
+    CompactionSubmit,
     Unknown,
 }
 
@@ -415,10 +585,122 @@ pub struct OperationStage {
     pub finished_at_epoch_ms: Option<u128>,
 }
 
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum OperationKind {
+    Trigger,
+    Injection,
+    AutonomousStart,
+    AutonomousStop,
+    AutonomousAdjust,
+    Index,
+    Compaction,
+}
+
+impl Default for OperationKind {
+    fn default() -> Self {
+        OperationKind::Trigger
+    }
+}
+
+impl OperationKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            OperationKind::Trigger => "觉知周期触发",
+            OperationKind::Injection => "HITL 注入",
+            OperationKind::AutonomousStart => "启动自主延续",
+            OperationKind::AutonomousStop => "终止自主延续",
+            OperationKind::AutonomousAdjust => "调整自主延续",
+            OperationKind::Index => "内容索引",
+            OperationKind::Compaction => "上下文压缩",
+        }
+    }
+
+    /// 该类操作预期经历的阶段，用于在阶段尚未开始时占位展示
+    pub fn expected_stages(&self) -> Vec<OperationStageKind> {
+        match self {
+            OperationKind::Trigger => vec![
+                OperationStageKind::TriggerSubmit,
+                OperationStageKind::StreamAwait,
+                OperationStageKind::SnapshotRefresh,
+                OperationStageKind::ContextSync,
+                OperationStageKind::OutboxReady,
+            ],
+            OperationKind::Injection => vec![OperationStageKind::HitlSubmit],
+            OperationKind::AutonomousStart
+            | OperationKind::AutonomousStop
+            | OperationKind::AutonomousAdjust => {
+                vec![OperationStageKind::AutonomousSubmit]
+            }
+            OperationKind::Index => vec![OperationStageKind::IndexSubmit],
+            OperationKind::Compaction => vec![
+                OperationStageKind::CompactionSubmit,
+                OperationStageKind::ContextSync,
+            ],
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum OperationOutcome {
+    Succeeded,
+    Failed,
+    Interrupted,
+}
+
+impl Default for OperationOutcome {
+    fn default() -> Self {
+        OperationOutcome::Succeeded
+    }
+}
+
+/// 已归档的一次操作，保留阶段时间线与诊断信息
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct OperationRecord {
+    pub id: u64,
+    pub kind: OperationKind,
+    pub outcome: OperationOutcome,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub triggered_at: Option<String>,
+    pub archived_at: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tenant_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub session_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycle_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trace_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub http_status: Option<u16>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error_code: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycle_outcome: Option<CycleOutcomeSummary>,
+    #[serde(default)]
+    pub stages: Vec<OperationStage>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total_elapsed_ms: Option<u64>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct StageDurationStats {
+    pub kind: OperationStageKind,
+    pub samples: usize,
+    pub p50_ms: Option<u64>,
+    pub p95_ms: Option<u64>,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum AuditActionKind {
     Copy,
     Export,
+    /// 客户端护栏触发的自动终止
+    Terminate,
 }
 
 impl Default for AuditActionKind {
@@ -455,6 +737,15 @@ pub struct GraphState {
     pub recall: Vec<RecallResultView>,
     pub is_loading: bool,
     pub error: Option<String>,
+    #[serde(default)]
+    pub recall_query: RecallQuery,
+    #[serde(default)]
+    pub recall_loading: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recall_error: Option<String>,
+    /// 固定到因果图中展示的召回事件，保存完整结果，不随召回结果刷新而丢失
+    #[serde(default)]
+    pub pinned_recall: Vec<RecallResultView>,
 }
 
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
@@ -480,11 +771,637 @@ pub struct OperationState {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub current_stage: Option<OperationStageKind>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub total_elapsed_ms: Option<u64>,
+    pub total_elapsed_ms: Option<u64>,
+    #[serde(default)]
+    pub last_indices_used: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_budget: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub current_kind: Option<OperationKind>,
+    #[serde(default)]
+    pub history: Vec<OperationRecord>,
+    #[serde(default)]
+    pub next_record_id: u64,
+    /// 当前操作的 ID，归档后即 `OperationRecord::id`；未经 `operation_begin` 的状态提示为空
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub current_id: Option<u64>,
+    /// 被新操作顶替但仍在进行的操作，只使用其中的当前操作字段，结束后归档
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub background: Vec<OperationState>,
+}
+
+impl OperationState {
+    const HISTORY_LIMIT: usize = 50;
+
+    fn has_run(&self) -> bool {
+        !self.stages.is_empty() || self.current_kind.is_some()
+    }
+
+    /// 操作已开始且尚未得出结果：有阶段在进行，或尚未进入任何阶段（提交前的提示也算进行中）
+    pub fn is_running(&self) -> bool {
+        if self.current_kind.is_none() || self.error.is_some() {
+            return false;
+        }
+        self.current_stage.is_some()
+            || self.stages.iter().any(|stage| {
+                matches!(
+                    stage.status,
+                    OperationStageStatus::Running | OperationStageStatus::Pending
+                )
+            })
+            || self.stages.is_empty()
+    }
+
+    /// 后台操作得出最终结果后即可归档
+    fn is_finished(&self) -> bool {
+        !self.is_running() && (self.error.is_some() || self.last_message.is_some())
+    }
+
+    fn to_record(
+        &self,
+        id: u64,
+        tenant_id: Option<String>,
+        session_id: Option<String>,
+    ) -> OperationRecord {
+        let outcome = if self.error.is_some()
+            || self
+                .stages
+                .iter()
+                .any(|stage| stage.status == OperationStageStatus::Failed)
+        {
+            OperationOutcome::Failed
+        } else if self.current_stage.is_some()
+            || self.stages.iter().any(|stage| {
+                matches!(
+                    stage.status,
+                    OperationStageStatus::Running | OperationStageStatus::Pending
+                )
+            })
+        {
+            OperationOutcome::Interrupted
+        } else {
+            OperationOutcome::Succeeded
+        };
+
+        OperationRecord {
+            id,
+            kind: self.current_kind.clone().unwrap_or_default(),
+            outcome,
+            message: self.error.clone().or_else(|| self.last_message.clone()),
+            context: self.context.clone(),
+            triggered_at: self.triggered_at.clone(),
+            archived_at: now_iso_timestamp(),
+            tenant_id,
+            session_id,
+            cycle_id: self.last_cycle_id.clone(),
+            trace_id: self.trace_id.clone(),
+            http_status: self.last_status,
+            error_code: self.error_code.clone(),
+            cycle_outcome: self.last_outcome.clone(),
+            stages: self.stages.clone(),
+            total_elapsed_ms: self.total_elapsed_ms,
+        }
+    }
+
+    fn push_history(&mut self, record: OperationRecord) {
+        self.history.insert(0, record);
+        if self.history.len() > Self::HISTORY_LIMIT {
+            self.history.truncate(Self::HISTORY_LIMIT);
+        }
+    }
+
+    /// 取出当前操作字段并重置当前操作，历史与后台操作保留
+    fn take_current(&mut self) -> OperationState {
+        let history = std::mem::take(&mut self.history);
+        let background = std::mem::take(&mut self.background);
+        let next_record_id = self.next_record_id;
+        let current = std::mem::take(self);
+        self.history = history;
+        self.background = background;
+        self.next_record_id = next_record_id;
+        current
+    }
+
+    /// 将当前操作归档到历史中，并清空当前阶段与诊断信息（历史与后台操作保留）
+    fn archive_current(&mut self, tenant_id: Option<String>, session_id: Option<String>) {
+        let current = self.take_current();
+        if current.has_run() {
+            let id = current.current_id.unwrap_or_else(|| {
+                let id = self.next_record_id;
+                self.next_record_id = id.saturating_add(1);
+                id
+            });
+            self.push_history(current.to_record(id, tenant_id, session_id));
+        }
+    }
+
+    /// 归档当前与后台的全部操作，未完成的记为中断
+    fn archive_all(&mut self, tenant_id: Option<String>, session_id: Option<String>) {
+        self.archive_current(tenant_id.clone(), session_id.clone());
+        for operation in std::mem::take(&mut self.background) {
+            let id = operation.current_id.unwrap_or_default();
+            self.push_history(operation.to_record(id, tenant_id.clone(), session_id.clone()));
+        }
+    }
+
+    fn stage_start(&mut self, kind: OperationStageKind, label: String) {
+        let now_label = now_iso_timestamp();
+        let now_epoch = now_epoch_ms();
+
+        if let Some(stage) = self.stages.iter_mut().find(|stage| stage.kind == kind) {
+            stage.status = OperationStageStatus::Running;
+            stage.label = label;
+            if stage.started_at.is_none() {
+                stage.started_at = Some(now_label);
+            }
+            stage.started_at_epoch_ms = Some(now_epoch);
+            stage.finished_at = None;
+            stage.finished_at_epoch_ms = None;
+            stage.duration_ms = None;
+            stage.detail = None;
+        } else {
+            let mut stage = OperationStage::default();
+            stage.kind = kind.clone();
+            stage.label = label;
+            stage.status = OperationStageStatus::Running;
+            stage.started_at = Some(now_label);
+            stage.started_at_epoch_ms = Some(now_epoch);
+            self.stages.push(stage);
+        }
+
+        self.current_stage = Some(kind);
+    }
+
+    /// 以完成或失败结束阶段，并重新汇总总耗时
+    fn stage_finish(
+        &mut self,
+        kind: OperationStageKind,
+        status: OperationStageStatus,
+        detail: Option<String>,
+    ) {
+        let now_label = now_iso_timestamp();
+        let now_epoch = now_epoch_ms();
+
+        if let Some(stage) = self.stages.iter_mut().find(|stage| stage.kind == kind) {
+            stage.status = status;
+            stage.finished_at = Some(now_label);
+            stage.finished_at_epoch_ms = Some(now_epoch);
+            stage.detail = detail;
+            if let Some(start_epoch) = stage.started_at_epoch_ms {
+                let duration = now_epoch.saturating_sub(start_epoch);
+                stage.duration_ms = Some((duration.min(u64::MAX as u128)) as u64);
+            }
+        } else {
+            let mut stage = OperationStage::default();
+            stage.kind = kind.clone();
+            stage.status = status;
+            stage.finished_at = Some(now_label);
+            stage.finished_at_epoch_ms = Some(now_epoch);
+            stage.detail = detail;
+            self.stages.push(stage);
+        }
+
+        if self.current_stage.as_ref() == Some(&kind) {
+            self.current_stage = None;
+        }
+
+        let total: u64 = self
+            .stages
+            .iter()
+            .filter_map(|stage| stage.duration_ms)
+            .sum();
+        self.total_elapsed_ms = if total > 0 { Some(total) } else { None };
+    }
+
+    /// 统计当前及历史操作中各阶段耗时的 p50 / p95
+    pub fn stage_duration_stats(&self, kinds: &[OperationStageKind]) -> Vec<StageDurationStats> {
+        kinds
+            .iter()
+            .map(|kind| {
+                let mut samples: Vec<u64> = self
+                    .stages
+                    .iter()
+                    .chain(self.history.iter().flat_map(|record| record.stages.iter()))
+                    .filter(|stage| {
+                        &stage.kind == kind && stage.status == OperationStageStatus::Completed
+                    })
+                    .filter_map(|stage| stage.duration_ms)
+                    .collect();
+                samples.sort_unstable();
+
+                StageDurationStats {
+                    kind: kind.clone(),
+                    samples: samples.len(),
+                    p50_ms: percentile(&samples, 50),
+                    p95_ms: percentile(&samples, 95),
+                }
+            })
+            .collect()
+    }
+}
+
+/// 最近秩法求百分位，输入需已升序排列
+fn percentile(sorted: &[u64], pct: usize) -> Option<u64> {
+    if sorted.is_empty() {
+        return None;
+    }
+    let rank = (pct * sorted.len()).div_ceil(100).max(1);
+    sorted.get(rank - 1).copied()
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct BudgetAlertConfig {
+    pub enabled: bool,
+    /// 实际消耗超过额度的百分比阈值（tokens / walltime / external cost 任一）
+    pub usage_pct: u32,
+    /// 路由预估与实际 tokens 的偏差百分比阈值
+    pub estimate_drift_pct: u32,
+}
+
+impl Default for BudgetAlertConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            usage_pct: 90,
+            estimate_drift_pct: 50,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum BudgetMetric {
+    Tokens,
+    Walltime,
+    ExternalCost,
+}
+
+impl BudgetMetric {
+    pub fn label(&self) -> &'static str {
+        match self {
+            BudgetMetric::Tokens => "Tokens",
+            BudgetMetric::Walltime => "Walltime",
+            BudgetMetric::ExternalCost => "外部成本",
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub enum BudgetAlertKind {
+    Overspend(BudgetMetric),
+    EstimateDrift(BudgetMetric),
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BudgetAlert {
+    /// `cycle_id` + 告警类型，用于重新评估时去重
+    pub key: String,
+    pub cycle_id: String,
+    pub kind: BudgetAlertKind,
+    pub observed_pct: f32,
+    pub message: String,
+    #[serde(default)]
+    pub toast_dismissed: bool,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct BudgetState {
+    #[serde(default)]
+    pub config: BudgetAlertConfig,
+    #[serde(default)]
+    pub alerts: Vec<BudgetAlert>,
+}
+
+impl BudgetState {
+    /// 根据周期快照重新评估告警，保留已关闭提示的状态
+    fn evaluate_cycle(&mut self, cycle_id: &str, snapshot: &CycleSnapshotView) {
+        let dismissed: BTreeSet<String> = self
+            .alerts
+            .iter()
+            .filter(|alert| alert.cycle_id == cycle_id && alert.toast_dismissed)
+            .map(|alert| alert.key.clone())
+            .collect();
+        self.alerts.retain(|alert| alert.cycle_id != cycle_id);
+
+        if !self.config.enabled {
+            return;
+        }
+
+        let budget = &snapshot.schedule.budget;
+        let usage = [
+            (
+                BudgetMetric::Tokens,
+                budget.tokens_spent as f64,
+                budget.tokens_allowed as f64,
+            ),
+            (
+                BudgetMetric::Walltime,
+                budget.walltime_ms_used as f64,
+                budget.walltime_ms_allowed as f64,
+            ),
+            (
+                BudgetMetric::ExternalCost,
+                budget.external_cost_spent as f64,
+                budget.external_cost_allowed as f64,
+            ),
+        ];
+
+        let mut fresh = Vec::new();
+        for (metric, spent, allowed) in usage.iter().copied() {
+            if allowed <= 0.0 {
+                continue;
+            }
+            let pct = (spent / allowed * 100.0) as f32;
+            if pct >= self.config.usage_pct as f32 {
+                fresh.push(BudgetAlert {
+                    key: format!("{cycle_id}:overspend:{metric:?}"),
+                    cycle_id: cycle_id.to_string(),
+                    kind: BudgetAlertKind::Overspend(metric),
+                    observed_pct: pct,
+                    message: format!(
+                        "周期 {cycle_id} {} 已使用 {pct:.0}%（阈值 {}%）",
+                        metric.label(),
+                        self.config.usage_pct
+                    ),
+                    toast_dismissed: false,
+                });
+            }
+        }
+
+        // 预估偏差仅在周期产出 outcome 后评估，避免运行中的部分消耗误报
+        if let Some(decision) = snapshot
+            .schedule
+            .router_decision
+            .as_ref()
+            .filter(|_| !snapshot.outcomes.is_empty())
+        {
+            let estimates = [
+                decision.plan.budget.tokens as f64,
+                decision.plan.budget.walltime_ms as f64,
+                decision.plan.budget.external_cost as f64,
+            ];
+            for ((metric, spent, _), estimate) in usage.into_iter().zip(estimates) {
+                if estimate <= 0.0 {
+                    continue;
+                }
+                let drift = ((spent - estimate).abs() / estimate * 100.0) as f32;
+                if drift >= self.config.estimate_drift_pct as f32 {
+                    fresh.push(BudgetAlert {
+                        key: format!("{cycle_id}:drift:{metric:?}"),
+                        cycle_id: cycle_id.to_string(),
+                        kind: BudgetAlertKind::EstimateDrift(metric),
+                        observed_pct: drift,
+                        message: format!(
+                            "周期 {cycle_id} {} 实际 {} 与预估 {} 偏差 {drift:.0}%（阈值 {}%）",
+                            metric.label(),
+                            format_budget_amount(metric, spent),
+                            format_budget_amount(metric, estimate),
+                            self.config.estimate_drift_pct
+                        ),
+                        toast_dismissed: false,
+                    });
+                }
+            }
+        }
+
+        for mut alert in fresh {
+            alert.toast_dismissed = dismissed.contains(&alert.key);
+            self.alerts.push(alert);
+        }
+    }
+}
+
+fn format_budget_amount(metric: BudgetMetric, value: f64) -> String {
+    match metric {
+        BudgetMetric::Tokens => format!("{value:.0}"),
+        BudgetMetric::Walltime => format!("{value:.0} ms"),
+        BudgetMetric::ExternalCost => format!("{value:.2}"),
+    }
+}
+
+/// 自主延续客户端护栏配置，各阈值为 0 时关闭对应规则
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct GuardrailConfig {
+    pub enabled: bool,
+    /// 最近日志窗口内的成本速率上限（$/分钟）
+    pub cost_per_minute: f64,
+    /// 连续 `failed` 执行日志条数上限
+    pub max_consecutive_failures: u32,
+    /// 连续相同消息条数上限，用于识别原地打转
+    pub max_repeated_messages: u32,
+    /// 单周期 token 上限
+    pub max_tokens_per_cycle: u64,
+    /// 触发后是否自动调用终止接口
+    pub auto_terminate: bool,
+}
+
+impl Default for GuardrailConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            cost_per_minute: 0.5,
+            max_consecutive_failures: 3,
+            max_repeated_messages: 4,
+            max_tokens_per_cycle: 50_000,
+            auto_terminate: false,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum GuardrailRule {
+    CostRate,
+    ConsecutiveFailures,
+    RepeatedMessages,
+    TokensPerCycle,
+}
+
+impl GuardrailRule {
+    pub fn label(&self) -> &'static str {
+        match self {
+            GuardrailRule::CostRate => "成本速率",
+            GuardrailRule::ConsecutiveFailures => "连续失败",
+            GuardrailRule::RepeatedMessages => "重复消息",
+            GuardrailRule::TokensPerCycle => "单周期 Token",
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct GuardrailViolation {
+    /// `orchestration_id` + 规则，用于重新评估时去重
+    pub key: String,
+    pub orchestration_id: String,
+    pub rule: GuardrailRule,
+    pub observed: f64,
+    pub threshold: f64,
+    pub message: String,
+    pub detected_at: String,
+    #[serde(default)]
+    pub toast_dismissed: bool,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct GuardrailState {
+    #[serde(default)]
+    pub config: GuardrailConfig,
+    #[serde(default)]
+    pub violations: Vec<GuardrailViolation>,
+    /// 护栏终止请求已被后端确认的会话
+    #[serde(default)]
+    pub enforced: BTreeSet<String>,
+    /// 护栏已提交、尚未返回结果的终止请求（会话 → 原因），避免轮询期间重复提交
+    #[serde(skip)]
+    pub pending: HashMap<String, String>,
+}
+
+impl GuardrailState {
+    /// 评估所有活动会话；已结束的会话不再产生告警，沿用已有告警的检测时间与提示状态
+    fn evaluate(&self, sessions: &[AutonomousSessionSummary]) -> Vec<GuardrailViolation> {
+        if !self.config.enabled {
+            return Vec::new();
+        }
+        let mut fresh = Vec::new();
+        for session in sessions.iter().filter(|session| {
+            matches!(
+                session.status,
+                AutonomousStatus::Starting | AutonomousStatus::Running
+            )
+        }) {
+            for (rule, observed, threshold, message) in guardrail_checks(&self.config, session) {
+                let key = format!("{}:{rule:?}", session.orchestration_id);
+                let previous = self.violations.iter().find(|existing| existing.key == key);
+                fresh.push(GuardrailViolation {
+                    orchestration_id: session.orchestration_id.clone(),
+                    rule,
+                    observed,
+                    threshold,
+                    message,
+                    detected_at: previous
+                        .map(|existing| existing.detected_at.clone())
+                        .unwrap_or_else(now_iso_timestamp),
+                    toast_dismissed: previous.is_some_and(|existing| existing.toast_dismissed),
+                    key,
+                });
+            }
+        }
+        fresh
+    }
+}
+
+/// 基于最近执行日志逐条检查护栏规则，返回 (规则, 观测值, 阈值, 描述)
+fn guardrail_checks(
+    config: &GuardrailConfig,
+    session: &AutonomousSessionSummary,
+) -> Vec<(GuardrailRule, f64, f64, String)> {
+    let mut logs: Vec<&ExecutionLogEntry> = session.recent_logs.iter().collect();
+    logs.sort_by_key(|log| (log.timestamp_ms, log.cycle_id));
+    let mut checks = Vec::new();
+
+    if config.cost_per_minute > 0.0 {
+        if let (Some(first), Some(last)) = (logs.first(), logs.last()) {
+            let span_ms = last.timestamp_ms - first.timestamp_ms;
+            if span_ms > 0 {
+                // 首条日志的成本发生在窗口起点之前，不计入速率
+                let cost: f64 = logs.iter().skip(1).map(|log| log.cost).sum();
+                let rate = cost / (span_ms as f64 / 60_000.0);
+                if rate > config.cost_per_minute {
+                    checks.push((
+                        GuardrailRule::CostRate,
+                        rate,
+                        config.cost_per_minute,
+                        format!(
+                            "成本速率 ${rate:.4}/分钟 超过阈值 ${:.4}/分钟",
+                            config.cost_per_minute
+                        ),
+                    ));
+                }
+            }
+        }
+    }
+
+    if config.max_consecutive_failures > 0 {
+        let failures = logs
+            .iter()
+            .rev()
+            .take_while(|log| log.status.eq_ignore_ascii_case("failed"))
+            .count() as u32;
+        if failures >= config.max_consecutive_failures {
+            checks.push((
+                GuardrailRule::ConsecutiveFailures,
+                failures as f64,
+                config.max_consecutive_failures as f64,
+                format!(
+                    "连续 {failures} 个周期失败（阈值 {}）",
+                    config.max_consecutive_failures
+                ),
+            ));
+        }
+    }
+
+    if config.max_repeated_messages > 0 {
+        if let Some(latest) = logs
+            .last()
+            .map(|log| log.message.trim())
+            .filter(|message| !message.is_empty())
+        {
+            let repeats = logs
+                .iter()
+                .rev()
+                .take_while(|log| log.message.trim() == latest)
+                .count() as u32;
+            if repeats >= config.max_repeated_messages {
+                checks.push((
+                    GuardrailRule::RepeatedMessages,
+                    repeats as f64,
+                    config.max_repeated_messages as f64,
+                    format!(
+                        "最近 {repeats} 个周期输出相同消息「{latest}」（阈值 {}）",
+                        config.max_repeated_messages
+                    ),
+                ));
+            }
+        }
+    }
+
+    if config.max_tokens_per_cycle > 0 {
+        if let Some(peak) = logs
+            .iter()
+            .filter(|log| log.tokens_used > config.max_tokens_per_cycle)
+            .max_by_key(|log| log.tokens_used)
+        {
+            checks.push((
+                GuardrailRule::TokensPerCycle,
+                peak.tokens_used as f64,
+                config.max_tokens_per_cycle as f64,
+                format!(
+                    "周期 #{} 使用 {} tokens 超过单周期阈值 {}",
+                    peak.cycle_id, peak.tokens_used, config.max_tokens_per_cycle
+                ),
+            ));
+        }
+    }
+
+    checks
+}
+
+/// DFR 决策详情的选择状态，ACE 周期与指纹列表都通过它打开详情
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct DfrViewState {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub selected_decision: Option<String>,
+    /// 最近查看的决策 ID，新的在前
     #[serde(default)]
-    pub last_indices_used: Vec<String>,
+    pub recent_decisions: Vec<String>,
+}
+
+/// 最近查看决策的保留条数
+const DFR_RECENT_LIMIT: usize = 8;
+
+/// 因果链探索器当前选中的事件，时间线与 Awareness 事件卡片都可以直接选中
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct CausalChainViewState {
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub last_budget: Option<String>,
+    pub selected_event: Option<String>,
 }
 
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
@@ -500,16 +1417,63 @@ pub struct AppState {
     pub graph: GraphState,
     pub operation: OperationState,
     pub audit: AuditLogState,
+    #[serde(default)]
+    pub budget: BudgetState,
+    #[serde(default)]
+    pub guardrail: GuardrailState,
+    #[serde(default)]
+    pub dfr: DfrViewState,
+    #[serde(default)]
+    pub causal_chain: CausalChainViewState,
 }
 
 #[derive(Clone)]
 pub struct AppActions {
     state: AppSignal,
+    /// 由 `operation_begin` 返回的句柄携带，操作相关的更新只落到该操作上
+    operation_id: Option<u64>,
 }
 
 impl AppActions {
+    pub fn from_signal(state: AppSignal) -> Self {
+        Self {
+            state,
+            operation_id: None,
+        }
+    }
+
+    /// 将操作更新路由到本句柄对应的操作：仍是当前操作时直接更新，已转入后台时更新后台副本，
+    /// 已归档的操作忽略后续更新；未绑定操作的句柄总是更新当前操作
+    fn with_operation(&self, update: impl FnOnce(&mut OperationState)) {
+        let mut state = self.state.write_unchecked();
+        let (tenant_id, session_id) = (state.tenant_id.clone(), state.session_id.clone());
+        let operation = &mut state.operation;
+        match self.operation_id {
+            Some(id) if operation.current_id != Some(id) => {
+                let Some(index) = operation
+                    .background
+                    .iter()
+                    .position(|background| background.current_id == Some(id))
+                else {
+                    return;
+                };
+                update(&mut operation.background[index]);
+                if operation.background[index].is_finished() {
+                    let finished = operation.background.remove(index);
+                    operation.push_history(finished.to_record(id, tenant_id, session_id));
+                }
+            }
+            _ => update(operation),
+        }
+    }
+
     pub fn set_tenant(&self, tenant: Option<String>) {
         let mut state = self.state.write_unchecked();
+        let (previous_tenant, previous_session) =
+            (state.tenant_id.clone(), state.session_id.clone());
+        state
+            .operation
+            .archive_all(previous_tenant, previous_session);
         state.tenant_id = tenant;
         state.timeline.clear();
         state.timeline.filters.clear();
@@ -518,13 +1482,21 @@ impl AppActions {
         state.timeline.query.cursor = None;
         state.context = ContextState::default();
         state.ace = AceState::default();
+        state.budget.alerts.clear();
+        state.guardrail.violations.clear();
+        state.dfr = DfrViewState::default();
+        state.causal_chain = CausalChainViewState::default();
         state.live_stream = LiveStreamState::default();
         state.graph = GraphState::default();
-        state.operation = OperationState::default();
     }
 
     pub fn set_session(&self, session: Option<String>) {
         let mut state = self.state.write_unchecked();
+        let (previous_tenant, previous_session) =
+            (state.tenant_id.clone(), state.session_id.clone());
+        state
+            .operation
+            .archive_all(previous_tenant, previous_session);
         state.session_id = session;
         state.timeline.clear();
         state.timeline.filters.clear();
@@ -532,9 +1504,9 @@ impl AppActions {
         state.timeline.query.cursor = None;
         state.context = ContextState::default();
         state.ace = AceState::default();
+        state.budget.alerts.clear();
         state.live_stream = LiveStreamState::default();
         state.graph = GraphState::default();
-        state.operation = OperationState::default();
     }
 
     pub fn set_scenario(&self, scenario: Option<ConversationScenario>) {
@@ -558,36 +1530,48 @@ impl AppActions {
     pub fn toggle_participant_role(&self, role: &str) {
         let mut state = self.state.write_unchecked();
         state.timeline.filters.toggle_participant_role(role);
+        state.timeline.focused_event_id = None;
     }
 
     pub fn toggle_access_class(&self, access_class: &str) {
         let mut state = self.state.write_unchecked();
         state.timeline.filters.toggle_access_class(access_class);
+        state.timeline.focused_event_id = None;
     }
 
     pub fn toggle_degradation_reason(&self, reason: &str) {
         let mut state = self.state.write_unchecked();
         state.timeline.filters.toggle_degradation_reason(reason);
+        state.timeline.focused_event_id = None;
     }
 
     pub fn toggle_awareness_type(&self, event_type: &str) {
         let mut state = self.state.write_unchecked();
         state.timeline.filters.toggle_awareness_type(event_type);
+        state.timeline.focused_event_id = None;
     }
 
     pub fn toggle_router_digest(&self, digest: &str) {
         let mut state = self.state.write_unchecked();
         state.timeline.filters.toggle_router_digest(digest);
+        state.timeline.focused_event_id = None;
     }
 
     pub fn toggle_query_hash(&self, hash: &str) {
         let mut state = self.state.write_unchecked();
         state.timeline.filters.toggle_query_hash(hash);
+        state.timeline.focused_event_id = None;
     }
 
     pub fn clear_timeline_filters(&self) {
         let mut state = self.state.write_unchecked();
         state.timeline.filters.clear();
+        state.timeline.focused_event_id = None;
+    }
+
+    pub fn focus_timeline_event(&self, event_id: Option<u64>) {
+        let mut state = self.state.write_unchecked();
+        state.timeline.focused_event_id = event_id;
     }
 
     pub fn add_event_tag(&self, event_id: u64, tag: String) {
@@ -765,12 +1749,61 @@ impl AppActions {
         state.ace.snapshot_loading = false;
     }
 
+    pub fn set_ace_listing(&self, next_cursor: Option<String>, fallback: bool) {
+        let mut state = self.state.write_unchecked();
+        state.ace.next_cursor = next_cursor;
+        state.ace.listing_fallback = fallback;
+    }
+
+    pub fn append_ace_cycles(&self, cycles: Vec<AceCycleSummary>, next_cursor: Option<String>) {
+        let mut state = self.state.write_unchecked();
+        for cycle in cycles {
+            if !state
+                .ace
+                .cycles
+                .iter()
+                .any(|existing| existing.cycle_id == cycle.cycle_id)
+            {
+                state.ace.cycles.push(cycle);
+            }
+        }
+        state.ace.next_cursor = next_cursor;
+        state.ace.loading_more = false;
+    }
+
+    pub fn set_ace_loading_more(&self, loading: bool) {
+        self.state.write_unchecked().ace.loading_more = loading;
+    }
+
+    pub fn set_ace_filter(&self, filter: AceCycleFilter) {
+        let mut state = self.state.write_unchecked();
+        state.ace.filter = filter;
+        state.ace.next_cursor = None;
+    }
+
     pub fn select_ace_cycle(&self, cycle_id: Option<String>) {
         let mut state = self.state.write_unchecked();
         state.ace.selected_cycle_id = cycle_id;
         state.ace.snapshot_error = None;
     }
 
+    pub fn open_dfr_decision(&self, decision_id: String) {
+        let mut state = self.state.write_unchecked();
+        let recent = &mut state.dfr.recent_decisions;
+        recent.retain(|id| *id != decision_id);
+        recent.insert(0, decision_id.clone());
+        recent.truncate(DFR_RECENT_LIMIT);
+        state.dfr.selected_decision = Some(decision_id);
+    }
+
+    pub fn close_dfr_decision(&self) {
+        self.state.write_unchecked().dfr.selected_decision = None;
+    }
+
+    pub fn explore_causal_chain(&self, event_id: Option<String>) {
+        self.state.write_unchecked().causal_chain.selected_event = event_id;
+    }
+
     pub fn set_ace_snapshot_loading(&self, loading: bool) {
         self.state.write_unchecked().ace.snapshot_loading = loading;
     }
@@ -781,6 +1814,173 @@ impl AppActions {
         state.ace.snapshot_loading = false;
     }
 
+    pub fn set_budget_alert_config(&self, config: BudgetAlertConfig) {
+        let mut state = self.state.write_unchecked();
+        state.budget.config = config;
+        let snapshots: Vec<(String, CycleSnapshotView)> = state
+            .ace
+            .snapshots
+            .iter()
+            .map(|(id, snapshot)| (id.clone(), snapshot.clone()))
+            .collect();
+        state.budget.alerts.clear();
+        for (cycle_id, snapshot) in snapshots.iter() {
+            state.budget.evaluate_cycle(cycle_id, snapshot);
+        }
+    }
+
+    pub fn dismiss_budget_alert_toasts(&self) {
+        let mut state = self.state.write_unchecked();
+        for alert in state.budget.alerts.iter_mut() {
+            alert.toast_dismissed = true;
+        }
+    }
+
+    pub fn clear_budget_alerts(&self) {
+        self.state.write_unchecked().budget.alerts.clear();
+    }
+
+    pub fn set_guardrail_config(&self, config: GuardrailConfig) {
+        let mut state = self.state.write_unchecked();
+        state.guardrail.config = config;
+        // 下一次轮询按新阈值重新评估
+        state.guardrail.violations.clear();
+    }
+
+    /// 以最新会话列表重新评估护栏，返回需要自动终止的 (会话, 原因)；
+    /// 返回的会话记为提交中，直到 `settle_guardrail_termination` 回报结果，结果无变化时不写入状态
+    pub fn evaluate_guardrails(
+        &self,
+        sessions: &[AutonomousSessionSummary],
+    ) -> Vec<(String, String)> {
+        let (fresh, to_terminate) = {
+            let state = self.state.peek();
+            let guardrail = &state.guardrail;
+            let fresh = guardrail.evaluate(sessions);
+            let mut to_terminate: Vec<(String, String)> = Vec::new();
+            if guardrail.config.auto_terminate {
+                for violation in fresh.iter() {
+                    if guardrail.enforced.contains(&violation.orchestration_id)
+                        || guardrail.pending.contains_key(&violation.orchestration_id)
+                    {
+                        continue;
+                    }
+                    match to_terminate
+                        .iter_mut()
+                        .find(|(id, _)| id == &violation.orchestration_id)
+                    {
+                        Some((_, reason)) => {
+                            reason.push_str("；");
+                            reason.push_str(&violation.message);
+                        }
+                        None => to_terminate.push((
+                            violation.orchestration_id.clone(),
+                            format!("客户端护栏: {}", violation.message),
+                        )),
+                    }
+                }
+            }
+            if fresh == guardrail.violations && to_terminate.is_empty() {
+                return Vec::new();
+            }
+            (fresh, to_terminate)
+        };
+
+        let mut state = self.state.write_unchecked();
+        state.guardrail.violations = fresh;
+        for (id, reason) in to_terminate.iter() {
+            state.guardrail.pending.insert(id.clone(), reason.clone());
+        }
+        to_terminate
+    }
+
+    /// 终止请求返回后回报结果；仅处理护栏发起的终止。
+    /// 成功时记为已执行并写入审计日志，失败时移出提交中，下次轮询重新尝试
+    pub fn settle_guardrail_termination(&self, orchestration_id: &str, result: Result<(), String>) {
+        let Some(reason) = self
+            .state
+            .write_unchecked()
+            .guardrail
+            .pending
+            .remove(orchestration_id)
+        else {
+            return;
+        };
+        match result {
+            Ok(()) => {
+                self.state
+                    .write_unchecked()
+                    .guardrail
+                    .enforced
+                    .insert(orchestration_id.to_string());
+                self.record_audit_event(
+                    AuditActionKind::Terminate,
+                    format!("护栏自动终止：{reason}"),
+                    format!("autonomous:{orchestration_id}"),
+                );
+            }
+            Err(err) => {
+                tracing::warn!("护栏自动终止 {orchestration_id} 失败，将在下次轮询重试: {err}");
+            }
+        }
+    }
+
+    pub fn dismiss_guardrail_toasts(&self) {
+        let mut state = self.state.write_unchecked();
+        for violation in state.guardrail.violations.iter_mut() {
+            violation.toast_dismissed = true;
+        }
+    }
+
+    pub fn set_ace_lineage_pending(&self, cycle_id: String, pending: bool) {
+        let mut state = self.state.write_unchecked();
+        if pending {
+            state.ace.lineage_errors.remove(&cycle_id);
+            state.ace.lineage_pending.insert(cycle_id);
+        } else {
+            state.ace.lineage_pending.remove(&cycle_id);
+        }
+    }
+
+    pub fn set_ace_lineage_error(&self, cycle_id: String, message: String) {
+        let mut state = self.state.write_unchecked();
+        state.ace.lineage_pending.remove(&cycle_id);
+        state.ace.lineage_errors.insert(cycle_id, message);
+    }
+
+    /// 批量补齐快照前登记待加载周期，清除其上次的错误
+    pub fn queue_ace_snapshot_batch(&self, cycle_ids: Vec<String>) {
+        let mut state = self.state.write_unchecked();
+        for cycle_id in cycle_ids {
+            state.ace.batch_errors.remove(&cycle_id);
+            state.ace.batch_pending.insert(cycle_id);
+        }
+    }
+
+    pub fn set_ace_batch_error(&self, cycle_id: String, message: String) {
+        let mut state = self.state.write_unchecked();
+        state.ace.batch_pending.remove(&cycle_id);
+        state.ace.batch_errors.insert(cycle_id, message);
+    }
+
+    /// 缓存批量补齐的快照，不影响当前选中周期与谱系视图的加载状态
+    pub fn store_ace_batch_snapshot(&self, cycle_id: String, snapshot: CycleSnapshotView) {
+        let mut state = self.state.write_unchecked();
+        state.ace.batch_pending.remove(&cycle_id);
+        state.ace.batch_errors.remove(&cycle_id);
+        state.budget.evaluate_cycle(&cycle_id, &snapshot);
+        state.ace.snapshots.insert(cycle_id, snapshot);
+    }
+
+    /// 仅缓存祖先快照，不影响当前选中周期的加载状态
+    pub fn store_ace_lineage_snapshot(&self, cycle_id: String, snapshot: CycleSnapshotView) {
+        let mut state = self.state.write_unchecked();
+        state.ace.lineage_pending.remove(&cycle_id);
+        state.ace.lineage_errors.remove(&cycle_id);
+        state.budget.evaluate_cycle(&cycle_id, &snapshot);
+        state.ace.snapshots.insert(cycle_id, snapshot);
+    }
+
     pub fn store_ace_snapshot(
         &self,
         cycle_id: String,
@@ -795,6 +1995,7 @@ impl AppActions {
         state.ace.outboxes.insert(cycle_id.clone(), outbox);
         state.ace.snapshot_loading = false;
         state.ace.snapshot_error = None;
+        state.budget.evaluate_cycle(&cycle_id, &snapshot);
 
         if let Some(summary) = state
             .ace
@@ -854,25 +2055,32 @@ impl AppActions {
         }
     }
 
-    pub fn set_operation_success(&self, message: String) {
+    pub fn store_ace_outbox(&self, cycle_id: String, outbox: Vec<OutboxMessageView>) {
         let mut state = self.state.write_unchecked();
-        state.operation.last_message = Some(message);
-        state.operation.error = None;
-        state.operation.last_status = None;
-        state.operation.error_code = None;
-        state.operation.context = None;
-        state.operation.last_indices_used.clear();
-        state.operation.last_budget = None;
+        state.ace.outboxes.insert(cycle_id, outbox);
+    }
+
+    pub fn set_operation_success(&self, message: String) {
+        self.with_operation(|operation| {
+            operation.last_message = Some(message);
+            operation.error = None;
+            operation.last_status = None;
+            operation.error_code = None;
+            operation.context = None;
+            operation.last_indices_used.clear();
+            operation.last_budget = None;
+        });
     }
 
     pub fn set_operation_error(&self, message: String) {
-        let mut state = self.state.write_unchecked();
-        state.operation.error = Some(message);
-        state.operation.last_message = None;
-        state.operation.last_status = None;
-        state.operation.error_code = None;
-        state.operation.trace_id = None;
-        state.operation.context = None;
+        self.with_operation(|operation| {
+            operation.error = Some(message);
+            operation.last_message = None;
+            operation.last_status = None;
+            operation.error_code = None;
+            operation.trace_id = None;
+            operation.context = None;
+        });
     }
 
     pub fn record_http_failure(
@@ -883,32 +2091,33 @@ impl AppActions {
         context: impl Into<String>,
         detail: Option<String>,
     ) {
-        let mut state = self.state.write_unchecked();
         let context_label = context.into();
         let message = detail.unwrap_or_else(|| http_status_advice(status).to_string());
 
-        state.operation.error = Some(message);
-        state.operation.last_message = None;
-        state.operation.last_status = Some(status);
-        state.operation.error_code = error_code;
-        state.operation.trace_id = trace_id;
-        state.operation.context = Some(context_label);
+        self.with_operation(|operation| {
+            operation.error = Some(message);
+            operation.last_message = None;
+            operation.last_status = Some(status);
+            operation.error_code = error_code;
+            operation.trace_id = trace_id;
+            operation.context = Some(context_label);
+        });
     }
 
     pub fn set_operation_trace(&self, trace_id: Option<String>) {
-        self.state.write_unchecked().operation.trace_id = trace_id;
+        self.with_operation(|operation| operation.trace_id = trace_id);
     }
 
     pub fn set_operation_context(&self, context: Option<String>) {
-        self.state.write_unchecked().operation.context = context;
+        self.with_operation(|operation| operation.context = context);
     }
 
     pub fn set_operation_triggered(&self, triggered_at: Option<String>) {
-        self.state.write_unchecked().operation.triggered_at = triggered_at;
+        self.with_operation(|operation| operation.triggered_at = triggered_at);
     }
 
     pub fn set_operation_cycle(&self, cycle_id: Option<String>) {
-        self.state.write_unchecked().operation.last_cycle_id = cycle_id;
+        self.with_operation(|operation| operation.last_cycle_id = cycle_id);
     }
 
     pub fn record_audit_event(
@@ -941,144 +2150,74 @@ impl AppActions {
     }
 
     pub fn set_operation_outcome(&self, outcome: Option<CycleOutcomeSummary>) {
-        self.state.write_unchecked().operation.last_outcome = outcome;
+        self.with_operation(|operation| operation.last_outcome = outcome);
     }
 
-    pub fn operation_stage_reset(&self) {
+    /// 开始一次新操作，返回绑定该操作的句柄，后续阶段与结果都应通过它更新。
+    /// 上一操作已结束时连同阶段时间线归档到历史；仍在进行时转入后台继续跟踪，不记为中断
+    pub fn operation_begin(&self, kind: OperationKind) -> AppActions {
         let mut state = self.state.write_unchecked();
-        state.operation.stages.clear();
-        state.operation.current_stage = None;
-        state.operation.total_elapsed_ms = None;
+        let (tenant_id, session_id) = (state.tenant_id.clone(), state.session_id.clone());
+        let operation = &mut state.operation;
+        if operation.is_running() && operation.current_id.is_some() {
+            let running = operation.take_current();
+            operation.background.push(running);
+        } else {
+            operation.archive_current(tenant_id, session_id);
+        }
+        let id = operation.next_record_id;
+        operation.next_record_id = id.saturating_add(1);
+        operation.current_id = Some(id);
+        operation.current_kind = Some(kind);
+
+        AppActions {
+            state: self.state,
+            operation_id: Some(id),
+        }
+    }
+
+    /// 提交前校验未通过：记为一次独立的失败操作，不影响仍在进行的操作
+    pub fn operation_reject(&self, kind: OperationKind, message: String) {
+        self.operation_begin(kind).set_operation_error(message);
     }
 
     pub fn operation_stage_start(&self, kind: OperationStageKind, label: impl Into<String>) {
-        let mut state = self.state.write_unchecked();
         let label = label.into();
-        let now_label = now_iso_timestamp();
-        let now_epoch = now_epoch_ms();
-
-        if let Some(stage) = state
-            .operation
-            .stages
-            .iter_mut()
-            .find(|stage| stage.kind == kind)
-        {
-            stage.status = OperationStageStatus::Running;
-            stage.label = label;
-            if stage.started_at.is_none() {
-                stage.started_at = Some(now_label);
-            }
-            stage.started_at_epoch_ms = Some(now_epoch);
-            stage.finished_at = None;
-            stage.finished_at_epoch_ms = None;
-            stage.duration_ms = None;
-            stage.detail = None;
-        } else {
-            let mut stage = OperationStage::default();
-            stage.kind = kind.clone();
-            stage.label = label;
-            stage.status = OperationStageStatus::Running;
-            stage.started_at = Some(now_label);
-            stage.started_at_epoch_ms = Some(now_epoch);
-            state.operation.stages.push(stage);
-        }
-
-        state.operation.current_stage = Some(kind);
+        self.with_operation(|operation| operation.stage_start(kind, label));
     }
 
     pub fn operation_stage_complete(&self, kind: OperationStageKind, detail: Option<String>) {
-        let mut state = self.state.write_unchecked();
-        let now_label = now_iso_timestamp();
-        let now_epoch = now_epoch_ms();
-
-        if let Some(stage) = state
-            .operation
-            .stages
-            .iter_mut()
-            .find(|stage| stage.kind == kind)
-        {
-            stage.status = OperationStageStatus::Completed;
-            stage.finished_at = Some(now_label);
-            stage.finished_at_epoch_ms = Some(now_epoch);
-            stage.detail = detail;
-            if let Some(start_epoch) = stage.started_at_epoch_ms {
-                let duration = now_epoch.saturating_sub(start_epoch);
-                stage.duration_ms = Some((duration.min(u64::MAX as u128)) as u64);
-            }
-        } else {
-            let mut stage = OperationStage::default();
-            stage.kind = kind.clone();
-            stage.status = OperationStageStatus::Completed;
-            stage.finished_at = Some(now_label);
-            stage.finished_at_epoch_ms = Some(now_epoch);
-            stage.detail = detail;
-            state.operation.stages.push(stage);
-        }
-
-        if state.operation.current_stage.as_ref() == Some(&kind) {
-            state.operation.current_stage = None;
-        }
-
-        let total: u64 = state
-            .operation
-            .stages
-            .iter()
-            .filter_map(|stage| stage.duration_ms)
-            .sum();
-        state.operation.total_elapsed_ms = if total > 0 { Some(total) } else { None };
+        self.with_operation(|operation| {
+            operation.stage_finish(kind, OperationStageStatus::Completed, detail)
+        });
     }
 
     pub fn operation_stage_fail(&self, kind: OperationStageKind, detail: Option<String>) {
-        let mut state = self.state.write_unchecked();
-        let now_label = now_iso_timestamp();
-        let now_epoch = now_epoch_ms();
-
-        if let Some(stage) = state
-            .operation
-            .stages
-            .iter_mut()
-            .find(|stage| stage.kind == kind)
-        {
-            stage.status = OperationStageStatus::Failed;
-            stage.finished_at = Some(now_label);
-            stage.finished_at_epoch_ms = Some(now_epoch);
-            stage.detail = detail;
-            if let Some(start_epoch) = stage.started_at_epoch_ms {
-                let duration = now_epoch.saturating_sub(start_epoch);
-                stage.duration_ms = Some((duration.min(u64::MAX as u128)) as u64);
-            }
-        } else {
-            let mut stage = OperationStage::default();
-            stage.kind = kind.clone();
-            stage.status = OperationStageStatus::Failed;
-            stage.finished_at = Some(now_label);
-            stage.finished_at_epoch_ms = Some(now_epoch);
-            stage.detail = detail;
-            state.operation.stages.push(stage);
-        }
-
-        if state.operation.current_stage.as_ref() == Some(&kind) {
-            state.operation.current_stage = None;
-        }
-
-        let total: u64 = state
-            .operation
-            .stages
-            .iter()
-            .filter_map(|stage| stage.duration_ms)
-            .sum();
-        state.operation.total_elapsed_ms = if total > 0 { Some(total) } else { None };
+        self.with_operation(|operation| {
+            operation.stage_finish(kind, OperationStageStatus::Failed, detail)
+        });
     }
 
     pub fn set_operation_diagnostics(&self, indices: Vec<String>, budget: Option<String>) {
-        let mut state = self.state.write_unchecked();
-        state.operation.last_indices_used = indices;
-        state.operation.last_budget = budget;
+        self.with_operation(|operation| {
+            operation.last_indices_used = indices;
+            operation.last_budget = budget;
+        });
     }
 
     pub fn clear_operation_status(&self) {
         let mut state = self.state.write_unchecked();
-        state.operation = OperationState::default();
+        let (tenant_id, session_id) = (state.tenant_id.clone(), state.session_id.clone());
+        state.operation.archive_current(tenant_id, session_id);
+    }
+
+    pub fn clear_operation_history(&self) {
+        let mut state = self.state.write_unchecked();
+        state.operation.history.clear();
+        // 仍有操作在进行时保留编号，避免与其归档记录冲突
+        if state.operation.current_id.is_none() && state.operation.background.is_empty() {
+            state.operation.next_record_id = 0;
+        }
     }
 
     pub fn update_cycle_metadata(&self, cycle_id: Option<String>, addition: Value) {
@@ -1112,6 +2251,14 @@ impl AppActions {
         }
     }
 
+    pub fn set_graph_depth(&self, depth: u8) {
+        self.state.write_unchecked().graph.query.depth = depth;
+    }
+
+    pub fn set_graph_direction(&self, direction: GraphDirection) {
+        self.state.write_unchecked().graph.query.direction = direction;
+    }
+
     pub fn set_graph_loading(&self, loading: bool) {
         self.state.write_unchecked().graph.is_loading = loading;
     }
@@ -1125,14 +2272,50 @@ impl AppActions {
         }
     }
 
-    pub fn set_graph_data(&self, causal: Option<CausalGraphView>, recall: Vec<RecallResultView>) {
+    pub fn set_graph_data(&self, causal: Option<CausalGraphView>) {
         let mut state = self.state.write_unchecked();
         state.graph.causal = causal;
-        state.graph.recall = recall;
         state.graph.is_loading = false;
         state.graph.error = None;
     }
 
+    pub fn set_recall_query(&self, query: RecallQuery) {
+        self.state.write_unchecked().graph.recall_query = query;
+    }
+
+    pub fn set_recall_loading(&self, loading: bool) {
+        self.state.write_unchecked().graph.recall_loading = loading;
+    }
+
+    pub fn set_recall_error(&self, message: Option<String>) {
+        let mut state = self.state.write_unchecked();
+        let has_error = message.is_some();
+        state.graph.recall_error = message;
+        if has_error {
+            state.graph.recall_loading = false;
+        }
+    }
+
+    pub fn set_recall_results(&self, recall: Vec<RecallResultView>) {
+        let mut state = self.state.write_unchecked();
+        state.graph.recall = recall;
+        state.graph.recall_loading = false;
+        state.graph.recall_error = None;
+    }
+
+    pub fn toggle_recall_pin(&self, item: RecallResultView) {
+        let mut state = self.state.write_unchecked();
+        let pinned = &mut state.graph.pinned_recall;
+        if let Some(pos) = pinned
+            .iter()
+            .position(|pinned| pinned.event_id == item.event_id)
+        {
+            pinned.remove(pos);
+        } else {
+            pinned.push(item);
+        }
+    }
+
     pub fn set_workspace_loading(&self, loading: bool) {
         self.state.write_unchecked().workspace.is_loading = loading;
     }
@@ -1493,5 +2676,5 @@ pub fn use_app_state() -> AppSignal {
 
 pub fn use_app_actions() -> AppActions {
     let state = use_app_state();
-    AppActions { state }
+    AppActions::from_signal(state)
 }
@@ -1,11 +1,20 @@
-use crate::hooks::ace::use_ace_cycles;
+use crate::hooks::ace::{load_more_ace_cycles, use_ace_cycles};
+use crate::hooks::dfr::{use_dfr_decision_list, DecisionLookup};
 use crate::models::{
-    AceCycleStatus, AceCycleSummary, AceLane, AwarenessDegradationReason, AwarenessEvent,
-    AwarenessEventType, AwarenessFork, BudgetSnapshotView, CycleSnapshotView, DialogueEvent,
-    HitlInjectionView, OutboxMessageView, RouteBudgetEstimate, RouterDecisionView,
-    SyncPointInputView,
+    AceCycleStatus, AceCycleSummary, AceLane, AwarenessDegradationReason, AwarenessEventType,
+    AwarenessFork, BudgetSnapshotView, CycleSnapshotView, DialogueEvent, HitlInjectionView,
+    OutboxMessageView, RouteBudgetEstimate, RouterDecisionView, SyncPointInputView,
 };
-use crate::state::{use_app_actions, use_app_state, AppActions, AuditActionKind};
+use crate::state::{
+    use_app_actions, use_app_state, AceCycleFilter, AceTimeWindow, AppActions, AuditActionKind,
+};
+use crate::ui::cycle_diff::CycleDiffPanel;
+use crate::ui::cycle_lineage::CycleLineagePanel;
+use crate::ui::dfr_decision::render_decision_links;
+use crate::ui::outbox_inspector::OutboxInspector;
+use crate::ui::performance_profile::PerformanceProfilePanel;
+use crate::ui::router_explorer::RouterExplorerPanel;
+use crate::ui::util::parse_datetime_ms;
 use dioxus::prelude::*;
 use serde_json::{to_string_pretty, Value};
 use std::collections::HashMap;
@@ -17,7 +26,8 @@ pub fn AcePanel() -> Element {
     use_ace_cycles();
 
     let actions = use_app_actions();
-    let ace_state = use_app_state().read().ace.clone();
+    let app_state = use_app_state();
+    let ace_state = app_state.read().ace.clone();
 
     let selected_cycle_id = ace_state.selected_cycle_id.clone();
     let selected_snapshot = selected_cycle_id
@@ -30,12 +40,60 @@ pub fn AcePanel() -> Element {
     let snapshot_loading = ace_state.snapshot_loading;
     let snapshot_error = ace_state.snapshot_error.clone();
 
+    let filter = ace_state.filter.clone();
+    let lane_value = filter.lane.as_ref().map(lane_filter_value).unwrap_or("all");
+    let status_value = filter
+        .status
+        .as_ref()
+        .map(status_filter_value)
+        .unwrap_or("all");
+    let mut custom_range = use_signal(|| false);
+    let window_value = match filter.window.as_ref() {
+        Some(window) => window_filter_value(window),
+        None if filter.has_custom_range() || *custom_range.read() => WINDOW_CUSTOM,
+        None => "all",
+    };
+    let show_range_inputs = window_value == WINDOW_CUSTOM;
+    let range_start_value = filter
+        .range_start_ms
+        .map(format_datetime_input)
+        .unwrap_or_default();
+    let range_end_value = filter
+        .range_end_ms
+        .map(format_datetime_input)
+        .unwrap_or_default();
+    let range_inverted = matches!(
+        (filter.range_start_ms, filter.range_end_ms),
+        (Some(start), Some(end)) if start > end
+    );
+    let has_next_page = ace_state.next_cursor.is_some();
+    let loading_more = ace_state.loading_more;
+    let listing_fallback = ace_state.listing_fallback;
+    let budget_alert_count = app_state.read().budget.alerts.len();
+
+    let filter_lane = filter.clone();
+    let actions_lane = actions.clone();
+    let filter_status = filter.clone();
+    let actions_status = actions.clone();
+    let filter_window = filter.clone();
+    let actions_window = actions.clone();
+    let filter_range_start = filter.clone();
+    let actions_range_start = actions.clone();
+    let filter_range_end = filter.clone();
+    let actions_range_end = actions.clone();
+    let actions_reset = actions.clone();
+    let actions_more = actions.clone();
+
     let body = if ace_state.is_loading {
         rsx! { p { class: "text-xs text-slate-500", "正在加载 ACE 周期..." } }
     } else if let Some(ref err) = ace_state.error {
         rsx! { p { class: "text-xs text-red-500", "ACE 数据加载失败: {err}" } }
     } else if ace_state.cycles.is_empty() {
-        rsx! { p { class: "text-xs text-slate-500 italic", "暂无 ACE 周期数据" } }
+        if filter.is_empty() {
+            rsx! { p { class: "text-xs text-slate-500 italic", "暂无 ACE 周期数据" } }
+        } else {
+            rsx! { p { class: "text-xs text-slate-500 italic", "没有符合筛选条件的周期" } }
+        }
     } else {
         let selected_cycle = ace_state
             .cycles
@@ -45,11 +103,23 @@ pub fn AcePanel() -> Element {
 
         rsx! {
             div { class: "flex flex-col gap-4 md:flex-row",
-                CycleList {
-                    cycles: ace_state.cycles.clone(),
-                    selected_cycle_id: ace_state.selected_cycle_id.clone(),
-                    actions: actions.clone(),
-                    snapshot_loading,
+                div { class: "md:w-1/3 space-y-2",
+                    CycleList {
+                        cycles: ace_state.cycles.clone(),
+                        selected_cycle_id: ace_state.selected_cycle_id.clone(),
+                        actions: actions.clone(),
+                        snapshot_loading,
+                    }
+                    if has_next_page {
+                        button {
+                            class: "w-full rounded border border-slate-300 px-3 py-1 text-[11px] text-slate-600 hover:bg-slate-100 disabled:opacity-50",
+                            disabled: loading_more,
+                            onclick: move |_| {
+                                spawn(load_more_ace_cycles(actions_more.clone(), app_state));
+                            },
+                            if loading_more { "加载中…" } else { "加载更多" }
+                        }
+                    }
                 }
                 CycleDetail {
                     cycle: selected_cycle,
@@ -60,15 +130,141 @@ pub fn AcePanel() -> Element {
                     actions: actions.clone(),
                 }
             }
+            CycleLineagePanel {
+                cycles: ace_state.cycles.clone(),
+                snapshots: ace_state.snapshots.clone(),
+                selected_cycle_id: ace_state.selected_cycle_id.clone(),
+                lineage_pending: ace_state.lineage_pending.clone(),
+                lineage_errors: ace_state.lineage_errors.clone(),
+                actions: actions.clone(),
+            }
+            RouterExplorerPanel {
+                cycles: ace_state.cycles.clone(),
+                snapshots: ace_state.snapshots.clone(),
+                selected_cycle_id: ace_state.selected_cycle_id.clone(),
+            }
+            CycleDiffPanel {
+                cycles: ace_state.cycles.clone(),
+                snapshots: ace_state.snapshots.clone(),
+                outboxes: ace_state.outboxes.clone(),
+                selected_cycle_id: ace_state.selected_cycle_id.clone(),
+            }
+            PerformanceProfilePanel {
+                cycles: ace_state.cycles.clone(),
+                selected_cycle_id: ace_state.selected_cycle_id.clone(),
+            }
         }
     };
 
     rsx! {
         section { class: "space-y-3",
             header { class: "flex flex-col gap-1",
-                h2 { class: "text-lg font-semibold text-slate-900", "ACE 周期与 HITL" }
+                div { class: "flex flex-wrap items-center gap-2",
+                    h2 { class: "text-lg font-semibold text-slate-900", "ACE 周期与 HITL" }
+                    if budget_alert_count > 0 {
+                        span { class: "rounded-full bg-red-100 px-2 py-0.5 text-[11px] font-semibold text-red-700", "预算告警 {budget_alert_count}" }
+                    }
+                }
                 p { class: "text-xs text-slate-500", "展示 Clarify/Tool/SelfReason 等周期的预算与当前状态" }
             }
+            div { class: "flex flex-wrap items-center gap-2 text-[11px] text-slate-600",
+                select {
+                    class: "rounded border border-slate-300 px-2 py-1 text-[11px] text-slate-600",
+                    value: "{lane_value}",
+                    onchange: move |evt| {
+                        let mut next = filter_lane.clone();
+                        next.lane = parse_lane_filter(&evt.value());
+                        actions_lane.set_ace_filter(next);
+                    },
+                    option { value: "all", "全部 Lane" }
+                    for lane in ACE_LANES.iter() {
+                        option { value: lane_filter_value(lane), "{format_lane(lane)}" }
+                    }
+                }
+                select {
+                    class: "rounded border border-slate-300 px-2 py-1 text-[11px] text-slate-600",
+                    value: "{status_value}",
+                    onchange: move |evt| {
+                        let mut next = filter_status.clone();
+                        next.status = parse_status_filter(&evt.value());
+                        actions_status.set_ace_filter(next);
+                    },
+                    option { value: "all", "全部状态" }
+                    for status in ACE_STATUSES.iter() {
+                        option { value: status_filter_value(status), "{format_status(status)}" }
+                    }
+                }
+                select {
+                    class: "rounded border border-slate-300 px-2 py-1 text-[11px] text-slate-600",
+                    value: "{window_value}",
+                    onchange: move |evt| {
+                        let value = evt.value();
+                        let mut next = filter_window.clone();
+                        next.window = parse_window_filter(&value);
+                        // 预设窗口与自定义区间互斥，切换时清空另一方
+                        if value != WINDOW_CUSTOM {
+                            next.range_start_ms = None;
+                            next.range_end_ms = None;
+                        }
+                        custom_range.set(value == WINDOW_CUSTOM);
+                        actions_window.set_ace_filter(next);
+                    },
+                    option { value: "all", "全部时间" }
+                    for window in ACE_TIME_WINDOWS.iter() {
+                        option { value: window_filter_value(window), "{window.label()}" }
+                    }
+                    option { value: WINDOW_CUSTOM, "自定义区间" }
+                }
+                if show_range_inputs {
+                    label { class: "flex items-center gap-1",
+                        span { "起" }
+                        input {
+                            r#type: "datetime-local",
+                            class: "rounded border border-slate-300 px-2 py-1 text-[11px] text-slate-600",
+                            value: "{range_start_value}",
+                            onchange: move |evt| {
+                                let mut next = filter_range_start.clone();
+                                next.range_start_ms = parse_datetime_ms(&evt.value());
+                                actions_range_start.set_ace_filter(next);
+                            },
+                        }
+                    }
+                    label { class: "flex items-center gap-1",
+                        span { "止" }
+                        input {
+                            r#type: "datetime-local",
+                            class: "rounded border border-slate-300 px-2 py-1 text-[11px] text-slate-600",
+                            value: "{range_end_value}",
+                            onchange: move |evt| {
+                                let mut next = filter_range_end.clone();
+                                next.range_end_ms = parse_datetime_ms(&evt.value());
+                                actions_range_end.set_ace_filter(next);
+                            },
+                        }
+                    }
+                    span { class: "text-slate-400", "UTC" }
+                    if range_inverted {
+                        span { class: "text-red-600", "开始时间晚于结束时间" }
+                    }
+                }
+                if !filter.is_empty() {
+                    button {
+                        class: "rounded border border-slate-300 px-2 py-1 text-[11px] text-slate-600 hover:bg-slate-100",
+                        onclick: move |_| {
+                            custom_range.set(false);
+                            actions_reset.set_ace_filter(AceCycleFilter::default());
+                        },
+                        "重置筛选"
+                    }
+                }
+                if listing_fallback {
+                    span {
+                        class: "rounded bg-amber-100 px-2 py-0.5 text-amber-700",
+                        title: "周期列表接口不可用，已根据 awareness 事件推断 lane 与状态",
+                        "事件推断"
+                    }
+                }
+            }
             {body}
         }
     }
@@ -94,7 +290,7 @@ impl Eq for CycleListProps {}
 #[component]
 fn CycleList(props: CycleListProps) -> Element {
     rsx! {
-        div { class: "md:w-1/3 space-y-2",
+        div { class: "space-y-2",
             for cycle in props.cycles.iter().cloned() {
                 {
                     let cycle_id = cycle.cycle_id.clone();
@@ -146,9 +342,11 @@ fn CycleListItem(props: CycleListItemProps) -> Element {
 
     let cycle_id = props.cycle.cycle_id.clone();
     let actions = props.actions.clone();
+    let dom_id = format!("ace-cycle-{cycle_id}");
 
     rsx! {
         button {
+            id: "{dom_id}",
             class: format!(
                 "w-full rounded-lg border px-3 py-2 text-left text-xs shadow-sm transition-colors {}",
                 card_class
@@ -196,6 +394,13 @@ impl Eq for CycleDetailProps {}
 #[component]
 fn CycleDetail(props: CycleDetailProps) -> Element {
     let actions = props.actions.clone();
+    // 周期产生的 DFR 决策以后端检索结果为准
+    let dfr_decisions = use_dfr_decision_list(
+        props
+            .cycle
+            .as_ref()
+            .map(|cycle| DecisionLookup::Cycle(cycle.cycle_id.clone())),
+    );
     let Some(cycle) = props.cycle.clone() else {
         return rsx! {
             div { class: "md:w-2/3 rounded-lg border border-slate-200 bg-white p-4 shadow-sm",
@@ -235,10 +440,17 @@ fn CycleDetail(props: CycleDetailProps) -> Element {
     let router_decision = snapshot.schedule.router_decision.clone();
     let created_at_str = format_offset_datetime_array(&snapshot.schedule.created_at);
     let outcomes = snapshot.outcomes.clone();
+    let dfr_links = render_decision_links(&dfr_decisions.read(), &actions, "该周期没有 DFR 决策");
 
     rsx! {
         div { class: "md:w-2/3 rounded-lg border border-slate-200 bg-white p-4 shadow-sm space-y-3",
-            h3 { class: "text-sm font-semibold text-slate-800", "周期详情" }
+            div { class: "flex items-center justify-between",
+                h3 { class: "text-sm font-semibold text-slate-800", "周期详情" }
+                div { class: "flex flex-wrap items-center gap-1 text-[11px]",
+                    span { class: "text-slate-500", "DFR 决策:" }
+                    {dfr_links}
+                }
+            }
             ul { class: "space-y-1 text-xs text-slate-600",
                 li { "周期 ID: {cycle.cycle_id}" }
                 li { "Lane: {format_lane(&cycle.lane)}" }
@@ -270,11 +482,102 @@ fn CycleDetail(props: CycleDetailProps) -> Element {
                     "metadata: {metadata}"
                 }
             }
-            { render_outbox_section(&outbox, actions.clone()) }
+            OutboxInspector {
+                outbox: outbox.clone(),
+                timeframe: snapshot.sync_point.timeframe.clone(),
+                actions: actions.clone(),
+            }
         }
     }
 }
 
+const ACE_LANES: &[AceLane] = &[
+    AceLane::Clarify,
+    AceLane::Tool,
+    AceLane::SelfReason,
+    AceLane::Collab,
+];
+
+const ACE_STATUSES: &[AceCycleStatus] = &[
+    AceCycleStatus::Pending,
+    AceCycleStatus::Running,
+    AceCycleStatus::Completed,
+    AceCycleStatus::Failed,
+    AceCycleStatus::Cancelled,
+];
+
+const ACE_TIME_WINDOWS: &[AceTimeWindow] = &[
+    AceTimeWindow::LastHour,
+    AceTimeWindow::LastDay,
+    AceTimeWindow::LastWeek,
+];
+
+fn lane_filter_value(lane: &AceLane) -> &'static str {
+    match lane {
+        AceLane::Clarify => "clarify",
+        AceLane::Tool => "tool",
+        AceLane::SelfReason => "self_reason",
+        AceLane::Collab => "collab",
+    }
+}
+
+fn parse_lane_filter(value: &str) -> Option<AceLane> {
+    ACE_LANES
+        .iter()
+        .find(|lane| lane_filter_value(lane) == value)
+        .cloned()
+}
+
+fn status_filter_value(status: &AceCycleStatus) -> &'static str {
+    match status {
+        AceCycleStatus::Pending => "pending",
+        AceCycleStatus::Running => "running",
+        AceCycleStatus::Completed => "completed",
+        AceCycleStatus::Failed => "failed",
+        AceCycleStatus::Cancelled => "cancelled",
+    }
+}
+
+fn parse_status_filter(value: &str) -> Option<AceCycleStatus> {
+    ACE_STATUSES
+        .iter()
+        .find(|status| status_filter_value(status) == value)
+        .cloned()
+}
+
+fn window_filter_value(window: &AceTimeWindow) -> &'static str {
+    match window {
+        AceTimeWindow::LastHour => "1h",
+        AceTimeWindow::LastDay => "24h",
+        AceTimeWindow::LastWeek => "7d",
+    }
+}
+
+fn parse_window_filter(value: &str) -> Option<AceTimeWindow> {
+    ACE_TIME_WINDOWS
+        .iter()
+        .find(|window| window_filter_value(window) == value)
+        .copied()
+}
+
+/// 时间筛选下拉中“自定义区间”的取值
+const WINDOW_CUSTOM: &str = "custom";
+
+/// 将 UTC 毫秒格式化为 `datetime-local` 输入框的取值（`YYYY-MM-DDTHH:MM`）
+fn format_datetime_input(ms: i64) -> String {
+    match OffsetDateTime::from_unix_timestamp(ms.div_euclid(1000)) {
+        Ok(dt) => format!(
+            "{:04}-{:02}-{:02}T{:02}:{:02}",
+            dt.year(),
+            u8::from(dt.month()),
+            dt.day(),
+            dt.hour(),
+            dt.minute()
+        ),
+        Err(_) => String::new(),
+    }
+}
+
 fn format_lane(lane: &AceLane) -> &'static str {
     match lane {
         AceLane::Clarify => "Clarify",
@@ -475,7 +778,7 @@ fn render_router_decision(decision: &RouterDecisionView) -> Element {
     }
 }
 
-const ROUTER_FORKS: &[AwarenessFork] = &[
+pub(crate) const ROUTER_FORKS: &[AwarenessFork] = &[
     AwarenessFork::Clarify,
     AwarenessFork::ToolPath,
     AwarenessFork::SelfReason,
@@ -483,12 +786,12 @@ const ROUTER_FORKS: &[AwarenessFork] = &[
 ];
 
 #[derive(Clone, Debug)]
-struct ForkScoreInfo {
-    score: f32,
-    components: Value,
+pub(crate) struct ForkScoreInfo {
+    pub score: f32,
+    pub components: Value,
 }
 
-fn extract_fork_scores(value: &Value) -> HashMap<AwarenessFork, ForkScoreInfo> {
+pub(crate) fn extract_fork_scores(value: &Value) -> HashMap<AwarenessFork, ForkScoreInfo> {
     let mut map = HashMap::new();
     let Some(obj) = value.as_object() else {
         return map;
@@ -560,7 +863,9 @@ fn format_decision_budget_estimate(budget: &Value) -> Option<String> {
     }
 }
 
-fn collect_degradation_reasons(decision: &RouterDecisionView) -> HashMap<AwarenessFork, String> {
+pub(crate) fn collect_degradation_reasons(
+    decision: &RouterDecisionView,
+) -> HashMap<AwarenessFork, String> {
     let mut map = HashMap::new();
     let plan = &decision.plan;
 
@@ -611,7 +916,7 @@ fn extract_budget_exceeded(diagnostics: &Value) -> Option<(AwarenessFork, String
     Some((fork, reason))
 }
 
-fn fork_display_name(fork: &AwarenessFork) -> &'static str {
+pub(crate) fn fork_display_name(fork: &AwarenessFork) -> &'static str {
     match fork {
         AwarenessFork::Clarify => "Clarify · 澄清",
         AwarenessFork::ToolPath => "Tool · 工具",
@@ -620,7 +925,7 @@ fn fork_display_name(fork: &AwarenessFork) -> &'static str {
     }
 }
 
-fn awareness_degradation_label(reason: &AwarenessDegradationReason) -> &'static str {
+pub(crate) fn awareness_degradation_label(reason: &AwarenessDegradationReason) -> &'static str {
     match reason {
         AwarenessDegradationReason::BudgetTokens => "预算 Token 超限",
         AwarenessDegradationReason::BudgetWalltime => "预算时间超限",
@@ -919,254 +1224,7 @@ fn render_sync_point_manifest_section(
         }
     }
 }
-fn render_outbox_section(outbox: &[OutboxMessageView], actions: AppActions) -> Element {
-    let summary_label = format!("Outbox 消息 ({})", outbox.len());
-    let rows = build_outbox_rows(outbox);
-    let button_row = serde_json::to_string_pretty(outbox)
-        .ok()
-        .map(Rc::new)
-        .map(|json| {
-            let copy_actions = actions.clone();
-            let export_actions = actions.clone();
-            let json_for_copy = json.clone();
-            let json_for_export = json.clone();
-            let filename = Rc::new("outbox-messages.json".to_string());
-            rsx! {
-                div { class: "flex flex-wrap gap-2",
-                    button {
-                        class: "rounded bg-slate-900 px-2 py-1 text-[11px] font-semibold text-white hover:bg-slate-800",
-                        onclick: move |_| copy_text_to_clipboard(copy_actions.clone(), "Outbox JSON", "ace:outbox:list", (*json_for_copy).clone()),
-                        "复制 Outbox JSON"
-                    }
-                    button {
-                        class: "rounded border border-slate-300 px-2 py-1 text-[11px] text-slate-700 hover:bg-slate-100",
-                        onclick: move |_| export_text_as_file(export_actions.clone(), "Outbox JSON", "ace:outbox:list", (*filename).clone(), (*json_for_export).clone()),
-                        "导出 Outbox JSON"
-                    }
-                }
-            }
-        });
-
-    rsx! {
-        details { class: "rounded border border-slate-200 bg-white p-3 text-xs text-slate-700 space-y-2",
-            summary { class: "cursor-pointer text-[11px] font-semibold text-slate-800", "{summary_label}" }
-            if rows.is_empty() {
-                p { class: "text-[11px] text-slate-500", "暂无 Outbox 信息" }
-            } else {
-                div { class: "space-y-2",
-                    for row in rows.iter().cloned() {
-                        { render_outbox_row(row, actions.clone()) }
-                    }
-                }
-            }
-            if let Some(row) = button_row {
-                {row}
-            }
-        }
-    }
-}
-fn render_outbox_row(row: OutboxRow, actions: AppActions) -> Element {
-    let timeline_actions = actions.clone();
-    let event_for_timeline = row.event.clone();
-    let event_id_label = row.event_id.clone();
-    let event_type_label = row.event_type_label.clone();
-    let occurred_at_label = row.occurred_at.clone();
-    let payload_summary = row.payload_summary.clone();
-    let degradation_label = row.degradation.clone();
-    let cycle_id = row.cycle_id;
-
-    let event_buttons = row.event_json.clone().map(|json| {
-        let copy_actions = actions.clone();
-        let export_actions = actions.clone();
-        let json_for_copy = json.clone();
-        let json_for_export = json.clone();
-        let filename = Rc::new(format!("outbox-event-{}.json", event_id_label.clone()));
-        rsx! {
-            Fragment {
-                button {
-                    class: "rounded border border-slate-300 px-2 py-1 text-[11px] text-slate-700 hover:bg-slate-100",
-                    onclick: move |_| copy_text_to_clipboard(copy_actions.clone(), "Outbox Event JSON", "ace:outbox:event", (*json_for_copy).clone()),
-                    "复制事件 JSON"
-                }
-                button {
-                    class: "rounded border border-slate-300 px-2 py-1 text-[11px] text-slate-700 hover:bg-slate-100",
-                    onclick: move |_| export_text_as_file(export_actions.clone(), "Outbox Event JSON", "ace:outbox:event", (*filename).clone(), (*json_for_export).clone()),
-                    "导出事件 JSON"
-                }
-            }
-        }
-    });
-
-    let payload_buttons = row.payload_json.clone().map(|json| {
-        let copy_actions = actions.clone();
-        let export_actions = actions.clone();
-        let json_for_copy = json.clone();
-        let json_for_export = json.clone();
-        let filename = Rc::new(format!("outbox-payload-{}.json", event_id_label.clone()));
-        rsx! {
-            Fragment {
-                button {
-                    class: "rounded border border-slate-300 px-2 py-1 text-[11px] text-slate-700 hover:bg-slate-100",
-                    onclick: move |_| copy_text_to_clipboard(copy_actions.clone(), "Outbox Payload JSON", "ace:outbox:payload", (*json_for_copy).clone()),
-                    "复制 Payload"
-                }
-                button {
-                    class: "rounded border border-slate-300 px-2 py-1 text-[11px] text-slate-700 hover:bg-slate-100",
-                    onclick: move |_| export_text_as_file(export_actions.clone(), "Outbox Payload JSON", "ace:outbox:payload", (*filename).clone(), (*json_for_export).clone()),
-                    "导出 Payload"
-                }
-            }
-        }
-    });
-
-    rsx! {
-        div { class: "rounded border border-slate-200 bg-slate-50 p-2 space-y-1",
-            div { class: "flex flex-wrap items-center justify-between gap-2",
-                span { class: "font-semibold", "{event_type_label}" }
-                span { class: "text-[11px] text-slate-500", "# {event_id_label}" }
-                span { class: "text-[11px] text-slate-500", "{occurred_at_label}" }
-            }
-            p { class: "text-[10px] text-slate-500", "Cycle ID: {cycle_id}" }
-            if let Some(degrade) = degradation_label {
-                p { class: "text-[10px] text-amber-600", "降级: {degrade}" }
-            }
-            p { class: "text-[11px] text-slate-600 break-words", "{payload_summary}" }
-            div { class: "flex flex-wrap gap-2 pt-1",
-                button {
-                    class: "rounded bg-emerald-600 px-2 py-1 text-[11px] font-semibold text-white hover:bg-emerald-500",
-                    onclick: move |_| {
-                        let event_id_for_msg = event_id_label.clone();
-                        // 尝试将 Value 反序列化为 AwarenessEvent
-                        match serde_json::from_value::<AwarenessEvent>(event_for_timeline.clone()) {
-                            Ok(awareness_event) => {
-                                timeline_actions.append_timeline(Vec::new(), vec![awareness_event], None);
-                                timeline_actions.set_operation_success(format!("Outbox 事件 {} 已写入时间线", event_id_for_msg));
-                            }
-                            Err(e) => {
-                                timeline_actions.set_operation_error(format!("无法将事件写入时间线: {}", e));
-                            }
-                        }
-                    },
-                    "写入时间线"
-                }
-                if let Some(buttons) = event_buttons {
-                    {buttons}
-                }
-                if let Some(buttons) = payload_buttons {
-                    {buttons}
-                }
-            }
-        }
-    }
-}
-
-#[derive(Clone)]
-struct OutboxRow {
-    event: Value,
-    event_id: String,
-    cycle_id: u64,
-    occurred_at: String,
-    event_type_label: String,
-    degradation: Option<String>,
-    payload_summary: String,
-    payload_json: Option<Rc<String>>,
-    event_json: Option<Rc<String>>,
-}
-
-fn build_outbox_rows(outbox: &[OutboxMessageView]) -> Vec<OutboxRow> {
-    use soulseed_agi_core_models::AwarenessCycleId;
-    use std::str::FromStr;
-
-    outbox
-        .iter()
-        .map(|item| {
-            let event = item.payload.clone();
-
-            // 从 Value 中提取字段
-            let event_id = event
-                .get("event_id")
-                .and_then(|v| v.as_str())
-                .unwrap_or("unknown")
-                .to_string();
-
-            let event_type_str = event
-                .get("event_type")
-                .and_then(|v| v.as_str())
-                .unwrap_or("Unknown");
-            let event_type_label = event_type_str.to_string();
-
-            let occurred_at_ms = event
-                .get("occurred_at_ms")
-                .and_then(|v| v.as_i64())
-                .unwrap_or(0);
-            let occurred_at = format_timestamp_ms(occurred_at_ms);
-
-            let degradation = event
-                .get("degradation_reason")
-                .and_then(|v| serde_json::from_value::<AwarenessDegradationReason>(v.clone()).ok())
-                .map(|reason| awareness_degradation_label(&reason).to_string());
-
-            let payload_value = event.get("payload").cloned().unwrap_or(Value::Null);
-            let payload_summary = summarize_outbox_payload(&payload_value);
-            let payload_json = serde_json::to_string_pretty(&payload_value)
-                .ok()
-                .map(Rc::new);
-            let event_json = serde_json::to_string_pretty(&event).ok().map(Rc::new);
-
-            // 将 Base36 字符串转换为 u64
-            let cycle_id = AwarenessCycleId::from_str(&item.cycle_id)
-                .map(|id| id.as_u64())
-                .unwrap_or_else(|_| {
-                    // 如果解析失败，尝试直接解析为 u64
-                    item.cycle_id.parse::<u64>().unwrap_or(0)
-                });
-
-            OutboxRow {
-                event,
-                event_id,
-                cycle_id,
-                occurred_at,
-                event_type_label,
-                degradation,
-                payload_summary,
-                payload_json,
-                event_json,
-            }
-        })
-        .collect()
-}
-
-fn summarize_outbox_payload(value: &Value) -> String {
-    if value.is_null() {
-        return "-".into();
-    }
-    if let Some(obj) = value.as_object() {
-        if let Some(kind) = obj.get("kind").and_then(|v| v.as_str()) {
-            return kind.to_string();
-        }
-        if let Some(content) = obj
-            .get("message")
-            .and_then(|v| v.as_object())
-            .and_then(|msg| msg.get("content"))
-            .and_then(|v| v.as_str())
-        {
-            return truncate_text(content, 96);
-        }
-        if let Some(summary) = obj.get("summary").and_then(|v| v.as_str()) {
-            return truncate_text(summary, 96);
-        }
-        if let Some(status) = obj.get("status").and_then(|v| v.as_str()) {
-            return status.to_string();
-        }
-        let keys: Vec<_> = obj.keys().take(3).cloned().collect();
-        if !keys.is_empty() {
-            return format!("{{{}}}", keys.join(", "));
-        }
-    }
-    truncate_text(&value.to_string(), 96)
-}
-
-fn format_timestamp_ms(ms: i64) -> String {
+pub(crate) fn format_timestamp_ms(ms: i64) -> String {
     let secs = ms.div_euclid(1000);
     let millis = ms.rem_euclid(1000);
     if let Ok(dt) = OffsetDateTime::from_unix_timestamp(secs) {
@@ -1283,7 +1341,7 @@ fn extract_event_summary_from_value(event: &Value) -> String {
     "-".into()
 }
 
-fn truncate_text(text: &str, limit: usize) -> String {
+pub(crate) fn truncate_text(text: &str, limit: usize) -> String {
     if text.len() <= limit {
         text.to_string()
     } else {
@@ -1336,7 +1394,12 @@ fn format_pending_injection_label(item: &HitlInjectionView) -> String {
 }
 
 #[cfg(target_arch = "wasm32")]
-fn copy_text_to_clipboard(actions: AppActions, label: &str, target: &str, content: String) {
+pub(crate) fn copy_text_to_clipboard(
+    actions: AppActions,
+    label: &str,
+    target: &str,
+    content: String,
+) {
     let label_text = label.to_string();
     let target_text = target.to_string();
     actions.record_audit_event(AuditActionKind::Copy, label_text.clone(), target_text);
@@ -1361,13 +1424,18 @@ fn copy_text_to_clipboard(actions: AppActions, label: &str, target: &str, conten
 }
 
 #[cfg(not(target_arch = "wasm32"))]
-fn copy_text_to_clipboard(actions: AppActions, label: &str, target: &str, _content: String) {
+pub(crate) fn copy_text_to_clipboard(
+    actions: AppActions,
+    label: &str,
+    target: &str,
+    _content: String,
+) {
     actions.record_audit_event(AuditActionKind::Copy, label.to_string(), target.to_string());
     actions.set_operation_success(format!("{label} 已复制（模拟）"));
 }
 
 #[cfg(target_arch = "wasm32")]
-fn export_text_as_file(
+pub(crate) fn export_text_as_file(
     actions: AppActions,
     label: &str,
     target: &str,
@@ -1416,7 +1484,7 @@ fn export_text_as_file(
 }
 
 #[cfg(not(target_arch = "wasm32"))]
-fn export_text_as_file(
+pub(crate) fn export_text_as_file(
     actions: AppActions,
     label: &str,
     target: &str,
@@ -5,8 +5,11 @@
 use dioxus::prelude::*;
 use std::rc::Rc;
 
-use crate::hooks::autonomous::{use_autonomous_control, use_autonomous_session, AutonomousControlResult};
+use crate::hooks::autonomous::{is_autonomous_active, use_autonomous_control, use_autonomous_session, AutonomousAdjustment, AutonomousControlResult};
 use crate::models::{AgendaItemInput, AutonomousConfig, AutonomousSessionSummary, AutonomousStatus, StartAutonomousRequest};
+use crate::state::{use_app_actions, use_app_state, GuardrailConfig, GuardrailState, GuardrailViolation};
+use crate::ui::autonomous_monitor::AutonomousSessionMonitor;
+use crate::ui::scenario_stack::ScenarioStackTimeline;
 
 /// 启动表单组件 - 独立组件避免轮询导致的焦点丢失
 /// 注意：agenda_input 状态从父组件传入，确保轮询时不会丢失
@@ -77,6 +80,148 @@ fn StartSessionForm(
     }
 }
 
+/// 客户端护栏设置，阈值填 0 表示关闭该规则
+#[component]
+fn GuardrailSettings(config: GuardrailConfig) -> Element {
+    let actions = use_app_actions();
+    let mut expanded = use_signal(|| false);
+
+    let config_enabled = config.clone();
+    let config_auto = config.clone();
+    let config_cost = config.clone();
+    let config_failures = config.clone();
+    let config_repeats = config.clone();
+    let config_tokens = config.clone();
+    let actions_enabled = actions.clone();
+    let actions_auto = actions.clone();
+    let actions_cost = actions.clone();
+    let actions_failures = actions.clone();
+    let actions_repeats = actions.clone();
+    let actions_tokens = actions.clone();
+
+    let summary = if !config.enabled {
+        "未启用".to_string()
+    } else if config.auto_terminate {
+        "告警并自动终止".to_string()
+    } else {
+        "仅告警".to_string()
+    };
+
+    rsx! {
+        div { class: "rounded-lg border border-slate-200 bg-white p-3 space-y-2",
+            div { class: "flex items-center justify-between",
+                div { class: "flex items-center gap-2",
+                    span { class: "text-sm font-semibold text-slate-800", "客户端护栏" }
+                    span { class: "text-xs text-slate-500", "{summary}" }
+                }
+                button {
+                    r#type: "button",
+                    class: "text-xs text-blue-600 hover:underline",
+                    onclick: move |_| {
+                        let next = !*expanded.read();
+                        expanded.set(next);
+                    },
+                    {if *expanded.read() { "收起" } else { "设置" }}
+                }
+            }
+            if *expanded.read() {
+                div { class: "flex flex-wrap items-center gap-3 rounded border border-slate-100 bg-slate-50 p-3 text-[11px] text-slate-600",
+                    label { class: "flex items-center gap-1",
+                        input {
+                            r#type: "checkbox",
+                            checked: config.enabled,
+                            onchange: move |evt| {
+                                let mut next = config_enabled.clone();
+                                next.enabled = evt.checked();
+                                actions_enabled.set_guardrail_config(next);
+                            },
+                        }
+                        "启用护栏"
+                    }
+                    label { class: "flex items-center gap-1",
+                        input {
+                            r#type: "checkbox",
+                            checked: config.auto_terminate,
+                            onchange: move |evt| {
+                                let mut next = config_auto.clone();
+                                next.auto_terminate = evt.checked();
+                                actions_auto.set_guardrail_config(next);
+                            },
+                        }
+                        "触发时自动终止"
+                    }
+                    label { class: "flex items-center gap-1",
+                        "成本速率 $/分钟"
+                        input {
+                            class: "w-20 rounded border border-slate-300 px-2 py-1",
+                            r#type: "number",
+                            min: "0",
+                            step: "0.01",
+                            value: "{config.cost_per_minute}",
+                            onchange: move |evt| {
+                                if let Ok(value) = evt.value().trim().parse::<f64>() {
+                                    let mut next = config_cost.clone();
+                                    next.cost_per_minute = value.max(0.0);
+                                    actions_cost.set_guardrail_config(next);
+                                }
+                            },
+                        }
+                    }
+                    label { class: "flex items-center gap-1",
+                        "连续失败"
+                        input {
+                            class: "w-16 rounded border border-slate-300 px-2 py-1",
+                            r#type: "number",
+                            min: "0",
+                            value: "{config.max_consecutive_failures}",
+                            onchange: move |evt| {
+                                if let Ok(value) = evt.value().trim().parse::<u32>() {
+                                    let mut next = config_failures.clone();
+                                    next.max_consecutive_failures = value;
+                                    actions_failures.set_guardrail_config(next);
+                                }
+                            },
+                        }
+                    }
+                    label { class: "flex items-center gap-1",
+                        "重复消息"
+                        input {
+                            class: "w-16 rounded border border-slate-300 px-2 py-1",
+                            r#type: "number",
+                            min: "0",
+                            value: "{config.max_repeated_messages}",
+                            onchange: move |evt| {
+                                if let Ok(value) = evt.value().trim().parse::<u32>() {
+                                    let mut next = config_repeats.clone();
+                                    next.max_repeated_messages = value;
+                                    actions_repeats.set_guardrail_config(next);
+                                }
+                            },
+                        }
+                    }
+                    label { class: "flex items-center gap-1",
+                        "单周期 Token"
+                        input {
+                            class: "w-24 rounded border border-slate-300 px-2 py-1",
+                            r#type: "number",
+                            min: "0",
+                            value: "{config.max_tokens_per_cycle}",
+                            onchange: move |evt| {
+                                if let Ok(value) = evt.value().trim().parse::<u64>() {
+                                    let mut next = config_tokens.clone();
+                                    next.max_tokens_per_cycle = value;
+                                    actions_tokens.set_guardrail_config(next);
+                                }
+                            },
+                        }
+                    }
+                }
+                p { class: "text-[11px] text-slate-400", "基于会话最近执行日志评估，阈值为 0 时关闭对应规则；自动终止每个会话只提交一次" }
+            }
+        }
+    }
+}
+
 /// 自主延续控制面板组件
 #[component]
 pub fn AutonomousPanel() -> Element {
@@ -85,6 +230,25 @@ pub fn AutonomousPanel() -> Element {
     let mut show_start_form = use_signal(|| false);
     // 将 agenda_input 状态提升到父组件，避免轮询时丢失
     let mut agenda_input = use_signal(|| String::new());
+    // 当前展开实时监控的会话
+    let monitored = use_signal(|| None::<String>);
+    let actions = use_app_actions();
+    let app_state = use_app_state();
+    let terminate_fn = Rc::new(control.terminate);
+
+    // 每次轮询后在客户端评估护栏，按配置自动终止失控会话
+    {
+        let terminate_fn = terminate_fn.clone();
+        let actions = actions.clone();
+        use_effect(move || {
+            let sessions = session_state.read().sessions.clone();
+            // 审计记录与已执行标记在终止请求返回后由 settle_guardrail_termination 写入
+            for (orchestration_id, reason) in actions.evaluate_guardrails(&sessions) {
+                tracing::warn!("护栏自动终止 {orchestration_id}: {reason}");
+                terminate_fn(orchestration_id, reason);
+            }
+        });
+    }
 
     let state = session_state.read();
     let starting = *control.starting.read();
@@ -110,6 +274,13 @@ pub fn AutonomousPanel() -> Element {
                     }
                 }
             }
+            Some(AutonomousControlResult::Adjusted(label)) => {
+                rsx! {
+                    div { class: "rounded-lg border border-blue-200 bg-blue-50 p-3 mb-3",
+                        p { class: "text-sm text-blue-700", "已调整: {label}" }
+                    }
+                }
+            }
             Some(AutonomousControlResult::Error(err)) => {
                 rsx! {
                     div { class: "rounded-lg border border-red-200 bg-red-50 p-3 mb-3",
@@ -155,7 +326,18 @@ pub fn AutonomousPanel() -> Element {
     }
 
     let sessions = state.sessions.clone();
-    let terminate_fn = Rc::new(control.terminate);
+    let guardrail = app_state.read().guardrail.clone();
+    let adjust_fn = Rc::new(control.adjust);
+    let adjusting = *control.adjusting.read();
+    let revision = *control.revision.read();
+    let monitored_id = monitored.read().clone();
+    let monitored_session = monitored_id
+        .as_ref()
+        .and_then(|id| sessions.iter().find(|s| &s.orchestration_id == id));
+    let monitored_logs = monitored_session
+        .map(|s| s.recent_logs.clone())
+        .unwrap_or_default();
+    let monitored_active = monitored_session.is_some_and(|s| is_autonomous_active(s.status));
 
     rsx! {
         section { class: "space-y-3",
@@ -166,15 +348,38 @@ pub fn AutonomousPanel() -> Element {
             {result_notification}
 
             div { class: "space-y-4",
+                GuardrailSettings { config: guardrail.config.clone() }
+
                 // 会话列表
                 if !sessions.is_empty() {
                     div { class: "space-y-3",
                         for session in sessions.iter() {
-                            {render_session_card(session, terminating, terminate_fn.clone())}
+                            {render_session_card(session, terminating, terminate_fn.clone(), adjusting, adjust_fn.clone(), monitored, &guardrail)}
                         }
                     }
                 }
 
+                // 实时监控
+                if let Some(orchestration_id) = monitored_id {
+                    AutonomousSessionMonitor {
+                        key: "{orchestration_id}",
+                        orchestration_id: orchestration_id.clone(),
+                        recent_logs: monitored_logs,
+                        revision: revision,
+                        adjusting: adjusting,
+                        on_adjust: {
+                            let adjust_fn = adjust_fn.clone();
+                            let target = orchestration_id.clone();
+                            move |adjustment: AutonomousAdjustment| adjust_fn(target.clone(), adjustment)
+                        },
+                    }
+                    ScenarioStackTimeline {
+                        key: "scenario-{orchestration_id}",
+                        orchestration_id: orchestration_id.clone(),
+                        active: monitored_active,
+                    }
+                }
+
                 // 启动表单 - 使用独立组件，状态从父组件传入
                 // 使用 key 确保组件身份稳定，避免轮询时重建
                 if show_form_val {
@@ -240,6 +445,10 @@ fn render_session_card(
     session: &AutonomousSessionSummary,
     terminating: bool,
     terminate_fn: Rc<Box<dyn Fn(String, String) + 'static>>,
+    adjusting: bool,
+    adjust_fn: Rc<Box<dyn Fn(String, AutonomousAdjustment) + 'static>>,
+    mut monitored: Signal<Option<String>>,
+    guardrail: &GuardrailState,
 ) -> Element {
     let status_style = match session.status {
         AutonomousStatus::Running => ("bg-green-100 text-green-700", "animate-pulse"),
@@ -263,8 +472,20 @@ fn render_session_card(
 
     let is_active = session.status == AutonomousStatus::Running
         || session.status == AutonomousStatus::Starting;
+    let is_paused = session.status == AutonomousStatus::Paused;
 
     let orch_id = session.orchestration_id.clone();
+    let violations: Vec<GuardrailViolation> = guardrail
+        .violations
+        .iter()
+        .filter(|violation| violation.orchestration_id == orch_id)
+        .cloned()
+        .collect();
+    let enforced = guardrail.enforced.contains(&orch_id);
+    let enforcing = guardrail.pending.contains_key(&orch_id);
+    let is_monitored = monitored.read().as_deref() == Some(orch_id.as_str());
+    let monitor_target = orch_id.clone();
+    let pause_target = orch_id.clone();
     let orch_id_display = if orch_id.len() > 20 {
         format!("{}...", &orch_id[..20])
     } else {
@@ -289,15 +510,60 @@ fn render_session_card(
                     }
                     span { class: "text-xs text-slate-500 font-mono", "{orch_id_display}" }
                 }
-                if is_active {
+                div { class: "flex items-center gap-2",
                     button {
                         r#type: "button",
-                        class: "rounded bg-red-600 px-3 py-1.5 text-xs font-semibold text-white hover:bg-red-500 disabled:opacity-50",
-                        disabled: terminating,
+                        class: "rounded border border-blue-300 px-3 py-1.5 text-xs text-blue-700 hover:bg-blue-50",
                         onclick: move |_| {
-                            terminate_fn(orch_id.clone(), "用户手动终止".to_string());
+                            if is_monitored {
+                                monitored.set(None);
+                            } else {
+                                monitored.set(Some(monitor_target.clone()));
+                            }
                         },
-                        {if terminating { "终止中..." } else { "终止" }}
+                        {if is_monitored { "收起监控" } else { "实时监控" }}
+                    }
+                    if is_active || is_paused {
+                        button {
+                            r#type: "button",
+                            class: "rounded border border-amber-300 px-3 py-1.5 text-xs text-amber-700 hover:bg-amber-50 disabled:opacity-50",
+                            disabled: adjusting,
+                            onclick: move |_| {
+                                let adjustment = if is_paused {
+                                    AutonomousAdjustment::Resume
+                                } else {
+                                    AutonomousAdjustment::Pause
+                                };
+                                adjust_fn(pause_target.clone(), adjustment);
+                            },
+                            {if is_paused { "恢复" } else { "暂停" }}
+                        }
+                    }
+                    if is_active || is_paused {
+                        button {
+                            r#type: "button",
+                            class: "rounded bg-red-600 px-3 py-1.5 text-xs font-semibold text-white hover:bg-red-500 disabled:opacity-50",
+                            disabled: terminating,
+                            onclick: move |_| {
+                                terminate_fn(orch_id.clone(), "用户手动终止".to_string());
+                            },
+                            {if terminating { "终止中..." } else { "终止" }}
+                        }
+                    }
+                }
+            }
+
+            // 护栏告警
+            if !violations.is_empty() || enforced || enforcing {
+                div { class: "mb-3 p-2 bg-red-50 rounded border border-red-200 space-y-1",
+                    p { class: "text-xs text-red-700 font-medium",
+                        {if enforced { "客户端护栏已触发，会话已终止" } else if enforcing { "客户端护栏已触发，正在提交终止请求" } else { "客户端护栏告警" }}
+                    }
+                    for violation in violations.iter() {
+                        div { class: "flex items-start gap-2 text-xs",
+                            span { class: "rounded bg-red-100 px-1.5 py-0.5 text-red-700", "{violation.rule.label()}" }
+                            span { class: "flex-1 text-red-800", "{violation.message}" }
+                        }
                     }
                 }
             }
@@ -0,0 +1,801 @@
+//! 自主会话实时监控
+//!
+//! 轮询单个自主会话的状态，绘制周期、token 与成本曲线，并预测各终止条件的触发时间
+
+use std::collections::BTreeMap;
+
+use dioxus::prelude::*;
+
+use crate::hooks::autonomous::{
+    is_autonomous_active, use_autonomous_monitor, AutonomousAdjustment,
+};
+use crate::models::{
+    AgendaItemInput, AgendaItemResponse, AgendaItemStatus, AutonomousConfig,
+    AutonomousSessionState, AutonomousStatus, ExecutionLogEntry, TerminationConditions,
+};
+use crate::ui::util::format_clock;
+
+const CHART_WIDTH: f64 = 320.0;
+const CHART_HEIGHT: f64 = 80.0;
+const MAX_PRIORITY: u8 = 10;
+const DEFAULT_PRIORITY: u8 = 5;
+
+/// 曲线：(名称, 颜色)
+const SERIES: &[(&str, &str)] = &[
+    ("周期", "#2563eb"),
+    ("Token", "#ea580c"),
+    ("成本", "#9333ea"),
+];
+
+/// 按时间累计的一个采样点
+#[derive(Clone, Copy, Debug)]
+struct CumulativePoint {
+    timestamp_ms: i64,
+    cycles: f64,
+    tokens: f64,
+    cost: f64,
+}
+
+impl CumulativePoint {
+    fn value(&self, series: usize) -> f64 {
+        match series {
+            0 => self.cycles,
+            1 => self.tokens,
+            _ => self.cost,
+        }
+    }
+}
+
+/// 将日志换算为累计曲线，并以会话总量为终点对齐（日志只覆盖最近一段时间）
+fn cumulative_series(
+    logs: &[ExecutionLogEntry],
+    session: &AutonomousSessionState,
+) -> Vec<CumulativePoint> {
+    let stats = &session.ac_stats;
+    let window_tokens: u64 = logs.iter().map(|log| log.tokens_used).sum();
+    let window_cost: f64 = logs.iter().map(|log| log.cost).sum();
+
+    let mut cycles = f64::from(stats.total_count).max(logs.len() as f64) - logs.len() as f64;
+    let mut tokens = stats.total_tokens.saturating_sub(window_tokens) as f64;
+    let mut cost = (stats.total_cost - window_cost).max(0.0);
+
+    let mut points = Vec::with_capacity(logs.len());
+    for log in logs.iter() {
+        cycles += 1.0;
+        tokens += log.tokens_used as f64;
+        cost += log.cost;
+        points.push(CumulativePoint {
+            timestamp_ms: log.timestamp_ms,
+            cycles,
+            tokens,
+            cost,
+        });
+    }
+    points
+}
+
+/// 终止条件的预测结果
+struct Projection {
+    label: &'static str,
+    current: String,
+    limit: String,
+    ratio: f64,
+    eta_ms: Option<f64>,
+    note: String,
+}
+
+/// 根据近期日志估算速率；日志不足两条时退回整个会话的平均速率
+fn project_conditions(
+    conditions: &TerminationConditions,
+    logs: &[ExecutionLogEntry],
+    session: &AutonomousSessionState,
+) -> Vec<Projection> {
+    let (span_ms, cycles, cost, idles) = match (logs.first(), logs.last()) {
+        (Some(first), Some(last)) if logs.len() >= 2 && last.timestamp_ms > first.timestamp_ms => {
+            // 首条日志只标记起点，区间内发生的是其后的 n-1 个周期
+            let tail = &logs[1..];
+            (
+                (last.timestamp_ms - first.timestamp_ms) as f64,
+                tail.len() as f64,
+                tail.iter().map(|log| log.cost).sum::<f64>(),
+                tail.iter().filter(|log| log.status == "idle").count() as f64,
+            )
+        }
+        _ => (
+            (session.last_activity_at_ms - session.created_at_ms).max(0) as f64,
+            f64::from(conditions.ac_count),
+            conditions.cost_spent,
+            f64::from(conditions.idle_count),
+        ),
+    };
+    let rate = |amount: f64| (span_ms > 0.0 && amount > 0.0).then(|| amount / span_ms);
+    let cycle_rate = rate(cycles);
+    let cost_rate = rate(cost);
+    let idle_rate = rate(idles);
+
+    let mut projections = Vec::new();
+    if conditions.max_ac > 0 {
+        let remaining = f64::from(conditions.max_ac.saturating_sub(conditions.ac_count));
+        projections.push(Projection {
+            label: "最大 AC 数",
+            current: conditions.ac_count.to_string(),
+            limit: conditions.max_ac.to_string(),
+            ratio: f64::from(conditions.ac_count) / f64::from(conditions.max_ac),
+            eta_ms: cycle_rate.map(|rate| remaining / rate),
+            note: format!("剩余 {remaining:.0} 个周期"),
+        });
+    }
+    if conditions.cost_limit > 0.0 {
+        let remaining = (conditions.cost_limit - conditions.cost_spent).max(0.0);
+        let per_cycle = (cycles > 0.0).then(|| cost / cycles);
+        projections.push(Projection {
+            label: "成本上限",
+            current: format!("${:.4}", conditions.cost_spent),
+            limit: format!("${:.4}", conditions.cost_limit),
+            ratio: conditions.cost_spent / conditions.cost_limit,
+            eta_ms: cost_rate.map(|rate| remaining / rate),
+            note: match per_cycle {
+                Some(per_cycle) if per_cycle > 0.0 => {
+                    format!("约 {:.0} 个周期后触发", (remaining / per_cycle).ceil())
+                }
+                _ => "暂无成本消耗".into(),
+            },
+        });
+    }
+    if conditions.max_idle > 0 {
+        let remaining = f64::from(conditions.max_idle.saturating_sub(conditions.idle_count));
+        projections.push(Projection {
+            label: "空转次数",
+            current: conditions.idle_count.to_string(),
+            limit: conditions.max_idle.to_string(),
+            ratio: f64::from(conditions.idle_count) / f64::from(conditions.max_idle),
+            eta_ms: idle_rate.map(|rate| remaining / rate),
+            note: if idle_rate.is_some() {
+                format!("剩余 {remaining:.0} 次空转")
+            } else {
+                "近期未出现空转".into()
+            },
+        });
+    }
+    projections
+}
+
+fn format_eta(eta_ms: f64) -> String {
+    let secs = (eta_ms / 1000.0).round() as u64;
+    if secs < 60 {
+        format!("约 {secs} 秒")
+    } else if secs < 3600 {
+        format!("约 {} 分钟", secs / 60)
+    } else {
+        format!("约 {:.1} 小时", secs as f64 / 3600.0)
+    }
+}
+
+fn agenda_status_style(status: AgendaItemStatus) -> (&'static str, &'static str) {
+    match status {
+        AgendaItemStatus::InProgress => ("bg-blue-600 text-white animate-pulse", "执行中"),
+        AgendaItemStatus::Pending => ("bg-slate-100 text-slate-600", "待执行"),
+        AgendaItemStatus::Completed => ("bg-green-100 text-green-700", "已完成"),
+        AgendaItemStatus::Skipped => ("bg-yellow-100 text-yellow-700", "已跳过"),
+        AgendaItemStatus::Failed => ("bg-red-100 text-red-700", "失败"),
+    }
+}
+
+/// 执行中的排最前，待执行项保持服务端队列顺序，已结束的放最后
+fn agenda_bucket(item: &AgendaItemResponse) -> u8 {
+    match item.status {
+        AgendaItemStatus::InProgress => 0,
+        AgendaItemStatus::Pending => 1,
+        _ => 2,
+    }
+}
+
+/// 交换相邻两个待执行项后的完整顺序
+fn swapped_order(pending_ids: &[String], index: usize, other: usize) -> Vec<String> {
+    let mut order = pending_ids.to_vec();
+    order.swap(index, other);
+    order
+}
+
+/// 自主会话实时监控
+#[component]
+pub fn AutonomousSessionMonitor(
+    orchestration_id: String,
+    recent_logs: Vec<ExecutionLogEntry>,
+    /// 外部调整成功后的版本号，变化时立即刷新
+    revision: u32,
+    adjusting: bool,
+    on_adjust: EventHandler<AutonomousAdjustment>,
+) -> Element {
+    let mut refresh = use_signal(|| 0u32);
+    // 列表接口只返回最近几条日志，这里按周期 ID 累积，曲线才能覆盖整个监控期间
+    let mut log_buffer = use_signal(BTreeMap::<u64, ExecutionLogEntry>::new);
+    let mut buffered_for = use_signal(String::new);
+    let mut editing_config = use_signal(|| false);
+
+    // 两个计数器都只增不减，求和后任一变化都会触发刷新
+    let refresh_key = refresh.read().wrapping_add(revision);
+    let monitor = use_autonomous_monitor(Some(orchestration_id.clone()), refresh_key);
+
+    use_effect(use_reactive!(|(orchestration_id, recent_logs)| {
+        if *buffered_for.peek() != orchestration_id {
+            log_buffer.write().clear();
+            buffered_for.set(orchestration_id.clone());
+        }
+        let mut buffer = log_buffer.write();
+        for log in recent_logs {
+            buffer.insert(log.cycle_id, log);
+        }
+    }));
+
+    let state = monitor.read();
+    let Some(session) = state.session.as_ref() else {
+        return if let Some(ref err) = state.error {
+            rsx! {
+                div { class: "rounded-lg border border-red-200 bg-red-50 p-3 text-xs text-red-600", "{err}" }
+            }
+        } else {
+            rsx! {
+                div { class: "rounded-lg border border-slate-200 bg-white p-4 text-xs text-slate-500", "正在加载会话状态..." }
+            }
+        };
+    };
+
+    let mut logs: Vec<ExecutionLogEntry> = log_buffer.read().values().cloned().collect();
+    logs.sort_by_key(|log| (log.timestamp_ms, log.cycle_id));
+
+    let active = is_autonomous_active(session.status);
+    let points = cumulative_series(&logs, session);
+    let projections = project_conditions(&session.termination_conditions, &logs, session);
+    // 最先触发的条件高亮
+    let nearest = projections
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, projection)| projection.eta_ms.map(|eta| (idx, eta)))
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(idx, _)| idx);
+    let mut agenda: Vec<&AgendaItemResponse> = session.agenda_queue.iter().collect();
+    agenda.sort_by_key(|item| agenda_bucket(item));
+    let pending_ids: Vec<String> = agenda
+        .iter()
+        .filter(|item| item.status == AgendaItemStatus::Pending)
+        .map(|item| item.item_id.clone())
+        .collect();
+    let editable = matches!(
+        session.status,
+        AutonomousStatus::Starting | AutonomousStatus::Running | AutonomousStatus::Paused
+    );
+    let conditions = &session.termination_conditions;
+    let config_open = *editing_config.read();
+    let refreshed_at = state
+        .last_refreshed_at_ms
+        .map(format_clock)
+        .unwrap_or_default();
+    let poll_hint = if active {
+        "每 3 秒自动刷新"
+    } else if session.status == AutonomousStatus::Paused {
+        "会话已暂停，停止轮询"
+    } else {
+        "会话已结束，停止轮询"
+    };
+    let stats = &session.ac_stats;
+
+    rsx! {
+        div { class: "rounded-lg border border-blue-200 bg-white p-4 shadow-sm space-y-4",
+            div { class: "flex flex-wrap items-center justify-between gap-2",
+                div { class: "flex items-center gap-2",
+                    h3 { class: "text-sm font-semibold text-slate-800", "会话监控" }
+                    span { class: "font-mono text-xs text-slate-500", "{session.orchestration_id}" }
+                    span { class: "rounded bg-slate-100 px-2 py-0.5 text-[11px] text-slate-600", "{session.mode}" }
+                }
+                div { class: "flex items-center gap-2 text-[11px] text-slate-400",
+                    if active {
+                        span { class: "inline-block h-2 w-2 rounded-full bg-green-500 animate-pulse" }
+                    }
+                    span { "{poll_hint}" }
+                    if !refreshed_at.is_empty() {
+                        span { "· 更新于 {refreshed_at}" }
+                    }
+                    button {
+                        r#type: "button",
+                        class: "rounded border border-slate-300 px-2 py-0.5 text-slate-600 hover:bg-slate-100",
+                        onclick: move |_| {
+                            let next = refresh.peek().wrapping_add(1);
+                            refresh.set(next);
+                        },
+                        "刷新"
+                    }
+                }
+            }
+            if let Some(ref err) = state.error {
+                p { class: "text-xs text-red-500", "{err}" }
+            }
+
+            div { class: "grid grid-cols-2 md:grid-cols-4 gap-2 text-center text-xs",
+                div { class: "rounded bg-slate-50 p-2",
+                    p { class: "text-sm font-bold text-blue-600", "{stats.completed_count}/{stats.total_count}" }
+                    p { class: "text-slate-500", "完成 / 总 AC" }
+                }
+                div { class: "rounded bg-slate-50 p-2",
+                    p { class: "text-sm font-bold text-red-600", "{stats.failed_count}" }
+                    p { class: "text-slate-500", "失败" }
+                }
+                div { class: "rounded bg-slate-50 p-2",
+                    p { class: "text-sm font-bold text-slate-700", {format!("{}ms", stats.average_duration_ms)} }
+                    p { class: "text-slate-500", "平均耗时" }
+                }
+                div { class: "rounded bg-slate-50 p-2",
+                    p { class: "text-sm font-bold text-purple-600", {format!("${:.4}", stats.total_cost)} }
+                    p { class: "text-slate-500", {format!("{} tokens", stats.total_tokens)} }
+                }
+            }
+
+            {render_usage_chart(&points)}
+            {render_projections(&projections, nearest)}
+
+            if editable {
+                div { class: "space-y-2",
+                    button {
+                        r#type: "button",
+                        class: "text-xs text-blue-700 hover:underline",
+                        onclick: move |_| editing_config.set(!config_open),
+                        if config_open { "收起终止条件调整" } else { "调整终止条件" }
+                    }
+                    if config_open {
+                        ConfigEditor {
+                            max_ac: conditions.max_ac,
+                            cost_limit: conditions.cost_limit,
+                            max_idle: conditions.max_idle,
+                            disabled: adjusting,
+                            on_adjust: move |adjustment| {
+                                on_adjust.call(adjustment);
+                                editing_config.set(false);
+                            },
+                        }
+                    }
+                }
+            }
+
+            div { class: "space-y-1",
+                p { class: "text-xs font-medium text-slate-600", {format!("议程队列（{}）", agenda.len())} }
+                if agenda.is_empty() {
+                    p { class: "text-xs italic text-slate-400", "议程为空" }
+                }
+                for item in agenda.iter() {
+                    {
+                        let position = pending_ids.iter().position(|id| *id == item.item_id);
+                        let controls = editable.then_some(AgendaControls {
+                            position,
+                            pending_ids: &pending_ids,
+                            disabled: adjusting,
+                            on_adjust,
+                        });
+                        render_agenda_item(item, controls)
+                    }
+                }
+                if editable {
+                    AgendaAppendForm { disabled: adjusting, on_adjust: on_adjust }
+                }
+            }
+        }
+    }
+}
+
+fn render_usage_chart(points: &[CumulativePoint]) -> Element {
+    if points.len() < 2 {
+        return rsx! {
+            p { class: "text-xs italic text-slate-400", "执行日志不足，暂无法绘制趋势" }
+        };
+    }
+
+    let start = points[0].timestamp_ms;
+    let span = (points[points.len() - 1].timestamp_ms - start).max(1) as f64;
+    let last = points[points.len() - 1];
+    // 三条曲线量纲不同，各自按最大值归一化
+    let lines: Vec<(String, &str)> = SERIES
+        .iter()
+        .enumerate()
+        .map(|(series, (_, color))| {
+            let max = points
+                .iter()
+                .map(|point| point.value(series))
+                .fold(0.0_f64, f64::max)
+                .max(f64::EPSILON);
+            let coords = points
+                .iter()
+                .map(|point| {
+                    let x = (point.timestamp_ms - start) as f64 / span * CHART_WIDTH;
+                    let y = CHART_HEIGHT - point.value(series) / max * CHART_HEIGHT;
+                    format!("{x:.1},{y:.1}")
+                })
+                .collect::<Vec<_>>()
+                .join(" ");
+            (coords, *color)
+        })
+        .collect();
+    let latest = [
+        format!("{:.0}", last.cycles),
+        format!("{:.0}", last.tokens),
+        format!("${:.4}", last.cost),
+    ];
+    let legend: Vec<(&str, &str, String)> = SERIES
+        .iter()
+        .zip(latest)
+        .map(|((name, color), value)| (*name, *color, value))
+        .collect();
+    let view_box = format!("0 0 {CHART_WIDTH} {CHART_HEIGHT}");
+    let start_label = format_clock(start);
+    let end_label = format_clock(last.timestamp_ms);
+
+    rsx! {
+        div { class: "space-y-1 text-xs text-slate-600",
+            div { class: "flex flex-wrap items-center gap-3",
+                span { class: "font-medium", "累计趋势" }
+                for (name, color, value) in legend.iter() {
+                    span { class: "flex items-center gap-1",
+                        span { class: "inline-block h-2 w-3 rounded", style: "background: {color}" }
+                        "{name} {value}"
+                    }
+                }
+            }
+            svg {
+                class: "h-20 w-full rounded bg-slate-50",
+                view_box: "{view_box}",
+                preserve_aspect_ratio: "none",
+                for (coords, color) in lines.iter() {
+                    polyline {
+                        points: "{coords}",
+                        fill: "none",
+                        stroke: "{color}",
+                        stroke_width: "1.5",
+                    }
+                }
+            }
+            div { class: "flex justify-between text-[10px] text-slate-400",
+                span { "{start_label}" }
+                span { "{end_label}" }
+            }
+        }
+    }
+}
+
+fn render_projections(projections: &[Projection], nearest: Option<usize>) -> Element {
+    if projections.is_empty() {
+        return rsx! {};
+    }
+
+    rsx! {
+        div { class: "space-y-2 text-xs text-slate-600",
+            p { class: "font-medium", "终止条件预测" }
+            for (idx, projection) in projections.iter().enumerate() {
+                {
+                    let is_nearest = nearest == Some(idx);
+                    let container = if is_nearest {
+                        "rounded border border-amber-300 bg-amber-50 p-2 space-y-1"
+                    } else {
+                        "rounded border border-slate-100 p-2 space-y-1"
+                    };
+                    let bar_color = if projection.ratio >= 0.9 {
+                        "bg-red-500"
+                    } else if projection.ratio >= 0.7 {
+                        "bg-amber-500"
+                    } else {
+                        "bg-blue-500"
+                    };
+                    let width = (projection.ratio * 100.0).clamp(0.0, 100.0);
+                    let eta = projection
+                        .eta_ms
+                        .map(|eta| format!("{}后触发", format_eta(eta)))
+                        .unwrap_or_else(|| "无法预测".into());
+                    rsx! {
+                        div { class: container,
+                            div { class: "flex flex-wrap items-center gap-2",
+                                span { class: "w-20 font-medium text-slate-700", "{projection.label}" }
+                                span { class: "font-mono", "{projection.current} / {projection.limit}" }
+                                if is_nearest {
+                                    span { class: "rounded bg-amber-500 px-1.5 py-0.5 text-[10px] text-white", "最先触发" }
+                                }
+                                span { class: "ml-auto text-slate-500", "{eta}" }
+                            }
+                            div { class: "h-1.5 w-full overflow-hidden rounded bg-slate-200",
+                                div { class: "h-1.5 {bar_color}", style: "width: {width:.1}%" }
+                            }
+                            p { class: "text-[11px] text-slate-400", "{projection.note}" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// 可编辑会话中议程项的操作上下文
+struct AgendaControls<'a> {
+    /// 在待执行项中的位置，非待执行项为 None
+    position: Option<usize>,
+    pending_ids: &'a [String],
+    disabled: bool,
+    on_adjust: EventHandler<AutonomousAdjustment>,
+}
+
+fn render_agenda_item(item: &AgendaItemResponse, controls: Option<AgendaControls>) -> Element {
+    let (badge_class, badge_text) = agenda_status_style(item.status);
+    let container = if item.status == AgendaItemStatus::InProgress {
+        "flex flex-wrap items-center gap-2 rounded border-2 border-blue-400 bg-blue-50 p-2 text-xs"
+    } else {
+        "flex flex-wrap items-center gap-2 rounded border border-slate-100 p-2 text-xs"
+    };
+    let timing = match (item.started_at_ms, item.completed_at_ms) {
+        (Some(started), Some(completed)) => format!(
+            "{} → {}（{:.1}s）",
+            format_clock(started),
+            format_clock(completed),
+            (completed - started) as f64 / 1000.0
+        ),
+        (Some(started), None) => format!("开始于 {}", format_clock(started)),
+        _ => String::new(),
+    };
+    let editor = controls.and_then(|controls| {
+        controls
+            .position
+            .map(|index| render_agenda_controls(item, index, &controls))
+    });
+
+    rsx! {
+        div { class: container,
+            span { class: "rounded px-2 py-0.5 text-[11px] {badge_class}", "{badge_text}" }
+            span { class: "rounded bg-slate-100 px-1.5 py-0.5 font-mono text-[10px] text-slate-500", "P{item.priority}" }
+            span { class: "flex-1 text-slate-700", "{item.description}" }
+            if !timing.is_empty() {
+                span { class: "text-[11px] text-slate-400", "{timing}" }
+            }
+            if let Some(editor) = editor {
+                {editor}
+            }
+        }
+    }
+}
+
+/// 待执行项的优先级、排序与跳过操作
+fn render_agenda_controls(
+    item: &AgendaItemResponse,
+    index: usize,
+    controls: &AgendaControls,
+) -> Element {
+    let on_adjust = controls.on_adjust;
+    let disabled = controls.disabled;
+    let current_priority = item.priority;
+    let priority_item = item.item_id.clone();
+    let skip_item = item.item_id.clone();
+    let move_up = (index > 0).then(|| swapped_order(controls.pending_ids, index, index - 1));
+    let move_down = (index + 1 < controls.pending_ids.len())
+        .then(|| swapped_order(controls.pending_ids, index, index + 1));
+    let up_disabled = disabled || move_up.is_none();
+    let down_disabled = disabled || move_down.is_none();
+
+    rsx! {
+        div { class: "flex items-center gap-1 text-[11px]",
+            select {
+                class: "rounded border border-slate-300 px-1 py-0.5",
+                title: "优先级",
+                disabled: disabled,
+                onchange: move |evt: FormEvent| {
+                    if let Ok(priority) = evt.value().parse::<u8>() {
+                        if priority != current_priority {
+                            on_adjust.call(AutonomousAdjustment::SetPriority {
+                                item_id: priority_item.clone(),
+                                priority,
+                            });
+                        }
+                    }
+                },
+                for priority in (0..=MAX_PRIORITY).rev() {
+                    option { value: "{priority}", selected: priority == current_priority, "P{priority}" }
+                }
+            }
+            button {
+                r#type: "button",
+                class: "rounded border border-slate-300 px-1.5 text-slate-600 hover:bg-slate-100 disabled:opacity-40",
+                title: "上移",
+                disabled: up_disabled,
+                onclick: move |_| {
+                    if let Some(order) = move_up.clone() {
+                        on_adjust.call(AutonomousAdjustment::Reorder(order));
+                    }
+                },
+                "↑"
+            }
+            button {
+                r#type: "button",
+                class: "rounded border border-slate-300 px-1.5 text-slate-600 hover:bg-slate-100 disabled:opacity-40",
+                title: "下移",
+                disabled: down_disabled,
+                onclick: move |_| {
+                    if let Some(order) = move_down.clone() {
+                        on_adjust.call(AutonomousAdjustment::Reorder(order));
+                    }
+                },
+                "↓"
+            }
+            button {
+                r#type: "button",
+                class: "rounded border border-amber-300 px-1.5 text-amber-700 hover:bg-amber-50 disabled:opacity-40",
+                disabled: disabled,
+                onclick: move |_| on_adjust.call(AutonomousAdjustment::Skip { item_id: skip_item.clone() }),
+                "跳过"
+            }
+        }
+    }
+}
+
+/// 向运行中的会话追加议程
+#[component]
+fn AgendaAppendForm(disabled: bool, on_adjust: EventHandler<AutonomousAdjustment>) -> Element {
+    let mut draft = use_signal(String::new);
+    let mut priority = use_signal(|| DEFAULT_PRIORITY);
+    let priority_value = *priority.read();
+
+    rsx! {
+        form {
+            class: "flex flex-wrap items-end gap-2 border-t border-slate-100 pt-2 text-xs",
+            onsubmit: move |evt: FormEvent| {
+                evt.prevent_default();
+                let priority = *priority.read();
+                let items: Vec<AgendaItemInput> = draft
+                    .read()
+                    .lines()
+                    .map(str::trim)
+                    .filter(|line| !line.is_empty())
+                    .map(|line| AgendaItemInput {
+                        description: line.to_string(),
+                        priority,
+                    })
+                    .collect();
+                if items.is_empty() {
+                    return;
+                }
+                on_adjust.call(AutonomousAdjustment::AddAgenda(items));
+                draft.set(String::new());
+            },
+            textarea {
+                class: "h-14 flex-1 rounded border border-slate-300 px-2 py-1",
+                placeholder: "追加议程（每行一个）",
+                value: "{draft}",
+                oninput: move |evt| draft.set(evt.value()),
+            }
+            select {
+                class: "rounded border border-slate-300 px-1 py-1",
+                title: "优先级",
+                onchange: move |evt: FormEvent| {
+                    if let Ok(value) = evt.value().parse::<u8>() {
+                        priority.set(value.min(MAX_PRIORITY));
+                    }
+                },
+                for value in (0..=MAX_PRIORITY).rev() {
+                    option { value: "{value}", selected: value == priority_value, "P{value}" }
+                }
+            }
+            button {
+                r#type: "submit",
+                class: "rounded bg-slate-900 px-3 py-1.5 font-semibold text-white hover:bg-slate-800 disabled:opacity-50",
+                disabled: disabled,
+                "追加"
+            }
+        }
+    }
+}
+
+fn parse_limit<T: std::str::FromStr>(label: &str, value: &str) -> Result<Option<T>, String> {
+    let trimmed = value.trim();
+    if trimmed.is_empty() {
+        return Ok(None);
+    }
+    trimmed
+        .parse::<T>()
+        .map(Some)
+        .map_err(|_| format!("{label} 不是有效数值"))
+}
+
+fn build_config(
+    max_ac: &str,
+    cost_limit: &str,
+    max_idle: &str,
+    idle_threshold: &str,
+) -> Result<AutonomousConfig, String> {
+    Ok(AutonomousConfig {
+        max_consecutive_ac: parse_limit("最大 AC 数", max_ac)?,
+        idle_threshold_ms: parse_limit("空闲阈值", idle_threshold)?,
+        cost_limit: parse_limit("成本上限", cost_limit)?,
+        max_idle_count: parse_limit("最大空转次数", max_idle)?,
+    })
+}
+
+/// 终止条件上限编辑，留空的字段保持不变
+#[component]
+fn ConfigEditor(
+    max_ac: u32,
+    cost_limit: f64,
+    max_idle: u32,
+    disabled: bool,
+    on_adjust: EventHandler<AutonomousAdjustment>,
+) -> Element {
+    let mut max_ac_input = use_signal(|| max_ac.to_string());
+    let mut cost_input = use_signal(|| format!("{cost_limit}"));
+    let mut idle_input = use_signal(|| max_idle.to_string());
+    let mut idle_threshold_input = use_signal(String::new);
+    let mut error = use_signal(|| Option::<String>::None);
+    let error_text = error.read().clone();
+
+    rsx! {
+        form {
+            class: "flex flex-wrap items-end gap-2 rounded border border-slate-200 bg-slate-50 p-2 text-xs",
+            onsubmit: move |evt: FormEvent| {
+                evt.prevent_default();
+                let config = build_config(
+                    &max_ac_input.read(),
+                    &cost_input.read(),
+                    &idle_input.read(),
+                    &idle_threshold_input.read(),
+                );
+                match config {
+                    Ok(config) => {
+                        error.set(None);
+                        on_adjust.call(AutonomousAdjustment::UpdateConfig(config));
+                    }
+                    Err(err) => error.set(Some(err)),
+                }
+            },
+            div { class: "flex flex-col gap-1",
+                label { class: "text-[11px] font-semibold text-slate-600", "最大 AC 数" }
+                input {
+                    class: "w-20 rounded border border-slate-300 px-2 py-1",
+                    r#type: "number",
+                    min: "0",
+                    value: "{max_ac_input}",
+                    oninput: move |evt| max_ac_input.set(evt.value()),
+                }
+            }
+            div { class: "flex flex-col gap-1",
+                label { class: "text-[11px] font-semibold text-slate-600", "成本上限 ($)" }
+                input {
+                    class: "w-24 rounded border border-slate-300 px-2 py-1",
+                    r#type: "number",
+                    min: "0",
+                    step: "0.01",
+                    value: "{cost_input}",
+                    oninput: move |evt| cost_input.set(evt.value()),
+                }
+            }
+            div { class: "flex flex-col gap-1",
+                label { class: "text-[11px] font-semibold text-slate-600", "最大空转次数" }
+                input {
+                    class: "w-20 rounded border border-slate-300 px-2 py-1",
+                    r#type: "number",
+                    min: "0",
+                    value: "{idle_input}",
+                    oninput: move |evt| idle_input.set(evt.value()),
+                }
+            }
+            div { class: "flex flex-col gap-1",
+                label { class: "text-[11px] font-semibold text-slate-600", "空闲阈值 (ms)" }
+                input {
+                    class: "w-24 rounded border border-slate-300 px-2 py-1",
+                    r#type: "number",
+                    min: "0",
+                    placeholder: "不变",
+                    value: "{idle_threshold_input}",
+                    oninput: move |evt| idle_threshold_input.set(evt.value()),
+                }
+            }
+            button {
+                r#type: "submit",
+                class: "rounded bg-slate-900 px-3 py-1.5 font-semibold text-white hover:bg-slate-800 disabled:opacity-50",
+                disabled: disabled,
+                "应用"
+            }
+            if let Some(err) = error_text {
+                span { class: "text-red-500", "{err}" }
+            }
+        }
+    }
+}
@@ -0,0 +1,408 @@
+use crate::hooks::ace::load_cycle_snapshots;
+use crate::models::{AwarenessFork, BudgetSnapshotView, RouteBudgetEstimate};
+use crate::state::{
+    use_app_actions, use_app_state, BudgetAlertConfig, BudgetAlertKind, BudgetMetric,
+};
+use crate::ui::ace::fork_display_name;
+use dioxus::prelude::*;
+
+const CHART_WIDTH: f64 = 480.0;
+const CHART_HEIGHT: f64 = 160.0;
+const CHART_PADDING: f64 = 24.0;
+
+const BUDGET_METRICS: &[BudgetMetric] = &[
+    BudgetMetric::Tokens,
+    BudgetMetric::Walltime,
+    BudgetMetric::ExternalCost,
+];
+
+#[derive(Clone)]
+struct CycleBudgetRow {
+    cycle_id: String,
+    fork: Option<AwarenessFork>,
+    budget: BudgetSnapshotView,
+    estimate: Option<RouteBudgetEstimate>,
+}
+
+fn metric_values(budget: &BudgetSnapshotView, metric: BudgetMetric) -> (f64, f64) {
+    match metric {
+        BudgetMetric::Tokens => (budget.tokens_spent as f64, budget.tokens_allowed as f64),
+        BudgetMetric::Walltime => (
+            budget.walltime_ms_used as f64,
+            budget.walltime_ms_allowed as f64,
+        ),
+        BudgetMetric::ExternalCost => (
+            budget.external_cost_spent as f64,
+            budget.external_cost_allowed as f64,
+        ),
+    }
+}
+
+fn estimate_value(estimate: &RouteBudgetEstimate, metric: BudgetMetric) -> f64 {
+    match metric {
+        BudgetMetric::Tokens => estimate.tokens as f64,
+        BudgetMetric::Walltime => estimate.walltime_ms as f64,
+        BudgetMetric::ExternalCost => estimate.external_cost as f64,
+    }
+}
+
+fn format_metric_value(metric: BudgetMetric, value: f64) -> String {
+    match metric {
+        BudgetMetric::Tokens => format!("{value:.0}"),
+        BudgetMetric::Walltime => format!("{value:.0} ms"),
+        BudgetMetric::ExternalCost => format!("{value:.2}"),
+    }
+}
+
+fn metric_value(metric: BudgetMetric) -> &'static str {
+    match metric {
+        BudgetMetric::Tokens => "tokens",
+        BudgetMetric::Walltime => "walltime",
+        BudgetMetric::ExternalCost => "external_cost",
+    }
+}
+
+fn parse_metric(value: &str) -> BudgetMetric {
+    BUDGET_METRICS
+        .iter()
+        .copied()
+        .find(|metric| metric_value(*metric) == value)
+        .unwrap_or(BudgetMetric::Tokens)
+}
+
+fn usage_bar_class(pct: f64) -> &'static str {
+    if pct >= 100.0 {
+        "bg-red-500"
+    } else if pct >= 80.0 {
+        "bg-amber-500"
+    } else {
+        "bg-emerald-500"
+    }
+}
+
+/// 按周期顺序累计额度与消耗，生成 SVG 折线坐标
+fn cumulative_polylines(rows: &[CycleBudgetRow], metric: BudgetMetric) -> (String, String, f64) {
+    let mut allowed_total = 0.0;
+    let mut spent_total = 0.0;
+    let mut allowed_points = Vec::with_capacity(rows.len());
+    let mut spent_points = Vec::with_capacity(rows.len());
+    for row in rows {
+        let (spent, allowed) = metric_values(&row.budget, metric);
+        allowed_total += allowed;
+        spent_total += spent;
+        allowed_points.push(allowed_total);
+        spent_points.push(spent_total);
+    }
+
+    let max = allowed_total.max(spent_total).max(1.0);
+    let step = if rows.len() > 1 {
+        (CHART_WIDTH - CHART_PADDING * 2.0) / (rows.len() - 1) as f64
+    } else {
+        0.0
+    };
+    let to_points = |values: &[f64]| -> String {
+        values
+            .iter()
+            .enumerate()
+            .map(|(idx, value)| {
+                let x = CHART_PADDING + step * idx as f64;
+                let y = CHART_HEIGHT
+                    - CHART_PADDING
+                    - value / max * (CHART_HEIGHT - CHART_PADDING * 2.0);
+                format!("{x:.1},{y:.1}")
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+
+    (to_points(&allowed_points), to_points(&spent_points), max)
+}
+
+struct ForkBudgetStats {
+    fork: AwarenessFork,
+    cycles: usize,
+    estimated: f64,
+    actual: f64,
+}
+
+fn fork_budget_stats(rows: &[CycleBudgetRow], metric: BudgetMetric) -> Vec<ForkBudgetStats> {
+    let mut stats: Vec<ForkBudgetStats> = Vec::new();
+    for row in rows {
+        let (Some(fork), Some(estimate)) = (row.fork.clone(), row.estimate.as_ref()) else {
+            continue;
+        };
+        let (spent, _) = metric_values(&row.budget, metric);
+        let estimated = estimate_value(estimate, metric);
+        match stats.iter_mut().find(|entry| entry.fork == fork) {
+            Some(entry) => {
+                entry.cycles += 1;
+                entry.estimated += estimated;
+                entry.actual += spent;
+            }
+            None => stats.push(ForkBudgetStats {
+                fork,
+                cycles: 1,
+                estimated,
+                actual: spent,
+            }),
+        }
+    }
+    stats
+}
+
+#[component]
+pub fn BudgetPanel() -> Element {
+    let actions = use_app_actions();
+    let app_state = use_app_state();
+    let snapshot = app_state.read();
+    let config = snapshot.budget.config.clone();
+    let alerts = snapshot.budget.alerts.clone();
+
+    // 周期列表按时间倒序，燃尽图按时间正序
+    let mut rows: Vec<CycleBudgetRow> = Vec::new();
+    let mut missing: Vec<String> = Vec::new();
+    for cycle in snapshot.ace.cycles.iter().rev() {
+        match snapshot.ace.snapshots.get(&cycle.cycle_id) {
+            Some(cycle_snapshot) => {
+                let decision = cycle_snapshot.schedule.router_decision.as_ref();
+                rows.push(CycleBudgetRow {
+                    cycle_id: cycle.cycle_id.clone(),
+                    fork: decision.map(|decision| decision.plan.fork.clone()),
+                    budget: cycle_snapshot.schedule.budget.clone(),
+                    estimate: decision.map(|decision| decision.plan.budget.clone()),
+                });
+            }
+            None => missing.push(cycle.cycle_id.clone()),
+        }
+    }
+    let loading_missing = missing
+        .iter()
+        .any(|id| snapshot.ace.batch_pending.contains(id));
+    let failed_missing = missing
+        .iter()
+        .filter(|id| snapshot.ace.batch_errors.contains_key(*id))
+        .count();
+    drop(snapshot);
+
+    let mut chart_metric = use_signal(|| BudgetMetric::Tokens);
+    let metric = *chart_metric.read();
+    let (allowed_points, spent_points, chart_max) = cumulative_polylines(&rows, metric);
+    let fork_stats = fork_budget_stats(&rows, metric);
+
+    let totals: Vec<(BudgetMetric, f64, f64)> = BUDGET_METRICS
+        .iter()
+        .map(|metric| {
+            let (spent, allowed) = rows.iter().fold((0.0, 0.0), |acc, row| {
+                let (spent, allowed) = metric_values(&row.budget, *metric);
+                (acc.0 + spent, acc.1 + allowed)
+            });
+            (*metric, spent, allowed)
+        })
+        .collect();
+
+    let config_enabled = config.clone();
+    let config_usage = config.clone();
+    let config_drift = config.clone();
+    let actions_enabled = actions.clone();
+    let actions_usage = actions.clone();
+    let actions_drift = actions.clone();
+    let actions_clear = actions.clone();
+    let actions_missing = actions.clone();
+    let missing_ids = missing.clone();
+
+    rsx! {
+        section { class: "rounded-lg border border-slate-200 bg-white p-4 shadow-sm space-y-3",
+            header { class: "flex flex-wrap items-start justify-between gap-2",
+                div { class: "space-y-1",
+                    h2 { class: "text-lg font-semibold text-slate-900", "预算分析" }
+                    p { class: "text-xs text-slate-500", "按周期累计额度与消耗，对比路由预估与实际开销" }
+                }
+                if !alerts.is_empty() {
+                    span { class: "rounded-full bg-red-100 px-3 py-1 text-[11px] font-semibold text-red-700", "预算告警 {alerts.len()}" }
+                }
+            }
+
+            div { class: "flex flex-wrap items-center gap-3 rounded border border-slate-100 bg-slate-50 p-3 text-[11px] text-slate-600",
+                label { class: "flex items-center gap-1",
+                    input {
+                        r#type: "checkbox",
+                        checked: config.enabled,
+                        onchange: move |evt| {
+                            let mut next: BudgetAlertConfig = config_enabled.clone();
+                            next.enabled = evt.checked();
+                            actions_enabled.set_budget_alert_config(next);
+                        },
+                    }
+                    "启用告警"
+                }
+                label { class: "flex items-center gap-1",
+                    "额度阈值 %"
+                    input {
+                        class: "w-16 rounded border border-slate-300 px-2 py-1",
+                        r#type: "number",
+                        min: "1",
+                        value: "{config.usage_pct}",
+                        onchange: move |evt| {
+                            if let Ok(value) = evt.value().trim().parse::<u32>() {
+                                let mut next = config_usage.clone();
+                                next.usage_pct = value.max(1);
+                                actions_usage.set_budget_alert_config(next);
+                            }
+                        },
+                    }
+                }
+                label { class: "flex items-center gap-1",
+                    "预估偏差阈值 %"
+                    input {
+                        class: "w-16 rounded border border-slate-300 px-2 py-1",
+                        r#type: "number",
+                        min: "1",
+                        value: "{config.estimate_drift_pct}",
+                        onchange: move |evt| {
+                            if let Ok(value) = evt.value().trim().parse::<u32>() {
+                                let mut next = config_drift.clone();
+                                next.estimate_drift_pct = value.max(1);
+                                actions_drift.set_budget_alert_config(next);
+                            }
+                        },
+                    }
+                }
+            }
+
+            if !missing.is_empty() {
+                div { class: "flex flex-wrap items-center gap-2 text-[11px] text-slate-500",
+                    span { "{missing.len()} 个周期尚未加载快照，未计入统计" }
+                    if failed_missing > 0 {
+                        span { class: "text-red-600", "{failed_missing} 个加载失败" }
+                    }
+                    button {
+                        class: "rounded border border-slate-300 px-2 py-0.5 text-slate-600 hover:bg-slate-100 disabled:opacity-50",
+                        disabled: loading_missing,
+                        onclick: move |_| {
+                            spawn(load_cycle_snapshots(actions_missing.clone(), app_state, missing_ids.clone()));
+                        },
+                        if loading_missing { "加载中…" } else if failed_missing > 0 { "重试加载" } else { "加载全部快照" }
+                    }
+                }
+            }
+
+            if rows.is_empty() {
+                p { class: "text-xs text-slate-500 italic", "暂无可用的周期预算数据" }
+            } else {
+                div { class: "grid gap-2 md:grid-cols-3",
+                    for (metric, spent, allowed) in totals.iter() {
+                        {
+                            let pct = if *allowed > 0.0 { spent / allowed * 100.0 } else { 0.0 };
+                            let width = pct.min(100.0);
+                            rsx! {
+                                div { class: "rounded border border-slate-200 p-2 space-y-1 text-[11px]",
+                                    div { class: "flex items-center justify-between",
+                                        span { class: "font-semibold text-slate-700", "{metric.label()}" }
+                                        span { class: "font-mono text-slate-500", "{pct:.0}%" }
+                                    }
+                                    div { class: "h-2 w-full rounded bg-slate-100",
+                                        div { class: format!("h-2 rounded {}", usage_bar_class(pct)), style: "width: {width:.1}%" }
+                                    }
+                                    p { class: "font-mono text-slate-500",
+                                        "{format_metric_value(*metric, *spent)} / {format_metric_value(*metric, *allowed)}"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                div { class: "space-y-2",
+                    div { class: "flex items-center justify-between",
+                        span { class: "text-[11px] font-semibold uppercase tracking-wide text-slate-500", "累计燃尽" }
+                        select {
+                            class: "rounded border border-slate-300 px-2 py-1 text-[11px] text-slate-600",
+                            value: metric_value(metric),
+                            onchange: move |evt| chart_metric.set(parse_metric(&evt.value())),
+                            for option_metric in BUDGET_METRICS.iter() {
+                                option { value: metric_value(*option_metric), "{option_metric.label()}" }
+                            }
+                        }
+                    }
+                    svg {
+                        class: "w-full rounded border border-slate-100 bg-slate-50",
+                        view_box: "0 0 {CHART_WIDTH} {CHART_HEIGHT}",
+                        polyline { points: "{allowed_points}", fill: "none", stroke: "#6366f1", stroke_width: "2" }
+                        polyline { points: "{spent_points}", fill: "none", stroke: "#ef4444", stroke_width: "2" }
+                        text { x: "{CHART_PADDING}", y: "14", font_size: "10", fill: "#64748b",
+                            "max {format_metric_value(metric, chart_max)}"
+                        }
+                    }
+                    div { class: "flex gap-3 text-[11px] text-slate-500",
+                        span { class: "text-indigo-600", "— 累计额度" }
+                        span { class: "text-red-600", "— 累计消耗" }
+                        span { "{rows.len()} 个周期" }
+                    }
+                }
+
+                if !fork_stats.is_empty() {
+                    div { class: "space-y-1",
+                        span { class: "text-[11px] font-semibold uppercase tracking-wide text-slate-500", "路由预估 vs 实际（按 Fork）" }
+                        table { class: "w-full text-left text-[11px] text-slate-600",
+                            thead {
+                                tr {
+                                    th { class: "py-1", "Fork" }
+                                    th { class: "py-1", "周期数" }
+                                    th { class: "py-1", "平均预估" }
+                                    th { class: "py-1", "平均实际" }
+                                    th { class: "py-1", "偏差" }
+                                }
+                            }
+                            tbody {
+                                for entry in fork_stats.iter() {
+                                    {
+                                        let avg_estimate = entry.estimated / entry.cycles as f64;
+                                        let avg_actual = entry.actual / entry.cycles as f64;
+                                        let drift = if avg_estimate > 0.0 {
+                                            format!("{:+.0}%", (avg_actual - avg_estimate) / avg_estimate * 100.0)
+                                        } else {
+                                            "-".to_string()
+                                        };
+                                        rsx! {
+                                            tr { class: "border-t border-slate-100",
+                                                td { class: "py-1", "{fork_display_name(&entry.fork)}" }
+                                                td { class: "py-1 font-mono", "{entry.cycles}" }
+                                                td { class: "py-1 font-mono", "{format_metric_value(metric, avg_estimate)}" }
+                                                td { class: "py-1 font-mono", "{format_metric_value(metric, avg_actual)}" }
+                                                td { class: "py-1 font-mono", "{drift}" }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            if !alerts.is_empty() {
+                div { class: "space-y-1",
+                    div { class: "flex items-center justify-between",
+                        span { class: "text-[11px] font-semibold uppercase tracking-wide text-slate-500", "告警记录" }
+                        button {
+                            class: "rounded border border-slate-300 px-2 py-0.5 text-[11px] text-slate-600 hover:bg-slate-100",
+                            onclick: move |_| actions_clear.clear_budget_alerts(),
+                            "清除告警"
+                        }
+                    }
+                    ul { class: "space-y-1 text-[11px]",
+                        for alert in alerts.iter() {
+                            li {
+                                class: match alert.kind {
+                                    BudgetAlertKind::Overspend(_) => "rounded bg-red-50 px-2 py-1 text-red-700",
+                                    BudgetAlertKind::EstimateDrift(_) => "rounded bg-amber-50 px-2 py-1 text-amber-800",
+                                },
+                                "{alert.message}"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
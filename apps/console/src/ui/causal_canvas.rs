@@ -0,0 +1,516 @@
+use crate::models::{CausalGraphNode, CausalGraphView};
+use crate::state::AppActions;
+use dioxus::prelude::*;
+use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
+
+const NODE_W: f64 = 150.0;
+const NODE_H: f64 = 52.0;
+const H_GAP: f64 = 210.0;
+const V_GAP: f64 = 80.0;
+const PAD: f64 = 24.0;
+const MIN_ZOOM: f64 = 0.2;
+const MAX_ZOOM: f64 = 4.0;
+/// 拖拽超过该距离（像素）后不再视为点击
+const DRAG_CLICK_TOLERANCE: f64 = 3.0;
+
+const TYPE_FILLS: &[&str] = &[
+    "#e0f2fe", "#dcfce7", "#ede9fe", "#fef3c7", "#ffe4e6", "#ccfbf1", "#f1f5f9", "#fae8ff",
+];
+const SCENARIO_STROKES: &[&str] = &[
+    "#0284c7", "#16a34a", "#7c3aed", "#d97706", "#e11d48", "#0d9488",
+];
+const DEFAULT_STROKE: &str = "#94a3b8";
+
+#[derive(Clone)]
+struct PlacedNode {
+    node: CausalGraphNode,
+    layer: i32,
+    x: f64,
+    y: f64,
+}
+
+struct PlacedEdge {
+    path: String,
+    arrow: String,
+    label: Option<String>,
+    label_x: f64,
+    label_y: f64,
+}
+
+struct GraphLayout {
+    nodes: Vec<PlacedNode>,
+    edges: Vec<PlacedEdge>,
+    width: f64,
+    height: f64,
+}
+
+/// 名称到调色板下标的稳定映射，保证同一类型在不同次渲染中颜色一致
+fn palette_index(name: &str, len: usize) -> usize {
+    name.bytes().fold(0usize, |acc, byte| {
+        acc.wrapping_mul(31).wrapping_add(byte as usize)
+    }) % len
+}
+
+fn type_name(node: &CausalGraphNode) -> Option<String> {
+    node.event_type.as_ref().map(|kind| format!("{kind:?}"))
+}
+
+fn scenario_name(node: &CausalGraphNode) -> Option<String> {
+    node.scenario
+        .as_ref()
+        .map(|scenario| format!("{scenario:?}"))
+}
+
+fn node_fill(node: &CausalGraphNode) -> &'static str {
+    type_name(node)
+        .map(|name| TYPE_FILLS[palette_index(&name, TYPE_FILLS.len())])
+        .unwrap_or("#f8fafc")
+}
+
+fn node_stroke(node: &CausalGraphNode) -> &'static str {
+    scenario_name(node)
+        .map(|name| SCENARIO_STROKES[palette_index(&name, SCENARIO_STROKES.len())])
+        .unwrap_or(DEFAULT_STROKE)
+}
+
+fn node_opacity(node: &CausalGraphNode) -> f64 {
+    node.score
+        .map(|score| 0.4 + 0.6 * (score as f64).clamp(0.0, 1.0))
+        .unwrap_or(1.0)
+}
+
+/// 以 root 为第 0 层做 BFS：沿边正向 +1、反向 -1，得到上游在左、下游在右的分层
+fn assign_layers(graph: &CausalGraphView, ids: &BTreeSet<u64>) -> HashMap<u64, i32> {
+    let mut neighbors: BTreeMap<u64, Vec<(u64, i32)>> = BTreeMap::new();
+    for edge in graph.edges.iter() {
+        neighbors.entry(edge.from).or_default().push((edge.to, 1));
+        neighbors.entry(edge.to).or_default().push((edge.from, -1));
+    }
+    for list in neighbors.values_mut() {
+        list.sort();
+    }
+
+    let mut layers = HashMap::new();
+    let mut queue = VecDeque::new();
+    if ids.contains(&graph.root_event_id) {
+        layers.insert(graph.root_event_id, 0);
+        queue.push_back(graph.root_event_id);
+    }
+    while let Some(current) = queue.pop_front() {
+        let base = layers[&current];
+        for (next, step) in neighbors.get(&current).into_iter().flatten() {
+            if !layers.contains_key(next) {
+                layers.insert(*next, base + step);
+                queue.push_back(*next);
+            }
+        }
+    }
+
+    // 与 root 不连通的节点退回服务端给出的 depth
+    let depth_of: HashMap<u64, i32> = graph
+        .nodes
+        .iter()
+        .map(|node| (node.event_id, node.depth.unwrap_or(0)))
+        .collect();
+    for id in ids.iter() {
+        layers
+            .entry(*id)
+            .or_insert_with(|| depth_of.get(id).copied().unwrap_or(0));
+    }
+    layers
+}
+
+fn compute_layout(graph: &CausalGraphView) -> GraphLayout {
+    let mut nodes_by_id: BTreeMap<u64, CausalGraphNode> = graph
+        .nodes
+        .iter()
+        .map(|node| (node.event_id, node.clone()))
+        .collect();
+    // 边引用了未返回的节点时补占位，避免边悬空
+    let referenced: Vec<u64> = graph
+        .edges
+        .iter()
+        .flat_map(|edge| [edge.from, edge.to])
+        .chain(std::iter::once(graph.root_event_id))
+        .collect();
+    for id in referenced {
+        nodes_by_id.entry(id).or_insert_with(|| CausalGraphNode {
+            event_id: id,
+            event_type: None,
+            scenario: None,
+            label: None,
+            summary: None,
+            timestamp_ms: None,
+            depth: None,
+            score: None,
+        });
+    }
+
+    let ids: BTreeSet<u64> = nodes_by_id.keys().copied().collect();
+    let layers = assign_layers(graph, &ids);
+
+    let mut by_layer: BTreeMap<i32, Vec<u64>> = BTreeMap::new();
+    for id in ids.iter() {
+        by_layer.entry(layers[id]).or_default().push(*id);
+    }
+
+    // 由近及远逐层排序：按已放置邻居的平均序号（重心法）排列，减少交叉
+    let mut order: HashMap<u64, f64> = HashMap::new();
+    let mut layer_keys: Vec<i32> = by_layer.keys().copied().collect();
+    layer_keys.sort_by_key(|layer| (layer.abs(), *layer));
+    for layer in layer_keys {
+        let members = by_layer.get_mut(&layer).expect("layer exists");
+        let mut keyed: Vec<(f64, u64)> = members
+            .iter()
+            .map(|id| {
+                let placed: Vec<f64> = graph
+                    .edges
+                    .iter()
+                    .filter_map(|edge| {
+                        if edge.from == *id {
+                            order.get(&edge.to).copied()
+                        } else if edge.to == *id {
+                            order.get(&edge.from).copied()
+                        } else {
+                            None
+                        }
+                    })
+                    .collect();
+                let barycenter = if placed.is_empty() {
+                    f64::MAX
+                } else {
+                    placed.iter().sum::<f64>() / placed.len() as f64
+                };
+                (barycenter, *id)
+            })
+            .collect();
+        keyed.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)));
+        *members = keyed.into_iter().map(|(_, id)| id).collect();
+        for (idx, id) in members.iter().enumerate() {
+            order.insert(*id, idx as f64);
+        }
+    }
+
+    let min_layer = by_layer.keys().next().copied().unwrap_or(0);
+    let max_layer = by_layer.keys().last().copied().unwrap_or(0);
+    let max_count = by_layer.values().map(|ids| ids.len()).max().unwrap_or(1);
+    let column_height = max_count as f64 * V_GAP;
+
+    let mut placed = Vec::new();
+    let mut position: HashMap<u64, (f64, f64)> = HashMap::new();
+    for (layer, members) in by_layer.iter() {
+        let offset = (column_height - members.len() as f64 * V_GAP) / 2.0;
+        for (idx, id) in members.iter().enumerate() {
+            let x = PAD + (layer - min_layer) as f64 * H_GAP;
+            let y = PAD + offset + idx as f64 * V_GAP;
+            position.insert(*id, (x, y));
+            placed.push(PlacedNode {
+                node: nodes_by_id[id].clone(),
+                layer: *layer,
+                x,
+                y,
+            });
+        }
+    }
+
+    let edges = graph
+        .edges
+        .iter()
+        .filter_map(|edge| {
+            let (sx, sy) = *position.get(&edge.from)?;
+            let (tx, ty) = *position.get(&edge.to)?;
+            let (start, end) = if tx > sx {
+                ((sx + NODE_W, sy + NODE_H / 2.0), (tx, ty + NODE_H / 2.0))
+            } else if tx < sx {
+                ((sx, sy + NODE_H / 2.0), (tx + NODE_W, ty + NODE_H / 2.0))
+            } else if ty > sy {
+                ((sx + NODE_W / 2.0, sy + NODE_H), (tx + NODE_W / 2.0, ty))
+            } else {
+                ((sx + NODE_W / 2.0, sy), (tx + NODE_W / 2.0, ty + NODE_H))
+            };
+            Some(build_edge(start, end, edge.relation.clone()))
+        })
+        .collect();
+
+    GraphLayout {
+        nodes: placed,
+        edges,
+        width: PAD * 2.0 + (max_layer - min_layer) as f64 * H_GAP + NODE_W,
+        height: PAD * 2.0 + column_height,
+    }
+}
+
+fn build_edge(start: (f64, f64), end: (f64, f64), relation: Option<String>) -> PlacedEdge {
+    let (dx, dy) = (end.0 - start.0, end.1 - start.1);
+    let len = (dx * dx + dy * dy).sqrt().max(1.0);
+    let (ux, uy) = (dx / len, dy / len);
+    let (px, py) = (-uy, ux);
+    let base = (end.0 - ux * 8.0, end.1 - uy * 8.0);
+    let arrow = format!(
+        "{:.1},{:.1} {:.1},{:.1} {:.1},{:.1}",
+        end.0,
+        end.1,
+        base.0 + px * 4.0,
+        base.1 + py * 4.0,
+        base.0 - px * 4.0,
+        base.1 - py * 4.0
+    );
+    PlacedEdge {
+        path: format!(
+            "M {:.1} {:.1} L {:.1} {:.1}",
+            start.0, start.1, base.0, base.1
+        ),
+        arrow,
+        label: relation,
+        label_x: (start.0 + end.0) / 2.0,
+        label_y: (start.1 + end.1) / 2.0 - 4.0,
+    }
+}
+
+fn node_title(node: &CausalGraphNode) -> String {
+    node.label
+        .clone()
+        .or_else(|| type_name(node))
+        .unwrap_or_else(|| "事件".into())
+}
+
+fn clip(text: &str, limit: usize) -> String {
+    if text.chars().count() > limit {
+        format!("{}…", text.chars().take(limit).collect::<String>())
+    } else {
+        text.to_string()
+    }
+}
+
+#[derive(Props, Clone)]
+#[props(no_eq)]
+pub struct CausalGraphCanvasProps {
+    graph: CausalGraphView,
+    actions: AppActions,
+}
+
+impl PartialEq for CausalGraphCanvasProps {
+    fn eq(&self, _other: &Self) -> bool {
+        false
+    }
+}
+
+impl Eq for CausalGraphCanvasProps {}
+
+#[component]
+pub fn CausalGraphCanvas(props: CausalGraphCanvasProps) -> Element {
+    let mut zoom = use_signal(|| 1.0_f64);
+    let mut pan = use_signal(|| (0.0_f64, 0.0_f64));
+    let mut drag_from = use_signal(|| Option::<(f64, f64)>::None);
+    let mut drag_moved = use_signal(|| false);
+    let mut hovered = use_signal(|| Option::<u64>::None);
+
+    let layout = compute_layout(&props.graph);
+    let root_id = props.graph.root_event_id;
+    let (pan_x, pan_y) = *pan.read();
+    let zoom_value = *zoom.read();
+    let transform = format!("translate({pan_x:.1} {pan_y:.1}) scale({zoom_value:.3})");
+    let zoom_pct = (zoom_value * 100.0).round() as i64;
+
+    let detail_id = hovered.read().unwrap_or(root_id);
+    let detail = layout
+        .nodes
+        .iter()
+        .find(|placed| placed.node.event_id == detail_id)
+        .cloned();
+
+    let type_legend: BTreeSet<String> = layout
+        .nodes
+        .iter()
+        .filter_map(|placed| type_name(&placed.node))
+        .collect();
+    let scenario_legend: BTreeSet<String> = layout
+        .nodes
+        .iter()
+        .filter_map(|placed| scenario_name(&placed.node))
+        .collect();
+
+    let mut zoom_by = move |factor: f64| {
+        let next = (*zoom.read() * factor).clamp(MIN_ZOOM, MAX_ZOOM);
+        zoom.set(next);
+    };
+
+    rsx! {
+        div { class: "space-y-2",
+            div { class: "flex flex-wrap items-center justify-between gap-2 text-[11px] text-slate-600",
+                h3 { class: "text-sm font-semibold text-slate-800", "因果链 root #{root_id}" }
+                div { class: "flex items-center gap-1",
+                    button {
+                        class: "rounded border border-slate-300 px-2 py-0.5 hover:bg-slate-100",
+                        onclick: move |_| zoom_by(1.0 / 1.2),
+                        "−"
+                    }
+                    span { class: "w-12 text-center font-mono", "{zoom_pct}%" }
+                    button {
+                        class: "rounded border border-slate-300 px-2 py-0.5 hover:bg-slate-100",
+                        onclick: move |_| zoom_by(1.2),
+                        "+"
+                    }
+                    button {
+                        class: "rounded border border-slate-300 px-2 py-0.5 hover:bg-slate-100",
+                        onclick: move |_| {
+                            zoom.set(1.0);
+                            pan.set((0.0, 0.0));
+                        },
+                        "重置视图"
+                    }
+                }
+            }
+
+            svg {
+                class: "w-full cursor-grab select-none rounded border border-slate-200 bg-slate-50",
+                height: "420",
+                view_box: "0 0 {layout.width:.0} {layout.height:.0}",
+                onmousedown: move |evt| {
+                    let point = evt.client_coordinates();
+                    drag_from.set(Some((point.x, point.y)));
+                    drag_moved.set(false);
+                },
+                onmousemove: move |evt| {
+                    let Some((last_x, last_y)) = *drag_from.read() else {
+                        return;
+                    };
+                    let point = evt.client_coordinates();
+                    let (dx, dy) = (point.x - last_x, point.y - last_y);
+                    if dx.abs() + dy.abs() > DRAG_CLICK_TOLERANCE {
+                        drag_moved.set(true);
+                    }
+                    let (px, py) = *pan.read();
+                    pan.set((px + dx, py + dy));
+                    drag_from.set(Some((point.x, point.y)));
+                },
+                onmouseup: move |_| drag_from.set(None),
+                onmouseleave: move |_| drag_from.set(None),
+                onwheel: move |evt| {
+                    evt.prevent_default();
+                    let delta = evt.delta().strip_units().y;
+                    zoom_by(if delta < 0.0 { 1.1 } else { 1.0 / 1.1 });
+                },
+                g { transform: "{transform}",
+                    for edge in layout.edges.iter() {
+                        g {
+                            path { d: "{edge.path}", fill: "none", stroke: "#94a3b8", stroke_width: "1.5" }
+                            polygon { points: "{edge.arrow}", fill: "#94a3b8" }
+                            if let Some(label) = edge.label.as_ref() {
+                                text {
+                                    x: "{edge.label_x:.1}",
+                                    y: "{edge.label_y:.1}",
+                                    font_size: "10",
+                                    fill: "#64748b",
+                                    text_anchor: "middle",
+                                    "{label}"
+                                }
+                            }
+                        }
+                    }
+                    for placed in layout.nodes.iter() {
+                        {
+                            let id = placed.node.event_id;
+                            let is_root = id == root_id;
+                            let is_hovered = *hovered.read() == Some(id);
+                            let stroke_width = if is_root { "3" } else if is_hovered { "2" } else { "1" };
+                            let title = clip(&node_title(&placed.node), 18);
+                            let score = placed.node.score.map(|score| format!("{score:.2}")).unwrap_or_else(|| "--".into());
+                            let opacity = format!("{:.2}", node_opacity(&placed.node));
+                            let (x, y) = (format!("{:.1}", placed.x), format!("{:.1}", placed.y));
+                            let text_x = format!("{:.1}", placed.x + 8.0);
+                            let id_y = format!("{:.1}", placed.y + 20.0);
+                            let title_y = format!("{:.1}", placed.y + 38.0);
+                            let actions = props.actions.clone();
+                            rsx! {
+                                g {
+                                    class: "cursor-pointer",
+                                    opacity: "{opacity}",
+                                    onmouseenter: move |_| hovered.set(Some(id)),
+                                    onmouseleave: move |_| hovered.set(None),
+                                    onclick: move |_| {
+                                        if !*drag_moved.read() && id != root_id {
+                                            actions.set_graph_root(Some(id));
+                                        }
+                                    },
+                                    rect {
+                                        x: "{x}",
+                                        y: "{y}",
+                                        width: "{NODE_W}",
+                                        height: "{NODE_H}",
+                                        rx: "6",
+                                        fill: node_fill(&placed.node),
+                                        stroke: node_stroke(&placed.node),
+                                        stroke_width,
+                                    }
+                                    text {
+                                        x: "{text_x}",
+                                        y: "{id_y}",
+                                        font_size: "11",
+                                        font_weight: "600",
+                                        fill: "#0f172a",
+                                        "#{id}"
+                                    }
+                                    text {
+                                        x: "{text_x}",
+                                        y: "{title_y}",
+                                        font_size: "10",
+                                        fill: "#475569",
+                                        "{title} · {score}"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            div { class: "flex flex-wrap gap-3 text-[10px] text-slate-500",
+                for name in type_legend.iter() {
+                    span { class: "flex items-center gap-1",
+                        span {
+                            class: "inline-block h-2 w-3 rounded-sm border border-slate-300",
+                            style: format!("background: {}", TYPE_FILLS[palette_index(name, TYPE_FILLS.len())]),
+                        }
+                        "{name}"
+                    }
+                }
+                for name in scenario_legend.iter() {
+                    span { class: "flex items-center gap-1",
+                        span {
+                            class: "inline-block h-2 w-3 rounded-sm border-2",
+                            style: format!("border-color: {}", SCENARIO_STROKES[palette_index(name, SCENARIO_STROKES.len())]),
+                        }
+                        "{name}"
+                    }
+                }
+                span { "透明度 = score · 粗边框 = root · 点击节点切换 root · 拖拽平移 · 滚轮缩放" }
+            }
+
+            if let Some(placed) = detail {
+                div { class: "rounded border border-slate-200 bg-white p-3 text-xs text-slate-600 space-y-1",
+                    div { class: "flex flex-wrap items-center justify-between gap-2",
+                        span { class: "font-semibold text-slate-800", "事件 #{placed.node.event_id}" }
+                        span { class: "text-[11px] text-slate-500", "层级 {placed.layer}" }
+                    }
+                    if let Some(label) = placed.node.label.as_ref() {
+                        p { class: "text-[11px] text-slate-500", "{label}" }
+                    }
+                    if let Some(summary) = placed.node.summary.as_ref() {
+                        p { "{summary}" }
+                    }
+                    div { class: "flex flex-wrap gap-2 text-[11px] text-slate-500",
+                        span { {format!("类型: {}", type_name(&placed.node).unwrap_or_else(|| "-".into()))} }
+                        span { {format!("场景: {}", scenario_name(&placed.node).unwrap_or_else(|| "-".into()))} }
+                        if let Some(score) = placed.node.score {
+                            span { "Score: {score:.2}" }
+                        }
+                        if let Some(ts) = placed.node.timestamp_ms {
+                            span { "时间戳: {ts}" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
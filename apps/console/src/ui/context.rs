@@ -1,10 +1,13 @@
-use crate::hooks::context::use_context_bundle;
+use crate::hooks::context::{compact_context, use_context_bundle};
 use crate::models::{
-    AceExplainSection, BundleSegment, ContextBundleView, DfrExplainSection, ExplainIndices,
-    ExplainSection, ManifestDigestRecord,
+    AceExplainSection, BundleSegment, ContextBundleView, ContextCompactRequest, DfrExplainSection,
+    ExplainIndices, ExplainSection, ManifestDigestRecord,
 };
-use crate::state::use_app_state;
+use crate::state::{use_app_actions, use_app_state};
+use crate::ui::manifest_diff::ManifestDiffPanel;
+use crate::ui::token_map::{context_item_dom_id, TokenUsageMap};
 use dioxus::prelude::*;
+use std::collections::BTreeSet;
 
 #[component]
 pub fn ContextPanel() -> Element {
@@ -34,12 +37,15 @@ pub fn ContextPanel() -> Element {
                     {explain_card}
                 }
                 {render_segments(bundle)}
+                TokenUsageMap { bundle: bundle.clone() }
+                CompactionPanel { bundle: bundle.clone() }
                 if let Some(ref explain_indices) = context_state.explain_indices {
                     {render_explain_indices(explain_indices)}
                 }
                 if let Some(history_view) = render_manifest_history(&history, active_digest.as_deref()) {
                     {history_view}
                 }
+                ManifestDiffPanel { history: history.clone() }
             }
         }
     } else {
@@ -195,7 +201,7 @@ fn render_segment(segment: &BundleSegment) -> Element {
             } else {
                 ul { class: "mt-2 space-y-1 text-xs text-slate-600",
                     for item in segment.items.iter() {
-                        li {
+                        li { id: context_item_dom_id(&segment.partition, &item.ci_id),
                             span { class: "font-medium", "{item.ci_id}" }
                             span { class: "ml-2", "Tokens: {item.tokens}" }
                             if let Some(level) = item.summary_level.as_ref() {
@@ -209,6 +215,153 @@ fn render_segment(segment: &BundleSegment) -> Element {
     }
 }
 
+#[derive(Props, Clone)]
+#[props(no_eq)]
+struct CompactionPanelProps {
+    bundle: ContextBundleView,
+}
+
+impl PartialEq for CompactionPanelProps {
+    fn eq(&self, _other: &Self) -> bool {
+        false
+    }
+}
+
+impl Eq for CompactionPanelProps {}
+
+#[component]
+fn CompactionPanel(props: CompactionPanelProps) -> Element {
+    let actions = use_app_actions();
+    let app_state = use_app_state();
+    let mut target_input = use_signal(String::new);
+    let mut selected = use_signal(BTreeSet::<String>::new);
+    let mut pending = use_signal(|| false);
+
+    let bundle = &props.bundle;
+    let partition_tokens: Vec<(String, u32)> = bundle
+        .segments
+        .iter()
+        .map(|segment| {
+            (
+                segment.partition.clone(),
+                segment.items.iter().map(|item| item.tokens).sum(),
+            )
+        })
+        .collect();
+    let item_total: u32 = partition_tokens.iter().map(|(_, tokens)| *tokens).sum();
+    let projected = bundle
+        .budget
+        .as_ref()
+        .map(|budget| budget.projected_tokens)
+        .unwrap_or(item_total);
+    let default_target = bundle
+        .budget
+        .as_ref()
+        .map(|budget| budget.target_tokens)
+        .unwrap_or(projected);
+
+    let target_text = target_input.read().clone();
+    let target = if target_text.trim().is_empty() {
+        Some(default_target)
+    } else {
+        target_text.trim().parse::<u32>().ok()
+    };
+    let chosen = selected.read().clone();
+    // 未勾选分区时视为压缩全部分区
+    let scope_tokens: u32 = partition_tokens
+        .iter()
+        .filter(|(name, _)| chosen.is_empty() || chosen.contains(name))
+        .map(|(_, tokens)| *tokens)
+        .sum();
+    let savings = target.map(|target| projected.saturating_sub(target).min(scope_tokens));
+    let is_pending = *pending.read();
+    let can_submit = !is_pending && savings.is_some_and(|saved| saved > 0);
+
+    let preview = match (target, savings) {
+        (None, _) => "目标 Token 需为非负整数".to_string(),
+        (Some(target), _) if target >= projected => {
+            format!("预计使用 {projected} 已不超过目标 {target}，无需压缩")
+        }
+        (Some(_), Some(saved)) => format!(
+            "预计使用 {projected} → {}，节省约 {saved} tokens（可压缩范围 {scope_tokens}）",
+            projected - saved
+        ),
+        _ => String::new(),
+    };
+
+    let submit = move |_| {
+        let Some(target_tokens) = target else {
+            return;
+        };
+        let request = ContextCompactRequest {
+            target_tokens,
+            partitions: selected.read().iter().cloned().collect(),
+            manifest_digest: app_state.read().context.active_manifest_digest.clone(),
+        };
+        pending.set(true);
+        let actions = actions.clone();
+        spawn(async move {
+            compact_context(actions, app_state, request).await;
+            pending.set(false);
+        });
+    };
+
+    rsx! {
+        div { class: "rounded-lg border border-slate-200 bg-white p-4 shadow-sm space-y-3 text-xs text-slate-600",
+            header { class: "flex flex-wrap items-center justify-between gap-2",
+                h3 { class: "text-sm font-semibold text-slate-800", "上下文压缩" }
+                span { class: "text-[11px] text-slate-500", "完成后新 Manifest 会写入历史，可在版本对比中查看前后差异" }
+            }
+            div { class: "flex flex-wrap items-center gap-2",
+                label { class: "text-[11px] text-slate-500", "目标 Token" }
+                input {
+                    class: "w-28 rounded border border-slate-300 px-2 py-1 text-[11px] font-mono",
+                    r#type: "number",
+                    min: "0",
+                    placeholder: "{default_target}",
+                    value: "{target_text}",
+                    oninput: move |evt| target_input.set(evt.value()),
+                }
+            }
+            if partition_tokens.is_empty() {
+                p { class: "text-[11px] text-slate-500 italic", "当前 Bundle 没有分区" }
+            } else {
+                div { class: "flex flex-wrap gap-2",
+                    for (name, tokens) in partition_tokens.iter() {
+                        {
+                            let name = name.clone();
+                            let checked = chosen.contains(&name);
+                            rsx! {
+                                label { class: "flex items-center gap-1 rounded border border-slate-200 bg-slate-50 px-2 py-1 text-[11px]",
+                                    input {
+                                        r#type: "checkbox",
+                                        checked,
+                                        onchange: move |_| {
+                                            let mut set = selected.write();
+                                            if !set.remove(&name) {
+                                                set.insert(name.clone());
+                                            }
+                                        },
+                                    }
+                                    span { class: "font-mono", "{name}" }
+                                    span { class: "text-slate-500", "{tokens}" }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            p { class: "text-[11px] text-slate-700", "{preview}" }
+            button {
+                class: "rounded bg-slate-900 px-3 py-1 text-[11px] font-semibold text-white hover:bg-slate-800 disabled:opacity-50",
+                disabled: !can_submit,
+                onclick: submit,
+                if is_pending { "压缩中…" } else { "提交压缩" }
+            }
+        }
+    }
+}
+
 fn render_manifest_explain(bundle: &ContextBundleView) -> Option<Element> {
     let explain = &bundle.explain;
     let has_indices = !explain.indices_used.is_empty();
@@ -348,7 +501,7 @@ fn render_manifest_history_item(
     }
 }
 
-fn format_degradation_label(value: &str) -> String {
+pub(crate) fn format_degradation_label(value: &str) -> String {
     value
         .split(|ch| ch == '_' || ch == '-')
         .filter(|segment| !segment.is_empty())
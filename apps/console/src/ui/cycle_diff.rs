@@ -0,0 +1,362 @@
+use crate::models::{AceCycleSummary, CycleSnapshotView, OutboxMessageView};
+use dioxus::prelude::*;
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+use std::collections::HashMap;
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum JsonDiffKind {
+    Added,
+    Removed,
+    Changed,
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct JsonDiffEntry {
+    pub path: String,
+    pub kind: JsonDiffKind,
+    pub left: Option<Value>,
+    pub right: Option<Value>,
+}
+
+/// 结构化比较两个 JSON 值：对象按 key 合并，数组按下标对齐，叶子节点直接比较
+pub(crate) fn diff_json(left: &Value, right: &Value) -> Vec<JsonDiffEntry> {
+    let mut entries = Vec::new();
+    diff_json_at("$", left, right, &mut entries);
+    entries
+}
+
+fn diff_json_at(path: &str, left: &Value, right: &Value, out: &mut Vec<JsonDiffEntry>) {
+    match (left, right) {
+        (Value::Object(l), Value::Object(r)) => {
+            let mut keys: Vec<&String> = l.keys().chain(r.keys()).collect();
+            keys.sort();
+            keys.dedup();
+            for key in keys {
+                let child = format!("{path}.{key}");
+                match (l.get(key), r.get(key)) {
+                    (Some(lv), Some(rv)) => diff_json_at(&child, lv, rv, out),
+                    (Some(lv), None) => out.push(JsonDiffEntry {
+                        path: child,
+                        kind: JsonDiffKind::Removed,
+                        left: Some(lv.clone()),
+                        right: None,
+                    }),
+                    (None, Some(rv)) => out.push(JsonDiffEntry {
+                        path: child,
+                        kind: JsonDiffKind::Added,
+                        left: None,
+                        right: Some(rv.clone()),
+                    }),
+                    (None, None) => {}
+                }
+            }
+        }
+        (Value::Array(l), Value::Array(r)) => {
+            for idx in 0..l.len().max(r.len()) {
+                let child = format!("{path}[{idx}]");
+                match (l.get(idx), r.get(idx)) {
+                    (Some(lv), Some(rv)) => diff_json_at(&child, lv, rv, out),
+                    (Some(lv), None) => out.push(JsonDiffEntry {
+                        path: child,
+                        kind: JsonDiffKind::Removed,
+                        left: Some(lv.clone()),
+                        right: None,
+                    }),
+                    (None, Some(rv)) => out.push(JsonDiffEntry {
+                        path: child,
+                        kind: JsonDiffKind::Added,
+                        left: None,
+                        right: Some(rv.clone()),
+                    }),
+                    (None, None) => {}
+                }
+            }
+        }
+        _ if left == right => {}
+        (Value::Null, _) => out.push(JsonDiffEntry {
+            path: path.to_string(),
+            kind: JsonDiffKind::Added,
+            left: None,
+            right: Some(right.clone()),
+        }),
+        (_, Value::Null) => out.push(JsonDiffEntry {
+            path: path.to_string(),
+            kind: JsonDiffKind::Removed,
+            left: Some(left.clone()),
+            right: None,
+        }),
+        _ => out.push(JsonDiffEntry {
+            path: path.to_string(),
+            kind: JsonDiffKind::Changed,
+            left: Some(left.clone()),
+            right: Some(right.clone()),
+        }),
+    }
+}
+
+pub(crate) fn render_json_diff_entries(entries: &[JsonDiffEntry]) -> Element {
+    if entries.is_empty() {
+        return rsx! {
+            p { class: "text-[11px] text-slate-500 italic", "无差异" }
+        };
+    }
+
+    rsx! {
+        ul { class: "space-y-1 text-[11px] font-mono",
+            for entry in entries.iter() {
+                {
+                    let (marker, row_class) = match entry.kind {
+                        JsonDiffKind::Added => ("+", "bg-emerald-50 text-emerald-700"),
+                        JsonDiffKind::Removed => ("-", "bg-red-50 text-red-700"),
+                        JsonDiffKind::Changed => ("~", "bg-amber-50 text-amber-800"),
+                    };
+                    let left = entry.left.as_ref().map(compact_json).unwrap_or_default();
+                    let right = entry.right.as_ref().map(compact_json).unwrap_or_default();
+                    let detail = match entry.kind {
+                        JsonDiffKind::Added => right,
+                        JsonDiffKind::Removed => left,
+                        JsonDiffKind::Changed => format!("{left} → {right}"),
+                    };
+                    rsx! {
+                        li { class: format!("rounded px-2 py-1 break-all {row_class}"),
+                            span { class: "font-semibold", "{marker} {entry.path}" }
+                            p { "{detail}" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn compact_json(value: &Value) -> String {
+    const LIMIT: usize = 240;
+    let text = match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    };
+    if text.chars().count() > LIMIT {
+        let truncated: String = text.chars().take(LIMIT).collect();
+        format!("{truncated}…")
+    } else {
+        text
+    }
+}
+
+fn to_value_or_null<T: Serialize>(value: &T) -> Value {
+    serde_json::to_value(value).unwrap_or(Value::Null)
+}
+
+/// 将列表转换为按标识字段索引的对象，避免插入一条记录导致后续下标全部错位。
+/// 同一标识重复出现时附加出现序号（`id@1`、`id@2`…），重复项不会互相覆盖
+fn index_by_key(items: Value, key: &str) -> Value {
+    let Value::Array(items) = items else {
+        return items;
+    };
+    let mut map = Map::new();
+    let mut occurrences: HashMap<String, usize> = HashMap::new();
+    for (idx, item) in items.into_iter().enumerate() {
+        let id = item
+            .get(key)
+            .map(|id| match id {
+                Value::String(s) => s.clone(),
+                other => other.to_string(),
+            })
+            .unwrap_or_else(|| format!("#{idx}"));
+        let seen = occurrences.entry(id.clone()).or_insert(0);
+        let entry_key = if *seen == 0 {
+            id
+        } else {
+            format!("{id}@{seen}")
+        };
+        *seen += 1;
+        map.insert(entry_key, item);
+    }
+    Value::Object(map)
+}
+
+fn router_section_value(snapshot: &CycleSnapshotView) -> Value {
+    match snapshot.schedule.router_decision.as_ref() {
+        Some(decision) => json!({
+            "fork": to_value_or_null(&decision.plan.fork),
+            "decision_plan": to_value_or_null(&decision.plan.decision_plan),
+            "priority": decision.plan.priority,
+            "rejected": to_value_or_null(&decision.rejected),
+            "indices_used": decision.plan.explain.indices_used,
+            "degradation_reason": decision.plan.explain.degradation_reason,
+            "decision_path": decision.decision_path,
+        }),
+        None => Value::Null,
+    }
+}
+
+fn outbox_section_value(
+    snapshot: &CycleSnapshotView,
+    outbox: Option<&Vec<OutboxMessageView>>,
+) -> Value {
+    let rows = outbox.unwrap_or(&snapshot.outbox);
+    index_by_key(to_value_or_null(rows), "event_id")
+}
+
+struct DiffSection {
+    title: &'static str,
+    entries: Vec<JsonDiffEntry>,
+}
+
+fn build_diff_sections(
+    left: &CycleSnapshotView,
+    right: &CycleSnapshotView,
+    left_outbox: Option<&Vec<OutboxMessageView>>,
+    right_outbox: Option<&Vec<OutboxMessageView>>,
+) -> Vec<DiffSection> {
+    vec![
+        DiffSection {
+            title: "路由决策",
+            entries: diff_json(&router_section_value(left), &router_section_value(right)),
+        },
+        DiffSection {
+            title: "预算快照",
+            entries: diff_json(
+                &to_value_or_null(&left.sync_point.budget),
+                &to_value_or_null(&right.sync_point.budget),
+            ),
+        },
+        DiffSection {
+            title: "同步点事件",
+            entries: diff_json(&left.sync_point.events, &right.sync_point.events),
+        },
+        DiffSection {
+            title: "待处理注入",
+            entries: diff_json(
+                &index_by_key(
+                    to_value_or_null(&left.sync_point.pending_injections),
+                    "injection_id",
+                ),
+                &index_by_key(
+                    to_value_or_null(&right.sync_point.pending_injections),
+                    "injection_id",
+                ),
+            ),
+        },
+        DiffSection {
+            title: "上下文 Manifest",
+            entries: diff_json(
+                &left.sync_point.context_manifest,
+                &right.sync_point.context_manifest,
+            ),
+        },
+        DiffSection {
+            title: "Outbox",
+            entries: diff_json(
+                &outbox_section_value(left, left_outbox),
+                &outbox_section_value(right, right_outbox),
+            ),
+        },
+    ]
+}
+
+#[derive(Props, Clone)]
+#[props(no_eq)]
+pub struct CycleDiffPanelProps {
+    cycles: Vec<AceCycleSummary>,
+    snapshots: HashMap<String, CycleSnapshotView>,
+    outboxes: HashMap<String, Vec<OutboxMessageView>>,
+    selected_cycle_id: Option<String>,
+}
+
+impl PartialEq for CycleDiffPanelProps {
+    fn eq(&self, _other: &Self) -> bool {
+        false
+    }
+}
+
+impl Eq for CycleDiffPanelProps {}
+
+#[component]
+pub fn CycleDiffPanel(props: CycleDiffPanelProps) -> Element {
+    let mut left_choice = use_signal(|| Option::<String>::None);
+    let mut right_choice = use_signal(|| Option::<String>::None);
+
+    // 仅能比较已缓存快照的周期，按周期列表顺序展示
+    let mut cached_ids: Vec<String> = props
+        .cycles
+        .iter()
+        .map(|cycle| cycle.cycle_id.clone())
+        .filter(|id| props.snapshots.contains_key(id))
+        .collect();
+    for id in props.snapshots.keys() {
+        if !cached_ids.contains(id) {
+            cached_ids.push(id.clone());
+        }
+    }
+
+    if cached_ids.len() < 2 {
+        return rsx! {
+            div { class: "rounded-lg border border-slate-200 bg-white p-4 shadow-sm space-y-1",
+                h3 { class: "text-sm font-semibold text-slate-800", "周期快照对比" }
+                p { class: "text-xs text-slate-500 italic", "至少需要加载两个周期的快照才能对比" }
+            }
+        };
+    }
+
+    let valid = |choice: Option<String>| choice.filter(|id| props.snapshots.contains_key(id));
+    let left_id = valid(left_choice.read().clone())
+        .or_else(|| valid(props.selected_cycle_id.clone()))
+        .unwrap_or_else(|| cached_ids[0].clone());
+    let right_id = valid(right_choice.read().clone())
+        .filter(|id| id != &left_id)
+        .or_else(|| cached_ids.iter().find(|id| **id != left_id).cloned())
+        .unwrap_or_else(|| cached_ids[1].clone());
+
+    let sections = match (
+        props.snapshots.get(&left_id),
+        props.snapshots.get(&right_id),
+    ) {
+        (Some(left), Some(right)) => build_diff_sections(
+            left,
+            right,
+            props.outboxes.get(&left_id),
+            props.outboxes.get(&right_id),
+        ),
+        _ => Vec::new(),
+    };
+    let total_changes: usize = sections.iter().map(|section| section.entries.len()).sum();
+
+    rsx! {
+        div { class: "rounded-lg border border-slate-200 bg-white p-4 shadow-sm space-y-3",
+            header { class: "flex flex-wrap items-center justify-between gap-2",
+                h3 { class: "text-sm font-semibold text-slate-800", "周期快照对比" }
+                div { class: "flex flex-wrap items-center gap-2 text-[11px] text-slate-600",
+                    select {
+                        class: "rounded border border-slate-300 px-2 py-1 text-[11px] text-slate-600",
+                        value: "{left_id}",
+                        onchange: move |evt| left_choice.set(Some(evt.value())),
+                        for id in cached_ids.iter() {
+                            option { value: "{id}", "#{id}" }
+                        }
+                    }
+                    span { "→" }
+                    select {
+                        class: "rounded border border-slate-300 px-2 py-1 text-[11px] text-slate-600",
+                        value: "{right_id}",
+                        onchange: move |evt| right_choice.set(Some(evt.value())),
+                        for id in cached_ids.iter() {
+                            option { value: "{id}", "#{id}" }
+                        }
+                    }
+                    span { class: "rounded bg-slate-100 px-2 py-0.5 text-slate-700", "{total_changes} 处差异" }
+                }
+            }
+            for section in sections.iter() {
+                div { class: "rounded border border-slate-100 bg-slate-50 p-3 space-y-2",
+                    div { class: "flex items-center justify-between",
+                        h4 { class: "text-xs font-semibold text-slate-800", "{section.title}" }
+                        span { class: "text-[11px] text-slate-500", "{section.entries.len()} 项" }
+                    }
+                    { render_json_diff_entries(&section.entries) }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,376 @@
+use crate::hooks::ace::load_cycle_ancestor;
+use crate::models::{AceCycleStatus, AceCycleSummary, AceLane, CycleSnapshotView};
+use crate::state::{use_app_state, AppActions, AppSignal};
+use dioxus::prelude::*;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+/// 谱系树最大展开深度；成环的周期不从根节点展开，另列于损坏分组
+const MAX_LINEAGE_DEPTH: usize = 16;
+const UNSCOPED_KEY: &str = "";
+
+#[derive(Clone, Debug)]
+struct LineageNode {
+    cycle_id: String,
+    parent_cycle_id: Option<String>,
+    collab_scope_id: Option<String>,
+    lane: String,
+    status: String,
+    budget: Option<String>,
+    has_snapshot: bool,
+}
+
+struct LineageForest {
+    nodes: HashMap<String, LineageNode>,
+    children: HashMap<String, Vec<String>>,
+    /// collab_scope_id → 根节点（包括尚未加载的缺失祖先）
+    scopes: BTreeMap<String, Vec<String>>,
+    /// 从任何根节点都走不到的周期：parent_cycle_id 链成环（及其后代），数据已损坏
+    orphans: Vec<String>,
+}
+
+fn lane_label(lane: &AceLane) -> &'static str {
+    match lane {
+        AceLane::Clarify => "Clarify",
+        AceLane::Tool => "Tool",
+        AceLane::SelfReason => "Self Reason",
+        AceLane::Collab => "Collab",
+    }
+}
+
+fn status_label(status: &AceCycleStatus) -> String {
+    format!("{status:?}")
+}
+
+fn build_lineage_node(
+    cycle_id: &str,
+    summary: Option<&AceCycleSummary>,
+    snapshot: Option<&CycleSnapshotView>,
+) -> LineageNode {
+    let parent_cycle_id = snapshot.and_then(|snap| {
+        snap.schedule
+            .parent_cycle_id
+            .clone()
+            .or_else(|| snap.sync_point.parent_cycle_id.clone())
+    });
+    let collab_scope_id = snapshot.and_then(|snap| {
+        snap.schedule
+            .collab_scope_id
+            .clone()
+            .or_else(|| snap.sync_point.collab_scope_id.clone())
+    });
+    let lane = summary
+        .map(|cycle| lane_label(&cycle.lane).to_string())
+        .or_else(|| snapshot.map(|snap| snap.schedule.lane.clone()))
+        .unwrap_or_else(|| "-".into());
+    let status = snapshot
+        .and_then(|snap| snap.outcomes.last().map(|outcome| outcome.status.clone()))
+        .or_else(|| summary.map(|cycle| status_label(&cycle.status)))
+        .or_else(|| snapshot.map(|snap| snap.schedule.status.clone()))
+        .unwrap_or_else(|| "-".into());
+    let budget = snapshot
+        .map(|snap| {
+            let budget = &snap.schedule.budget;
+            format!("{} / {} tokens", budget.tokens_spent, budget.tokens_allowed)
+        })
+        .or_else(|| {
+            summary
+                .and_then(|cycle| cycle.budget.as_ref())
+                .map(|budget| {
+                    format!(
+                        "{} / {} tokens",
+                        budget.tokens_spent.unwrap_or(0),
+                        budget.tokens_allowed.unwrap_or(0)
+                    )
+                })
+        });
+
+    LineageNode {
+        cycle_id: cycle_id.to_string(),
+        parent_cycle_id,
+        collab_scope_id,
+        lane,
+        status,
+        budget,
+        has_snapshot: snapshot.is_some(),
+    }
+}
+
+fn build_lineage_forest(
+    cycles: &[AceCycleSummary],
+    snapshots: &HashMap<String, CycleSnapshotView>,
+) -> LineageForest {
+    let mut ids: Vec<String> = cycles.iter().map(|cycle| cycle.cycle_id.clone()).collect();
+    for id in snapshots.keys() {
+        if !ids.contains(id) {
+            ids.push(id.clone());
+        }
+    }
+
+    let mut nodes: HashMap<String, LineageNode> = ids
+        .iter()
+        .map(|id| {
+            let summary = cycles.iter().find(|cycle| &cycle.cycle_id == id);
+            (
+                id.clone(),
+                build_lineage_node(id, summary, snapshots.get(id)),
+            )
+        })
+        .collect();
+
+    // 父周期尚未缓存时补一个占位节点，便于按需拉取
+    let missing: BTreeSet<String> = nodes
+        .values()
+        .filter_map(|node| node.parent_cycle_id.clone())
+        .filter(|parent| !nodes.contains_key(parent))
+        .collect();
+    for parent in missing {
+        nodes.insert(parent.clone(), build_lineage_node(&parent, None, None));
+        ids.push(parent);
+    }
+
+    let mut children: HashMap<String, Vec<String>> = HashMap::new();
+    for id in ids.iter() {
+        if let Some(parent) = nodes[id].parent_cycle_id.clone() {
+            if &parent != id {
+                children.entry(parent).or_default().push(id.clone());
+            }
+        }
+    }
+
+    let mut scopes: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for id in ids.iter() {
+        let node = &nodes[id];
+        let is_root = match node.parent_cycle_id.as_ref() {
+            Some(parent) => parent == id,
+            None => true,
+        };
+        if !is_root {
+            continue;
+        }
+        let scope = resolve_scope(id, &nodes, &children).unwrap_or_default();
+        scopes.entry(scope).or_default().push(id.clone());
+    }
+
+    let mut visited: BTreeSet<&str> = BTreeSet::new();
+    let mut queue: Vec<&str> = scopes.values().flatten().map(String::as_str).collect();
+    while let Some(id) = queue.pop() {
+        if !visited.insert(id) {
+            continue;
+        }
+        if let Some(kids) = children.get(id) {
+            queue.extend(kids.iter().map(String::as_str));
+        }
+    }
+    let mut orphans: Vec<String> = ids
+        .iter()
+        .filter(|id| !visited.contains(id.as_str()))
+        .cloned()
+        .collect();
+    orphans.sort();
+
+    LineageForest {
+        nodes,
+        children,
+        scopes,
+        orphans,
+    }
+}
+
+/// 根节点自身没有 collab_scope_id 时（例如缺失祖先占位），沿用首个带作用域的后代
+fn resolve_scope(
+    root: &str,
+    nodes: &HashMap<String, LineageNode>,
+    children: &HashMap<String, Vec<String>>,
+) -> Option<String> {
+    let mut queue = vec![(root.to_string(), 0usize)];
+    while let Some((id, depth)) = queue.pop() {
+        if let Some(scope) = nodes.get(&id).and_then(|node| node.collab_scope_id.clone()) {
+            return Some(scope);
+        }
+        if depth >= MAX_LINEAGE_DEPTH {
+            continue;
+        }
+        if let Some(kids) = children.get(&id) {
+            queue.extend(kids.iter().rev().map(|kid| (kid.clone(), depth + 1)));
+        }
+    }
+    None
+}
+
+#[derive(Props, Clone)]
+#[props(no_eq)]
+pub struct CycleLineagePanelProps {
+    cycles: Vec<AceCycleSummary>,
+    snapshots: HashMap<String, CycleSnapshotView>,
+    selected_cycle_id: Option<String>,
+    lineage_pending: BTreeSet<String>,
+    lineage_errors: HashMap<String, String>,
+    actions: AppActions,
+}
+
+impl PartialEq for CycleLineagePanelProps {
+    fn eq(&self, _other: &Self) -> bool {
+        false
+    }
+}
+
+impl Eq for CycleLineagePanelProps {}
+
+#[component]
+pub fn CycleLineagePanel(props: CycleLineagePanelProps) -> Element {
+    let app_state = use_app_state();
+    let forest = build_lineage_forest(&props.cycles, &props.snapshots);
+    let ctx = LineageRenderContext {
+        forest: &forest,
+        selected_cycle_id: props.selected_cycle_id.as_deref(),
+        pending: &props.lineage_pending,
+        errors: &props.lineage_errors,
+        actions: &props.actions,
+        app_state,
+    };
+
+    rsx! {
+        div { class: "rounded-lg border border-slate-200 bg-white p-4 shadow-sm space-y-3",
+            header { class: "flex flex-col gap-1",
+                h3 { class: "text-sm font-semibold text-slate-800", "周期谱系" }
+                p { class: "text-[11px] text-slate-500", "按协作作用域分组，展示 parent_cycle_id 派生的子周期；父周期依据已加载的快照推断" }
+            }
+            if forest.scopes.is_empty() && forest.orphans.is_empty() {
+                p { class: "text-xs text-slate-500 italic", "暂无周期" }
+            }
+            for (scope, roots) in forest.scopes.iter() {
+                div { class: "rounded border border-slate-100 bg-slate-50 p-3 space-y-2",
+                    h4 { class: "text-xs font-semibold text-slate-800",
+                        if scope.as_str() == UNSCOPED_KEY {
+                            "未指定协作作用域"
+                        } else {
+                            "协作作用域 {scope}"
+                        }
+                    }
+                    ul { class: "space-y-1",
+                        for root in roots.iter() {
+                            { render_lineage_node(&ctx, root, 0) }
+                        }
+                    }
+                }
+            }
+            if !forest.orphans.is_empty() {
+                div { class: "rounded border border-red-200 bg-red-50 p-3 space-y-2",
+                    h4 { class: "text-xs font-semibold text-red-700", "环形谱系 / 孤立周期" }
+                    p { class: "text-[11px] text-red-600",
+                        "以下周期的 parent_cycle_id 链成环，无法归入任何根节点，谱系数据可能已损坏"
+                    }
+                    ul { class: "space-y-1",
+                        for cycle_id in forest.orphans.iter() {
+                            { render_orphan_node(&ctx, cycle_id) }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+struct LineageRenderContext<'a> {
+    forest: &'a LineageForest,
+    selected_cycle_id: Option<&'a str>,
+    pending: &'a BTreeSet<String>,
+    errors: &'a HashMap<String, String>,
+    actions: &'a AppActions,
+    app_state: AppSignal,
+}
+
+fn render_lineage_node(ctx: &LineageRenderContext<'_>, cycle_id: &str, depth: usize) -> Element {
+    let Some(node) = ctx.forest.nodes.get(cycle_id) else {
+        return rsx! {};
+    };
+    let children: Vec<String> = if depth < MAX_LINEAGE_DEPTH {
+        ctx.forest
+            .children
+            .get(cycle_id)
+            .cloned()
+            .unwrap_or_default()
+    } else {
+        Vec::new()
+    };
+    let truncated = depth >= MAX_LINEAGE_DEPTH && ctx.forest.children.contains_key(cycle_id);
+
+    rsx! {
+        li { class: "space-y-1",
+            { render_lineage_row(ctx, node) }
+            if !children.is_empty() {
+                ul { class: "ml-4 space-y-1 border-l border-slate-200 pl-3",
+                    for child in children.iter() {
+                        { render_lineage_node(ctx, child, depth + 1) }
+                    }
+                }
+            }
+            if truncated {
+                p { class: "ml-4 text-[10px] text-slate-500 italic", "层级过深，已截断" }
+            }
+        }
+    }
+}
+
+/// 成环的周期不展开子节点，只平铺列出并标明其父周期
+fn render_orphan_node(ctx: &LineageRenderContext<'_>, cycle_id: &str) -> Element {
+    let Some(node) = ctx.forest.nodes.get(cycle_id) else {
+        return rsx! {};
+    };
+
+    rsx! {
+        li { class: "space-y-0.5",
+            { render_lineage_row(ctx, node) }
+            if let Some(parent) = node.parent_cycle_id.as_ref() {
+                p { class: "ml-2 text-[10px] text-red-600", "父周期 #{parent}" }
+            }
+        }
+    }
+}
+
+fn render_lineage_row(ctx: &LineageRenderContext<'_>, node: &LineageNode) -> Element {
+    let cycle_id = node.cycle_id.as_str();
+    let is_selected = ctx.selected_cycle_id == Some(cycle_id);
+    let is_pending = ctx.pending.contains(cycle_id);
+    let error = ctx.errors.get(cycle_id).cloned();
+
+    let row_class = if is_selected {
+        "flex flex-wrap items-center gap-2 rounded border border-indigo-300 bg-indigo-50 px-2 py-1 text-[11px]"
+    } else {
+        "flex flex-wrap items-center gap-2 rounded border border-slate-200 bg-white px-2 py-1 text-[11px]"
+    };
+
+    let select_actions = ctx.actions.clone();
+    let select_id = node.cycle_id.clone();
+    let fetch_actions = ctx.actions.clone();
+    let fetch_id = node.cycle_id.clone();
+    let app_state = ctx.app_state;
+
+    rsx! {
+        div { class: row_class,
+            button {
+                class: "font-mono text-slate-800 hover:underline",
+                onclick: move |_| select_actions.select_ace_cycle(Some(select_id.clone())),
+                "#{node.cycle_id}"
+            }
+            span { class: "rounded bg-slate-100 px-2 py-0.5 text-slate-700", "{node.lane}" }
+            span { class: "rounded bg-emerald-100 px-2 py-0.5 text-emerald-700", "{node.status}" }
+            if let Some(budget) = node.budget.clone() {
+                span { class: "rounded bg-violet-100 px-2 py-0.5 font-mono text-violet-700", "{budget}" }
+            }
+            if !node.has_snapshot {
+                button {
+                    class: "rounded border border-slate-300 px-2 py-0.5 text-slate-600 hover:bg-slate-100 disabled:opacity-50",
+                    disabled: is_pending,
+                    onclick: move |_| {
+                        spawn(load_cycle_ancestor(fetch_actions.clone(), app_state, fetch_id.clone()));
+                    },
+                    if is_pending { "加载中…" } else { "加载快照" }
+                }
+            }
+            if let Some(err) = error {
+                span { class: "text-red-600", "{err}" }
+            }
+        }
+    }
+}
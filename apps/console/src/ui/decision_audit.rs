@@ -0,0 +1,471 @@
+use crate::hooks::metacognition::use_decision_audit;
+use crate::models::{
+    AlternativeAudit, ConfidenceFactor, DecisionAuditResponse, DecisionOutcomeAudit, RiskAssessment,
+};
+use crate::state::use_app_actions;
+use crate::ui::ace::{copy_text_to_clipboard, export_text_as_file};
+use crate::ui::severity::Severity;
+use dioxus::prelude::*;
+
+/// 概率分桶的上界（不含），最后一档兜底
+const PROBABILITY_BUCKETS: &[(f32, &str)] =
+    &[(0.33, "低概率"), (0.66, "中概率"), (f32::MAX, "高概率")];
+const RECENT_LIMIT: usize = 8;
+
+fn probability_bucket(probability: f32) -> usize {
+    PROBABILITY_BUCKETS
+        .iter()
+        .position(|(upper, _)| probability < *upper)
+        .unwrap_or(PROBABILITY_BUCKETS.len() - 1)
+}
+
+/// 严重度越高、概率越大，格子颜色越深；未知严重度不参与配色
+fn matrix_cell_class(severity: Severity, bucket: usize) -> &'static str {
+    if severity == Severity::Unknown {
+        return "bg-slate-100";
+    }
+    let level = (Severity::KNOWN.len() - 1 - severity.rank()) + bucket;
+    match level {
+        0 | 1 => "bg-emerald-50",
+        2 => "bg-amber-50",
+        3 => "bg-orange-100",
+        _ => "bg-red-100",
+    }
+}
+
+fn markdown_escape(text: &str) -> String {
+    text.replace('|', "\\|").replace('\n', " ")
+}
+
+/// 生成合规审阅用的 Markdown 审计报告
+pub fn audit_to_markdown(audit: &DecisionAuditResponse) -> String {
+    let mut out = format!("# 决策审计报告 {}\n\n", audit.decision_id);
+    out.push_str(&format!("- 决策路径: `{}`\n", audit.decision_path));
+    if let Some(outcome) = audit.outcome.as_ref() {
+        out.push_str(&format!(
+            "- 实际路径: `{}`（{}）\n",
+            outcome.actual_path,
+            if outcome.was_successful {
+                "成功"
+            } else {
+                "失败"
+            }
+        ));
+    }
+
+    out.push_str("\n## 决策理由\n\n");
+    out.push_str(&format!("{}\n", audit.rationale.primary_reason));
+    if !audit.rationale.supporting_evidence.is_empty() {
+        out.push_str("\n支持证据:\n\n");
+        for evidence in audit.rationale.supporting_evidence.iter() {
+            out.push_str(&format!("- {evidence}\n"));
+        }
+    }
+    if !audit.rationale.context_factors.is_empty() {
+        out.push_str("\n上下文因素:\n\n");
+        for factor in audit.rationale.context_factors.iter() {
+            out.push_str(&format!("- {factor}\n"));
+        }
+    }
+
+    out.push_str("\n## 备选路径\n\n");
+    if audit.alternatives_considered.is_empty() {
+        out.push_str("无\n");
+    } else {
+        out.push_str("| 路径 | 评分 | 否决原因 |\n| --- | --- | --- |\n");
+        for alt in sorted_alternatives(&audit.alternatives_considered) {
+            out.push_str(&format!(
+                "| {} | {:.2} | {} |\n",
+                markdown_escape(&alt.path),
+                alt.score,
+                markdown_escape(&alt.rejection_reason)
+            ));
+        }
+    }
+
+    out.push_str("\n## 置信度因素\n\n");
+    if audit.confidence_factors.is_empty() {
+        out.push_str("无\n");
+    } else {
+        out.push_str("| 因素 | 取值 | 权重 | 贡献 |\n| --- | --- | --- | --- |\n");
+        for factor in audit.confidence_factors.iter() {
+            out.push_str(&format!(
+                "| {} | {:.2} | {:.2} | {:.3} |\n",
+                markdown_escape(&factor.factor_name),
+                factor.value,
+                factor.weight,
+                factor.contribution
+            ));
+        }
+    }
+
+    out.push_str("\n## 风险评估\n\n");
+    if audit.risks.is_empty() {
+        out.push_str("无\n");
+    } else {
+        out.push_str(
+            "| 类型 | 严重度 | 概率 | 描述 | 缓解措施 |\n| --- | --- | --- | --- | --- |\n",
+        );
+        for risk in audit.risks.iter() {
+            out.push_str(&format!(
+                "| {} | {} | {:.0}% | {} | {} |\n",
+                markdown_escape(&risk.risk_type),
+                markdown_escape(&risk.severity),
+                risk.probability * 100.0,
+                markdown_escape(&risk.description),
+                markdown_escape(risk.mitigation.as_deref().unwrap_or("-"))
+            ));
+        }
+    }
+
+    if let Some(feedback) = audit
+        .outcome
+        .as_ref()
+        .and_then(|outcome| outcome.user_feedback.as_ref())
+    {
+        out.push_str(&format!(
+            "\n## 用户反馈\n\n> {}\n",
+            feedback.replace('\n', "\n> ")
+        ));
+    }
+    out
+}
+
+fn sorted_alternatives(alternatives: &[AlternativeAudit]) -> Vec<&AlternativeAudit> {
+    let mut sorted: Vec<&AlternativeAudit> = alternatives.iter().collect();
+    sorted.sort_by(|a, b| b.score.total_cmp(&a.score));
+    sorted
+}
+
+/// 决策审计查看器
+#[component]
+pub fn DecisionAuditViewer() -> Element {
+    let actions = use_app_actions();
+    let mut decision_input = use_signal(String::new);
+    let mut selected = use_signal(|| Option::<String>::None);
+    let mut recent = use_signal(Vec::<String>::new);
+
+    let audit_state = use_decision_audit(selected.read().clone());
+
+    let mut open_decision = move |decision_id: String| {
+        decision_input.set(decision_id.clone());
+        let mut history = recent.write();
+        history.retain(|id| *id != decision_id);
+        history.insert(0, decision_id.clone());
+        history.truncate(RECENT_LIMIT);
+        drop(history);
+        selected.set(Some(decision_id));
+    };
+
+    let recent_ids = recent.read().clone();
+    let body = {
+        let state = audit_state.read();
+        if selected.read().is_none() {
+            rsx! { p { class: "text-xs text-slate-500 italic", "输入决策 ID 以查看审计记录" } }
+        } else if state.loading {
+            rsx! { p { class: "text-xs text-slate-500", "正在加载决策审计..." } }
+        } else if let Some(ref err) = state.error {
+            rsx! { p { class: "text-xs text-red-500", "{err}" } }
+        } else if let Some(ref audit) = state.audit {
+            let markdown = audit_to_markdown(audit);
+            let json = serde_json::to_string_pretty(audit).unwrap_or_default();
+            let target = format!("metacognition:decision_audit:{}", audit.decision_id);
+            let md_filename = format!("decision_audit_{}.md", audit.decision_id);
+            let json_filename = format!("decision_audit_{}.json", audit.decision_id);
+            let copy_markdown = markdown.clone();
+            let copy_actions = actions.clone();
+            let md_actions = actions.clone();
+            let json_actions = actions.clone();
+            let md_target = target.clone();
+            let json_target = target.clone();
+            rsx! {
+                div { class: "space-y-4",
+                    div { class: "flex flex-wrap gap-2 text-[11px]",
+                        button {
+                            class: "rounded border border-slate-300 px-2 py-1 text-slate-700 hover:bg-slate-100",
+                            onclick: move |_| export_text_as_file(md_actions.clone(), "决策审计 Markdown", &md_target, md_filename.clone(), markdown.clone()),
+                            "导出 Markdown"
+                        }
+                        button {
+                            class: "rounded border border-slate-300 px-2 py-1 text-slate-700 hover:bg-slate-100",
+                            onclick: move |_| export_text_as_file(json_actions.clone(), "决策审计 JSON", &json_target, json_filename.clone(), json.clone()),
+                            "导出 JSON"
+                        }
+                        button {
+                            class: "rounded border border-slate-300 px-2 py-1 text-slate-700 hover:bg-slate-100",
+                            onclick: move |_| copy_text_to_clipboard(copy_actions.clone(), "决策审计 Markdown", &target, copy_markdown.clone()),
+                            "复制 Markdown"
+                        }
+                    }
+                    {render_paths(audit)}
+                    {render_confidence_factors(&audit.confidence_factors)}
+                    {render_risk_matrix(&audit.risks)}
+                    if let Some(outcome) = audit.outcome.as_ref() {
+                        {render_outcome(outcome, &audit.decision_path)}
+                    }
+                }
+            }
+        } else {
+            rsx! { p { class: "text-xs text-slate-500 italic", "该决策暂无审计记录" } }
+        }
+    };
+
+    rsx! {
+        div { class: "rounded-lg border border-slate-200 bg-white p-4 shadow-sm space-y-3",
+            h3 { class: "text-sm font-semibold text-slate-800", "决策审计" }
+            form {
+                class: "flex flex-wrap items-end gap-2 text-xs",
+                onsubmit: move |evt: FormEvent| {
+                    evt.prevent_default();
+                    let value = decision_input.read().trim().to_string();
+                    if !value.is_empty() {
+                        open_decision(value);
+                    }
+                },
+                div { class: "flex flex-col gap-1",
+                    label { class: "text-[11px] font-semibold text-slate-600", "决策 ID" }
+                    input {
+                        class: "w-56 rounded border border-slate-300 px-2 py-1 font-mono",
+                        value: "{decision_input}",
+                        oninput: move |evt| decision_input.set(evt.value()),
+                        placeholder: "decision_id",
+                    }
+                }
+                button {
+                    class: "rounded bg-slate-900 px-3 py-1.5 font-semibold text-white hover:bg-slate-800",
+                    r#type: "submit",
+                    "查看审计"
+                }
+            }
+            if !recent_ids.is_empty() {
+                div { class: "flex flex-wrap items-center gap-1 text-[11px]",
+                    span { class: "text-slate-500", "最近查看:" }
+                    for decision_id in recent_ids.iter() {
+                        {
+                            let target = decision_id.clone();
+                            rsx! {
+                                button {
+                                    class: "rounded bg-slate-100 px-2 py-0.5 font-mono text-slate-600 hover:bg-slate-200",
+                                    onclick: move |_| open_decision(target.clone()),
+                                    "{decision_id}"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            {body}
+        }
+    }
+}
+
+fn render_paths(audit: &DecisionAuditResponse) -> Element {
+    let alternatives = sorted_alternatives(&audit.alternatives_considered);
+    let max_score = alternatives
+        .iter()
+        .map(|alt| alt.score)
+        .fold(0.0_f32, f32::max)
+        .max(f32::EPSILON);
+
+    rsx! {
+        div { class: "space-y-2 text-xs text-slate-600",
+            div { class: "rounded border border-blue-200 bg-blue-50 p-3 space-y-1",
+                div { class: "flex items-center gap-2",
+                    span { class: "rounded bg-blue-600 px-2 py-0.5 text-[11px] text-white", "选定路径" }
+                    span { class: "font-mono font-semibold text-slate-800", "{audit.decision_path}" }
+                }
+                p { class: "text-slate-700", "{audit.rationale.primary_reason}" }
+                if !audit.rationale.supporting_evidence.is_empty() {
+                    ul { class: "list-disc pl-4 text-[11px] text-slate-600",
+                        for evidence in audit.rationale.supporting_evidence.iter() {
+                            li { "{evidence}" }
+                        }
+                    }
+                }
+                if !audit.rationale.context_factors.is_empty() {
+                    div { class: "flex flex-wrap gap-1",
+                        for factor in audit.rationale.context_factors.iter() {
+                            span { class: "rounded bg-white px-2 py-0.5 text-[11px] text-blue-700", "{factor}" }
+                        }
+                    }
+                }
+            }
+            if alternatives.is_empty() {
+                p { class: "text-[11px] italic text-slate-400", "未记录备选路径" }
+            } else {
+                div { class: "space-y-1",
+                    span { class: "font-semibold text-slate-700", "备选路径" }
+                    for alt in alternatives.iter() {
+                        {
+                            let width = (alt.score / max_score * 100.0).clamp(0.0, 100.0);
+                            rsx! {
+                                div { class: "rounded border border-slate-100 bg-slate-50 p-2 space-y-1",
+                                    div { class: "flex items-center gap-2",
+                                        span { class: "w-40 truncate font-mono text-slate-700", title: "{alt.path}", "{alt.path}" }
+                                        div { class: "h-2 flex-1 overflow-hidden rounded bg-slate-200",
+                                            div { class: "h-2 bg-slate-500", style: "width: {width:.1}%" }
+                                        }
+                                        span { class: "w-12 text-right font-mono", {format!("{:.2}", alt.score)} }
+                                    }
+                                    p { class: "text-[11px] text-red-600", "否决: {alt.rejection_reason}" }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn render_confidence_factors(factors: &[ConfidenceFactor]) -> Element {
+    if factors.is_empty() {
+        return rsx! {};
+    }
+
+    let total: f32 = factors.iter().map(|factor| factor.contribution).sum();
+    let max_abs = factors
+        .iter()
+        .map(|factor| factor.contribution.abs())
+        .fold(0.0_f32, f32::max)
+        .max(f32::EPSILON);
+    let mut sorted: Vec<&ConfidenceFactor> = factors.iter().collect();
+    sorted.sort_by(|a, b| b.contribution.abs().total_cmp(&a.contribution.abs()));
+
+    rsx! {
+        div { class: "space-y-1 text-xs text-slate-600",
+            div { class: "flex items-center justify-between",
+                span { class: "font-semibold text-slate-700", "置信度贡献" }
+                span { class: "font-mono", {format!("合计 {total:.3}")} }
+            }
+            for factor in sorted.iter() {
+                {
+                    // 以中线为零点，正贡献向右、负贡献向左
+                    let width = factor.contribution.abs() / max_abs * 50.0;
+                    let (left, color) = if factor.contribution >= 0.0 {
+                        (50.0, "bg-emerald-500")
+                    } else {
+                        (50.0 - width, "bg-red-500")
+                    };
+                    rsx! {
+                        div { class: "flex items-center gap-2",
+                            span { class: "w-32 truncate", title: "{factor.factor_name}", "{factor.factor_name}" }
+                            div { class: "relative h-3 flex-1 rounded bg-slate-100",
+                                div { class: "absolute top-0 h-3 border-l border-slate-400", style: "left: 50%" }
+                                div {
+                                    class: "absolute top-0 h-3 rounded {color}",
+                                    style: "left: {left:.1}%; width: {width:.1}%",
+                                }
+                            }
+                            span { class: "w-40 text-right font-mono text-[11px]",
+                                {format!("{:+.3} (值 {:.2} × 权重 {:.2})", factor.contribution, factor.value, factor.weight)}
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn render_risk_matrix(risks: &[RiskAssessment]) -> Element {
+    if risks.is_empty() {
+        return rsx! { p { class: "text-[11px] italic text-slate-400", "未记录风险" } };
+    }
+
+    // 无法识别的严重度单独归入末尾的“未知”行，而不是混入低风险
+    let mut cells: Vec<Vec<Vec<&RiskAssessment>>> =
+        vec![vec![Vec::new(); PROBABILITY_BUCKETS.len()]; Severity::KNOWN.len() + 1];
+    for risk in risks.iter() {
+        let severity = Severity::parse(&risk.severity);
+        cells[severity.rank()][probability_bucket(risk.probability)].push(risk);
+    }
+    let mut rows: Vec<Severity> = Severity::KNOWN.to_vec();
+    if cells[Severity::Unknown.rank()]
+        .iter()
+        .any(|cell| !cell.is_empty())
+    {
+        rows.push(Severity::Unknown);
+    }
+
+    rsx! {
+        div { class: "space-y-2 text-xs text-slate-600",
+            span { class: "font-semibold text-slate-700", "风险矩阵" }
+            table { class: "w-full table-fixed border-collapse text-[11px]",
+                thead {
+                    tr {
+                        th { class: "w-16 p-1 text-left text-slate-500", "严重度" }
+                        for (_, label) in PROBABILITY_BUCKETS.iter() {
+                            th { class: "p-1 text-slate-500", "{label}" }
+                        }
+                    }
+                }
+                tbody {
+                    for severity in rows.into_iter() {
+                        tr {
+                            td {
+                                class: "p-1 font-medium text-slate-600",
+                                title: if severity == Severity::Unknown { "后端返回了无法识别的严重度" } else { "" },
+                                {severity.label()}
+                            }
+                            for (bucket_idx, cell) in cells[severity.rank()].iter().enumerate() {
+                                {
+                                    let cell_class = matrix_cell_class(severity, bucket_idx);
+                                    rsx! {
+                                        td { class: "h-10 border border-white p-1 align-top {cell_class}",
+                                            for risk in cell.iter() {
+                                                span {
+                                                    class: "mr-1 inline-block rounded bg-white/70 px-1 text-slate-700",
+                                                    title: "{risk.description}",
+                                                    "{risk.risk_type}"
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            div { class: "space-y-1",
+                for risk in risks.iter() {
+                    div { class: "rounded border border-slate-100 p-2",
+                        p { class: "font-medium text-slate-700",
+                            {format!("{} · {} · {:.0}%", risk.risk_type, risk.severity, risk.probability * 100.0)}
+                        }
+                        p { "{risk.description}" }
+                        if let Some(mitigation) = risk.mitigation.as_ref() {
+                            p { class: "text-emerald-700", "缓解: {mitigation}" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn render_outcome(outcome: &DecisionOutcomeAudit, decision_path: &str) -> Element {
+    let (badge_class, badge_text) = if outcome.was_successful {
+        ("bg-green-100 text-green-700", "成功")
+    } else {
+        ("bg-red-100 text-red-700", "失败")
+    };
+    let diverged = outcome.actual_path != decision_path;
+
+    rsx! {
+        div { class: "rounded border border-slate-200 p-3 space-y-1 text-xs text-slate-600",
+            div { class: "flex items-center gap-2",
+                span { class: "font-semibold text-slate-700", "实际结果" }
+                span { class: "rounded px-2 py-0.5 text-[11px] {badge_class}", "{badge_text}" }
+            }
+            p { "实际路径: " span { class: "font-mono", "{outcome.actual_path}" } }
+            if diverged {
+                p { class: "text-amber-700", "实际执行路径与选定路径不一致" }
+            }
+            if let Some(feedback) = outcome.user_feedback.as_ref() {
+                blockquote { class: "border-l-2 border-slate-300 pl-2 italic text-slate-700", "{feedback}" }
+            }
+        }
+    }
+}
@@ -4,23 +4,34 @@
 
 use dioxus::prelude::*;
 
-use crate::hooks::dfr::use_fingerprint_list;
+use crate::hooks::dfr::{use_dfr_decision_list, use_fingerprint_list, DecisionLookup};
+use crate::models::FingerprintSummary;
+use crate::state::use_app_actions;
+use crate::ui::ace::format_timestamp_ms;
+use crate::ui::dfr_decision::{render_decision_links, DfrDecisionDetail};
 
 /// DFR 决策面板组件
 #[component]
 pub fn DfrPanel() -> Element {
     let fingerprints = use_fingerprint_list();
+    // 当前展开关联决策的指纹
+    let expanded = use_signal(|| None::<String>);
 
     let body = {
         let fp_data = fingerprints.read();
         if let Some(ref data) = *fp_data {
             rsx! {
                 div { class: "space-y-4",
-                    div { class: "rounded-lg border border-slate-200 bg-slate-50 p-4 shadow-sm",
+                    div { class: "rounded-lg border border-slate-200 bg-slate-50 p-4 shadow-sm space-y-2",
                         h3 { class: "text-sm font-semibold text-slate-800 mb-3", "决策指纹" }
                         p { class: "text-xs text-slate-600",
                             {format!("共 {} 个指纹记录", data.fingerprints.len())}
                         }
+                        div { class: "space-y-2 max-h-72 overflow-y-auto",
+                            for fingerprint in data.fingerprints.iter() {
+                                {render_fingerprint_row(fingerprint, expanded)}
+                            }
+                        }
                     }
                 }
             }
@@ -36,6 +47,65 @@ pub fn DfrPanel() -> Element {
                 p { class: "text-xs text-slate-500", "决策路径记录与场景复现分析" }
             }
             {body}
+            DfrDecisionDetail {}
+        }
+    }
+}
+
+/// 指纹行可展开，按指纹向后端检索使用过该指纹的决策
+fn render_fingerprint_row(
+    fingerprint: &FingerprintSummary,
+    mut expanded: Signal<Option<String>>,
+) -> Element {
+    let success_pct = fingerprint.success_rate * 100.0;
+    let last_used = format_timestamp_ms(fingerprint.last_used_at_ms);
+    let fingerprint_id = fingerprint.fingerprint_id.clone();
+    let is_expanded = expanded.read().as_deref() == Some(fingerprint_id.as_str());
+
+    rsx! {
+        div { class: "rounded border border-slate-200 bg-white p-2 text-xs space-y-1",
+            div { class: "flex flex-wrap items-center gap-2",
+                span { class: "font-mono font-semibold text-slate-800", "{fingerprint.decision_path}" }
+                span { class: "rounded bg-slate-100 px-1.5 py-0.5 text-slate-600", "{fingerprint.scenario}" }
+                span { class: "ml-auto font-mono text-slate-500", "使用 {fingerprint.usage_count} 次 · 成功率 {success_pct:.0}%" }
+            }
+            div { class: "flex flex-wrap items-center gap-2 text-[11px] text-slate-400",
+                span { class: "font-mono", title: "{fingerprint.hash}", "{fingerprint.fingerprint_id}" }
+                span { "最近使用 {last_used}" }
+                button {
+                    r#type: "button",
+                    class: "ml-auto text-blue-700 hover:underline",
+                    onclick: move |_| {
+                        let next = if is_expanded { None } else { Some(fingerprint_id.clone()) };
+                        expanded.set(next);
+                    },
+                    if is_expanded { "收起关联决策" } else { "查看关联决策" }
+                }
+            }
+            if is_expanded {
+                FingerprintDecisions {
+                    fingerprint_id: fingerprint.fingerprint_id.clone(),
+                    hash: fingerprint.hash.clone(),
+                }
+            }
+        }
+    }
+}
+
+/// 使用该指纹的决策列表
+#[component]
+fn FingerprintDecisions(fingerprint_id: String, hash: String) -> Element {
+    let actions = use_app_actions();
+    let decisions = use_dfr_decision_list(Some(DecisionLookup::Fingerprint {
+        fingerprint_id,
+        hash,
+    }));
+    let links = render_decision_links(&decisions.read(), &actions, "没有使用该指纹的决策");
+
+    rsx! {
+        div { class: "flex flex-wrap items-center gap-1 text-[11px]",
+            span { class: "text-slate-400", "关联决策:" }
+            {links}
         }
     }
 }
@@ -0,0 +1,510 @@
+//! DFR 决策详情
+//!
+//! 展示单个 DFR 决策的粘性决策、备选方案、决策理由、指纹特征与最终结果
+
+use dioxus::prelude::*;
+use serde_json::Value;
+
+use crate::hooks::dfr::{use_dfr_decision, DfrDecisionListState};
+use crate::models::{
+    AlternativeInfo, DecisionDetail, DecisionOutcome, DecisionRationaleInfo, FeatureInfo,
+    StickyDecisionInfo,
+};
+use crate::state::{use_app_actions, use_app_state, AppActions};
+use crate::ui::ace::format_timestamp_ms;
+use crate::ui::severity::Severity;
+use crate::ui::util::scroll_to_element;
+
+/// 详情区域的 DOM id，供 ACE 周期跳转后滚动定位
+pub const DFR_DECISION_DOM_ID: &str = "dfr-decision-detail";
+const FEATURE_VALUE_PREVIEW: usize = 80;
+
+fn feature_value_text(value: &Value) -> String {
+    let text = match value {
+        Value::String(text) => text.clone(),
+        other => other.to_string(),
+    };
+    if text.chars().count() > FEATURE_VALUE_PREVIEW {
+        let preview: String = text.chars().take(FEATURE_VALUE_PREVIEW).collect();
+        format!("{preview}…")
+    } else {
+        text
+    }
+}
+
+/// 打开 ACE 周期详情并滚动到周期面板
+fn open_cycle(actions: &AppActions, cycle_id: &str) {
+    actions.select_ace_cycle(Some(cycle_id.to_string()));
+    scroll_to_element(format!("ace-cycle-{cycle_id}"));
+}
+
+/// 打开 DFR 决策详情并滚动到详情区域
+pub(crate) fn open_decision(actions: &AppActions, decision_id: String) {
+    actions.open_dfr_decision(decision_id);
+    scroll_to_element(DFR_DECISION_DOM_ID.to_string());
+}
+
+/// 检索到的决策链接列表，决策 ID 以后端返回或按 ID 校验通过的结果为准
+pub(crate) fn render_decision_links(
+    list: &DfrDecisionListState,
+    actions: &AppActions,
+    empty_label: &str,
+) -> Element {
+    if list.loading {
+        return rsx! { span { class: "text-[11px] text-slate-400", "正在检索 DFR 决策..." } };
+    }
+    if let Some(err) = list.error.as_ref() {
+        return rsx! { span { class: "text-[11px] text-red-500", title: "{err}", "DFR 决策检索失败" } };
+    }
+    let fallback_hint = list
+        .from_fallback
+        .then_some("后端未提供决策检索接口，仅核对已加载的 ACE 周期");
+    if list.decisions.is_empty() {
+        return rsx! {
+            span { class: "text-[11px] text-slate-400", title: fallback_hint.unwrap_or_default(),
+                "{empty_label}"
+                if fallback_hint.is_some() { "（仅已加载周期）" }
+            }
+        };
+    }
+
+    rsx! {
+        div { class: "flex flex-wrap items-center gap-1 text-[11px]",
+            if let Some(hint) = fallback_hint {
+                span { class: "text-slate-400", title: hint, "（仅已加载周期）" }
+            }
+            for decision in list.decisions.iter() {
+                {
+                    let open_actions = actions.clone();
+                    let decision_id = decision.decision_id.clone();
+                    let label = if decision.path.is_empty() {
+                        decision.decision_id.clone()
+                    } else {
+                        format!("{} · {}", decision.path, decision.decision_id)
+                    };
+                    let title = format!(
+                        "周期 {} · {}",
+                        decision.cycle_id,
+                        format_timestamp_ms(decision.decided_at_ms)
+                    );
+                    rsx! {
+                        button {
+                            r#type: "button",
+                            class: "rounded bg-slate-100 px-1.5 py-0.5 font-mono text-blue-700 hover:bg-slate-200",
+                            title: "{title}",
+                            onclick: move |_| open_decision(&open_actions, decision_id.clone()),
+                            "{label}"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// 当前选中的 DFR 决策详情，决策由 `AppActions::open_dfr_decision` 选择
+#[component]
+pub fn DfrDecisionDetail() -> Element {
+    let state_store = use_app_state();
+    let actions = use_app_actions();
+    let mut decision_input = use_signal(String::new);
+
+    let snapshot = state_store.read();
+    let selected = snapshot.dfr.selected_decision.clone();
+    let recent = snapshot.dfr.recent_decisions.clone();
+    drop(snapshot);
+
+    let detail_state = use_dfr_decision(selected.clone());
+
+    let body = {
+        let state = detail_state.read();
+        if selected.is_none() {
+            rsx! { p { class: "text-xs text-slate-500 italic", "从指纹列表或 ACE 周期打开决策，或直接输入决策 ID" } }
+        } else if state.loading {
+            rsx! { p { class: "text-xs text-slate-500", "正在加载决策详情..." } }
+        } else if let Some(ref err) = state.error {
+            rsx! { p { class: "text-xs text-red-500", "{err}" } }
+        } else if let Some(ref decision) = state.decision {
+            render_decision(decision, &actions)
+        } else {
+            rsx! { p { class: "text-xs text-slate-500 italic", "未找到该决策" } }
+        }
+    };
+
+    let submit_actions = actions.clone();
+    let close_actions = actions.clone();
+
+    rsx! {
+        div { id: DFR_DECISION_DOM_ID, class: "rounded-lg border border-slate-200 bg-white p-4 shadow-sm space-y-3",
+            div { class: "flex items-center justify-between",
+                h3 { class: "text-sm font-semibold text-slate-800", "决策详情" }
+                if selected.is_some() {
+                    button {
+                        r#type: "button",
+                        class: "text-xs text-slate-500 hover:underline",
+                        onclick: move |_| close_actions.close_dfr_decision(),
+                        "关闭"
+                    }
+                }
+            }
+            form {
+                class: "flex flex-wrap items-center gap-2",
+                onsubmit: move |evt: FormEvent| {
+                    evt.prevent_default();
+                    let decision_id = decision_input.read().trim().to_string();
+                    if !decision_id.is_empty() {
+                        submit_actions.open_dfr_decision(decision_id);
+                        decision_input.set(String::new());
+                    }
+                },
+                input {
+                    class: "flex-1 rounded border border-slate-300 px-2 py-1 text-xs font-mono",
+                    placeholder: "决策 ID",
+                    value: "{decision_input}",
+                    oninput: move |evt| decision_input.set(evt.value()),
+                }
+                button {
+                    r#type: "submit",
+                    class: "rounded bg-slate-900 px-3 py-1 text-xs font-semibold text-white hover:bg-slate-800",
+                    "查看"
+                }
+            }
+            if !recent.is_empty() {
+                div { class: "flex flex-wrap items-center gap-1 text-[11px]",
+                    span { class: "text-slate-400", "最近查看:" }
+                    for decision_id in recent.iter() {
+                        {
+                            let is_selected = selected.as_deref() == Some(decision_id.as_str());
+                            let class = if is_selected {
+                                "rounded bg-blue-600 px-1.5 py-0.5 font-mono text-white"
+                            } else {
+                                "rounded bg-slate-100 px-1.5 py-0.5 font-mono text-slate-600 hover:bg-slate-200"
+                            };
+                            let open_actions = actions.clone();
+                            let open_id = decision_id.clone();
+                            rsx! {
+                                button {
+                                    r#type: "button",
+                                    class: "{class}",
+                                    onclick: move |_| open_actions.open_dfr_decision(open_id.clone()),
+                                    "{decision_id}"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            {body}
+        }
+    }
+}
+
+fn render_decision(decision: &DecisionDetail, actions: &AppActions) -> Element {
+    let decided_at = format_timestamp_ms(decision.decided_at_ms);
+    let confidence_pct = decision.rationale.overall_confidence * 100.0;
+    let cycle_actions = actions.clone();
+    let cycle_id = decision.cycle_id.clone();
+
+    rsx! {
+        div { class: "space-y-4 text-xs text-slate-700",
+            div { class: "grid gap-2 md:grid-cols-2",
+                div { class: "space-y-1",
+                    p { "决策 ID: " span { class: "font-mono", "{decision.decision_id}" } }
+                    p { class: "flex items-center gap-1",
+                        "ACE 周期: "
+                        button {
+                            r#type: "button",
+                            class: "font-mono text-blue-700 hover:underline",
+                            title: "在 ACE 面板中打开该周期",
+                            onclick: move |_| open_cycle(&cycle_actions, &cycle_id),
+                            "{decision.cycle_id}"
+                        }
+                    }
+                    p { "决策时间: {decided_at}" }
+                }
+                div { class: "space-y-1",
+                    p { "选定路径: " span { class: "font-mono font-semibold text-slate-900", "{decision.path}" } }
+                    p { "整体置信度: {confidence_pct:.0}%" }
+                }
+            }
+            if let Some(sticky) = decision.sticky.as_ref() {
+                {render_sticky(sticky)}
+            }
+            {render_alternatives(&decision.path, &decision.alternatives_considered)}
+            {render_rationale(&decision.rationale)}
+            {render_fingerprint(decision)}
+            {render_outcome(&decision.path, decision.outcome.as_ref())}
+        }
+    }
+}
+
+fn render_sticky(sticky: &StickyDecisionInfo) -> Element {
+    let remaining = sticky
+        .remaining_turns
+        .map(|turns| format!("剩余 {turns} 轮"))
+        .unwrap_or_else(|| "不限轮次".to_string());
+    let refinement = if sticky.refinement_allowed {
+        "允许细化"
+    } else {
+        "禁止细化"
+    };
+
+    rsx! {
+        div { class: "rounded border border-indigo-200 bg-indigo-50 p-3 space-y-1",
+            div { class: "flex flex-wrap items-center gap-2",
+                h4 { class: "font-semibold text-indigo-800", "粘性决策" }
+                span { class: "rounded bg-indigo-100 px-2 py-0.5 text-indigo-700", "{sticky.duration_type}" }
+                span { class: "rounded bg-indigo-600 px-2 py-0.5 font-semibold text-white", "{remaining}" }
+                span { class: "rounded bg-white px-2 py-0.5 text-indigo-700", "{refinement}" }
+            }
+            p { "{sticky.reason}" }
+            if let Some(fallback) = sticky.fallback_path.as_ref() {
+                p { "回退路径: " span { class: "font-mono text-indigo-800", "{fallback}" } }
+            } else {
+                p { class: "text-slate-500", "无回退路径" }
+            }
+        }
+    }
+}
+
+fn render_alternatives(chosen_path: &str, alternatives: &[AlternativeInfo]) -> Element {
+    let mut sorted: Vec<&AlternativeInfo> = alternatives.iter().collect();
+    sorted.sort_by(|a, b| b.score.total_cmp(&a.score));
+
+    rsx! {
+        div { class: "space-y-2",
+            h4 { class: "font-semibold text-slate-800", "备选方案" }
+            if sorted.is_empty() {
+                p { class: "text-slate-500 italic", "未记录备选方案，选定路径: {chosen_path}" }
+            } else {
+                for alt in sorted.into_iter() {
+                    {
+                        let score_text = format!("{:.2}", alt.score);
+                        let (delta_text, delta_class) = match alt.score_delta {
+                            Some(delta) if delta >= 0.0 => (format!("+{delta:.2}"), "text-green-600"),
+                            Some(delta) => (format!("{delta:.2}"), "text-red-600"),
+                            None => ("--".to_string(), "text-slate-400"),
+                        };
+                        let risk_text = alt
+                            .risk_assessment
+                            .map(|risk| format!("风险 {:.0}%", risk * 100.0));
+                        rsx! {
+                            div { class: "rounded border border-slate-200 p-2 space-y-1",
+                                div { class: "flex flex-wrap items-center gap-2",
+                                    span { class: "font-mono font-semibold text-slate-800", "{alt.path}" }
+                                    span { class: "font-mono text-slate-500", "评分 {score_text}" }
+                                    span { class: "font-mono {delta_class}", title: "相对选定路径的评分差", "Δ {delta_text}" }
+                                    if let Some(risk) = risk_text {
+                                        span { class: "rounded bg-amber-100 px-1.5 py-0.5 text-amber-700", "{risk}" }
+                                    }
+                                }
+                                p { class: "text-slate-600", "否决原因: {alt.rejection_reason}" }
+                                if !alt.would_have_required.is_empty() {
+                                    div { class: "flex flex-wrap items-center gap-1",
+                                        span { class: "text-slate-400", "若选择需要:" }
+                                        for requirement in alt.would_have_required.iter() {
+                                            span { class: "rounded bg-slate-100 px-1.5 py-0.5 text-slate-600", "{requirement}" }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn render_rationale(rationale: &DecisionRationaleInfo) -> Element {
+    let max_contribution = rationale
+        .confidence_factors
+        .iter()
+        .map(|factor| factor.contribution.abs())
+        .fold(0.0f32, f32::max)
+        .max(f32::EPSILON);
+
+    rsx! {
+        div { class: "space-y-3",
+            h4 { class: "font-semibold text-slate-800", "决策理由" }
+            p { class: "rounded bg-slate-50 p-2 text-slate-800", "{rationale.primary_reason}" }
+
+            if !rationale.supporting_evidence.is_empty() {
+                div { class: "space-y-1",
+                    p { class: "font-medium text-slate-600", "支持证据" }
+                    for evidence in rationale.supporting_evidence.iter() {
+                        {
+                            let relevance_pct = (evidence.relevance * 100.0).clamp(0.0, 100.0);
+                            rsx! {
+                                div { class: "rounded border border-slate-100 p-2 space-y-1",
+                                    div { class: "flex flex-wrap items-center gap-2",
+                                        span { class: "rounded bg-blue-100 px-1.5 py-0.5 text-blue-700", "{evidence.evidence_type}" }
+                                        span { class: "font-mono text-slate-500", "{evidence.source}" }
+                                        span { class: "ml-auto font-mono text-slate-500", "相关度 {relevance_pct:.0}%" }
+                                    }
+                                    div { class: "h-1 w-full rounded bg-slate-100",
+                                        div { class: "h-1 rounded bg-blue-500", style: "width: {relevance_pct:.1}%" }
+                                    }
+                                    p { "{evidence.description}" }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            if !rationale.confidence_factors.is_empty() {
+                div { class: "space-y-1",
+                    p { class: "font-medium text-slate-600", "置信度因素" }
+                    table { class: "w-full text-left",
+                        thead {
+                            tr { class: "text-slate-400",
+                                th { class: "py-1 font-normal", "因素" }
+                                th { class: "py-1 font-normal", "取值" }
+                                th { class: "py-1 font-normal", "权重" }
+                                th { class: "py-1 font-normal w-1/3", "贡献" }
+                            }
+                        }
+                        tbody {
+                            for factor in rationale.confidence_factors.iter() {
+                                {
+                                    let width = factor.contribution.abs() / max_contribution * 100.0;
+                                    let bar_class = if factor.contribution >= 0.0 {
+                                        "h-1.5 rounded bg-green-500"
+                                    } else {
+                                        "h-1.5 rounded bg-red-500"
+                                    };
+                                    rsx! {
+                                        tr { class: "border-t border-slate-100",
+                                            td { class: "py-1", "{factor.factor_name}" }
+                                            td { class: "py-1 font-mono", "{factor.value:.2}" }
+                                            td { class: "py-1 font-mono", "{factor.weight:.2}" }
+                                            td { class: "py-1",
+                                                div { class: "flex items-center gap-1",
+                                                    div { class: "h-1.5 flex-1 rounded bg-slate-100",
+                                                        div { class: "{bar_class}", style: "width: {width:.1}%" }
+                                                    }
+                                                    span { class: "w-12 text-right font-mono", "{factor.contribution:.3}" }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            if !rationale.potential_risks.is_empty() {
+                div { class: "space-y-1",
+                    p { class: "font-medium text-slate-600", "潜在风险" }
+                    for risk in rationale.potential_risks.iter() {
+                        {
+                            let badge = format!("rounded px-1.5 py-0.5 {}", Severity::parse(&risk.severity).badge_class());
+                            let probability_pct = risk.probability * 100.0;
+                            rsx! {
+                                div { class: "rounded border border-slate-100 p-2 space-y-1",
+                                    div { class: "flex flex-wrap items-center gap-2",
+                                        span { class: "{badge}", "{risk.severity}" }
+                                        span { class: "font-medium", "{risk.risk_type}" }
+                                        span { class: "ml-auto font-mono text-slate-500", "概率 {probability_pct:.0}%" }
+                                    }
+                                    p { "{risk.description}" }
+                                    if let Some(mitigation) = risk.mitigation.as_ref() {
+                                        p { class: "text-slate-500", "缓解: {mitigation}" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn render_fingerprint(decision: &DecisionDetail) -> Element {
+    let fingerprint = &decision.fingerprint;
+    let created_at = format_timestamp_ms(fingerprint.created_at_ms);
+    let mut features: Vec<&FeatureInfo> = fingerprint.features.iter().collect();
+    features.sort_by(|a, b| b.weight.total_cmp(&a.weight));
+    let max_weight = features
+        .iter()
+        .map(|feature| feature.weight.abs())
+        .fold(0.0f32, f32::max)
+        .max(f32::EPSILON);
+
+    rsx! {
+        div { class: "space-y-2",
+            h4 { class: "font-semibold text-slate-800", "决策指纹" }
+            div { class: "flex flex-wrap gap-3 text-slate-600",
+                span { "指纹 ID: " span { class: "font-mono", "{fingerprint.fingerprint_id}" } }
+                span { "Hash: " span { class: "font-mono", "{fingerprint.hash}" } }
+                span { "创建于 {created_at}" }
+            }
+            if features.is_empty() {
+                p { class: "text-slate-500 italic", "未记录特征" }
+            } else {
+                div { class: "space-y-1",
+                    for feature in features.into_iter() {
+                        {
+                            let width = feature.weight.abs() / max_weight * 100.0;
+                            let value_text = feature_value_text(&feature.value);
+                            rsx! {
+                                div { class: "grid grid-cols-12 items-center gap-2",
+                                    span { class: "col-span-3 truncate font-medium", title: "{feature.name}", "{feature.name}" }
+                                    span { class: "col-span-2 truncate text-slate-400", "{feature.feature_type}" }
+                                    div { class: "col-span-3 flex items-center gap-1",
+                                        div { class: "h-1.5 flex-1 rounded bg-slate-100",
+                                            div { class: "h-1.5 rounded bg-purple-500", style: "width: {width:.1}%" }
+                                        }
+                                        span { class: "w-10 text-right font-mono", "{feature.weight:.2}" }
+                                    }
+                                    span { class: "col-span-4 truncate font-mono text-slate-500", title: "{value_text}", "{value_text}" }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn render_outcome(chosen_path: &str, outcome: Option<&DecisionOutcome>) -> Element {
+    let Some(outcome) = outcome else {
+        return rsx! {
+            div { class: "space-y-1",
+                h4 { class: "font-semibold text-slate-800", "决策结果" }
+                p { class: "text-slate-500 italic", "尚无结果" }
+            }
+        };
+    };
+    let (status_text, status_class) = if outcome.was_successful {
+        ("成功", "bg-green-100 text-green-700")
+    } else {
+        ("失败", "bg-red-100 text-red-700")
+    };
+    let diverged = outcome.actual_path_taken != chosen_path;
+
+    rsx! {
+        div { class: "space-y-1",
+            div { class: "flex items-center gap-2",
+                h4 { class: "font-semibold text-slate-800", "决策结果" }
+                span { class: "rounded px-2 py-0.5 {status_class}", "{status_text}" }
+            }
+            p {
+                "实际路径: "
+                span { class: "font-mono", "{outcome.actual_path_taken}" }
+                if diverged {
+                    span { class: "ml-2 rounded bg-amber-100 px-1.5 py-0.5 text-amber-700", "与选定路径不同" }
+                }
+            }
+            p { "耗时 {outcome.duration_ms} ms · 成本 ${outcome.cost:.4}" }
+            if let Some(feedback) = outcome.user_feedback.as_ref() {
+                p { class: "rounded bg-slate-50 p-2 text-slate-600", "用户反馈: {feedback}" }
+            }
+        }
+    }
+}
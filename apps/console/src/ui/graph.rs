@@ -1,8 +1,12 @@
-use std::collections::BTreeMap;
+use std::collections::HashSet;
 
-use crate::hooks::graph::use_graph_insights;
-use crate::models::{CausalGraphNode, CausalGraphView, RecallResultView};
-use crate::state::{use_app_actions, use_app_state};
+use crate::hooks::graph::{fetch_recall, use_graph_insights};
+use crate::models::{CausalGraphEdge, CausalGraphNode, CausalGraphView, RecallResultView};
+use crate::state::{use_app_actions, use_app_state, AppActions, GraphDirection, RecallQuery};
+use crate::ui::causal_canvas::CausalGraphCanvas;
+use crate::ui::graph_export::{ExportGraph, GraphExportMenu};
+use crate::ui::interaction::{parse_scenario, scenario_value, SCENARIO_OPTIONS};
+use crate::ui::util::focus_timeline_event;
 use dioxus::prelude::*;
 
 #[component]
@@ -101,7 +105,14 @@ pub fn GraphPanel() -> Element {
         })
         .unwrap_or_default();
 
-    let recall_section = render_recall_list(&graph_state.recall);
+    let canvas_graph = graph_state
+        .causal
+        .as_ref()
+        .map(|graph| with_pinned_recall(graph, &graph_state.pinned_recall));
+    // 导出只包含后端返回的因果关系，固定召回的连线仅用于画布展示
+    let export_graph = graph_state.causal.as_ref().map(ExportGraph::from);
+    let depth = graph_state.query.effective_depth();
+    let direction = graph_state.query.direction;
 
     rsx! {
         section { class: "space-y-4",
@@ -126,6 +137,53 @@ pub fn GraphPanel() -> Element {
                         r#type: "submit",
                         "刷新"
                     }
+                    div { class: "flex flex-col gap-1",
+                        label { class: "text-[11px] font-semibold text-slate-600", "深度" }
+                        select {
+                            class: "rounded border border-slate-300 px-2 py-1 text-xs",
+                            value: "{depth}",
+                            onchange: {
+                                let actions = actions.clone();
+                                move |evt: FormEvent| {
+                                    if let Ok(value) = evt.value().parse::<u8>() {
+                                        actions.set_graph_depth(value);
+                                    }
+                                }
+                            },
+                            for level in 1..=6u8 {
+                                option { value: "{level}", selected: level == depth, "{level}" }
+                            }
+                        }
+                    }
+                    div { class: "flex flex-col gap-1",
+                        label { class: "text-[11px] font-semibold text-slate-600", "方向" }
+                        select {
+                            class: "rounded border border-slate-300 px-2 py-1 text-xs",
+                            value: direction.as_str(),
+                            onchange: {
+                                let actions = actions.clone();
+                                move |evt: FormEvent| {
+                                    let next = [
+                                        GraphDirection::Both,
+                                        GraphDirection::Upstream,
+                                        GraphDirection::Downstream,
+                                    ]
+                                    .into_iter()
+                                    .find(|candidate| candidate.as_str() == evt.value());
+                                    if let Some(next) = next {
+                                        actions.set_graph_direction(next);
+                                    }
+                                }
+                            },
+                            for option_direction in [GraphDirection::Both, GraphDirection::Upstream, GraphDirection::Downstream] {
+                                option {
+                                    value: option_direction.as_str(),
+                                    selected: option_direction == direction,
+                                    {option_direction.label()}
+                                }
+                            }
+                        }
+                    }
                     if let Some(up) = upstream {
                         button {
                             class: "rounded border border-slate-300 px-3 py-1.5 text-xs text-slate-700 hover:bg-slate-100",
@@ -160,87 +218,213 @@ pub fn GraphPanel() -> Element {
                     p { class: "text-xs text-slate-500", "正在加载图谱数据..." }
                 } else if let Some(ref err) = graph_state.error {
                     p { class: "text-xs text-red-500", "加载失败: {err}" }
-                } else if let Some(graph) = graph_state.causal.as_ref() {
-                    {render_causal_graph(graph)}
+                } else if let (Some(graph), Some(export_graph)) = (canvas_graph, export_graph) {
+                    GraphExportMenu { graph: export_graph, target: "graph:causal" }
+                    CausalGraphCanvas { graph, actions: actions.clone() }
                 } else {
                     p { class: "text-xs text-slate-500 italic", "暂无图谱数据" }
                 }
             }
 
-            div { class: "space-y-2",
-                h3 { class: "text-sm font-semibold text-slate-800", "Top-K 历史召回" }
-                {recall_section}
-            }
+            RecallPanel {}
         }
     }
 }
 
-fn render_recall_list(items: &[RecallResultView]) -> Element {
-    if items.is_empty() {
-        return rsx! { p { class: "text-xs text-slate-500 italic", "暂无召回结果" } };
-    }
+#[component]
+fn RecallPanel() -> Element {
+    let state = use_app_state();
+    let actions = use_app_actions();
+
+    let snapshot = state.read();
+    let graph_state = snapshot.graph.clone();
+    let timeline_ids: HashSet<u64> = snapshot
+        .timeline
+        .events
+        .iter()
+        .map(|event| event.event_id.as_u64())
+        .collect();
+    drop(snapshot);
+
+    let last_query = graph_state.recall_query.clone();
+    let mut text_input = use_signal(|| last_query.text.clone().unwrap_or_default());
+    let mut anchor_input = use_signal(|| {
+        last_query
+            .anchor_event_id
+            .map(|id| id.to_string())
+            .unwrap_or_default()
+    });
+    let mut top_k_input = use_signal(|| last_query.effective_top_k().to_string());
+    let mut scenario_input = use_signal(|| {
+        last_query
+            .scenario
+            .as_ref()
+            .map(|scenario| scenario_value(scenario).to_string())
+            .unwrap_or_default()
+    });
+
+    let root = graph_state.query.root_event_id;
+    let scenario_selected = scenario_input.read().clone();
+
+    let on_submit = {
+        let actions = actions.clone();
+        move |evt: FormEvent| {
+            evt.prevent_default();
+            let anchor_text = anchor_input.read().trim().to_string();
+            let anchor_event_id = if anchor_text.is_empty() {
+                None
+            } else {
+                match anchor_text.parse::<u64>() {
+                    Ok(id) => Some(id),
+                    Err(_) => {
+                        actions.set_recall_error(Some("锚点事件必须是数字".into()));
+                        return;
+                    }
+                }
+            };
+            let text = text_input.read().trim().to_string();
+            let query = RecallQuery {
+                text: (!text.is_empty()).then_some(text),
+                anchor_event_id,
+                top_k: top_k_input.read().trim().parse().unwrap_or(0),
+                scenario: parse_scenario(&scenario_input.read()),
+            };
+            spawn(fetch_recall(actions.clone(), state, query));
+        }
+    };
+
+    let recall_list = render_recall_list(
+        &graph_state.recall,
+        &graph_state.pinned_recall,
+        &timeline_ids,
+        root,
+        &actions,
+    );
 
     rsx! {
-        ul { class: "space-y-2",
-            for item in items.iter() {
-                li { class: "rounded border border-slate-200 bg-white p-3 text-xs text-slate-600 shadow-sm space-y-1",
-                    div { class: "flex items-center justify-between",
-                        span { class: "font-semibold text-slate-800", {format!("事件 #{}", item.event_id)} }
-                        span { class: "rounded bg-slate-100 px-2 py-0.5 text-[11px] text-slate-600", {format_score(item.score)} }
+        div { class: "rounded-lg border border-slate-200 bg-white p-4 shadow-sm space-y-3",
+            header { class: "flex flex-wrap items-center justify-between gap-2",
+                h3 { class: "text-sm font-semibold text-slate-800", "Top-K 历史召回" }
+                if !graph_state.pinned_recall.is_empty() {
+                    span { class: "text-[11px] text-slate-500",
+                        {format!("已固定 {} 个召回事件到因果图", graph_state.pinned_recall.len())}
+                    }
+                }
+            }
+
+            form { class: "flex flex-wrap items-end gap-2 text-xs", onsubmit: on_submit,
+                div { class: "flex min-w-[200px] flex-1 flex-col gap-1",
+                    label { class: "text-[11px] font-semibold text-slate-600", "召回文本" }
+                    input {
+                        class: "rounded border border-slate-300 px-2 py-1",
+                        value: "{text_input.read()}",
+                        oninput: move |evt| text_input.set(evt.value()),
+                        placeholder: "描述要回溯的内容",
                     }
-                    if let Some(label) = item.label.as_ref() {
-                        p { class: "text-[11px] text-slate-500", "{label}" }
+                }
+                div { class: "flex flex-col gap-1",
+                    label { class: "text-[11px] font-semibold text-slate-600", "锚点事件" }
+                    div { class: "flex gap-1",
+                        input {
+                            class: "w-28 rounded border border-slate-300 px-2 py-1",
+                            value: "{anchor_input.read()}",
+                            oninput: move |evt| anchor_input.set(evt.value()),
+                            placeholder: "event_id",
+                        }
+                        if let Some(root) = root {
+                            button {
+                                class: "rounded border border-slate-300 px-2 py-1 text-[11px] text-slate-600 hover:bg-slate-100",
+                                r#type: "button",
+                                onclick: move |_| anchor_input.set(root.to_string()),
+                                "使用 root"
+                            }
+                        }
                     }
-                    if let Some(snippet) = item.snippet.as_ref() {
-                        p { class: "text-slate-500", "{snippet}" }
+                }
+                div { class: "flex flex-col gap-1",
+                    label { class: "text-[11px] font-semibold text-slate-600", "Top-K" }
+                    input {
+                        class: "w-16 rounded border border-slate-300 px-2 py-1",
+                        r#type: "number",
+                        min: "1",
+                        max: "100",
+                        value: "{top_k_input.read()}",
+                        oninput: move |evt| top_k_input.set(evt.value()),
                     }
-                    if let Some(reason) = item.reason.as_ref() {
-                        p { class: "text-[11px] text-slate-400", {format!("来源: {reason}")} }
+                }
+                div { class: "flex flex-col gap-1",
+                    label { class: "text-[11px] font-semibold text-slate-600", "场景" }
+                    select {
+                        class: "rounded border border-slate-300 px-2 py-1",
+                        value: "{scenario_selected}",
+                        onchange: move |evt| scenario_input.set(evt.value()),
+                        option { value: "", selected: scenario_selected.is_empty(), "全部场景" }
+                        for option in SCENARIO_OPTIONS.iter() {
+                            option {
+                                value: option.value,
+                                selected: scenario_selected == option.value,
+                                "{option.label}"
+                            }
+                        }
                     }
                 }
+                button {
+                    class: "rounded bg-slate-900 px-3 py-1.5 text-xs font-semibold text-white hover:bg-slate-800 disabled:opacity-50",
+                    r#type: "submit",
+                    disabled: graph_state.recall_loading,
+                    if graph_state.recall_loading { "召回中..." } else { "召回" }
+                }
+            }
+
+            if let Some(err) = graph_state.recall_error.as_ref() {
+                p { class: "text-xs text-red-500", "{err}" }
             }
+            {recall_list}
         }
     }
 }
 
-fn render_causal_graph(graph: &CausalGraphView) -> Element {
-    let mut grouped: BTreeMap<i32, Vec<&CausalGraphNode>> = BTreeMap::new();
-    for node in &graph.nodes {
-        let depth = node.depth.unwrap_or(0);
-        grouped.entry(depth).or_default().push(node);
+fn render_recall_list(
+    items: &[RecallResultView],
+    pinned: &[RecallResultView],
+    timeline_ids: &HashSet<u64>,
+    root: Option<u64>,
+    actions: &AppActions,
+) -> Element {
+    // 固定项独立于当前结果保存，不在本次结果中的单独列出以便取消固定
+    let detached: Vec<&RecallResultView> = pinned
+        .iter()
+        .filter(|pin| !items.iter().any(|item| item.event_id == pin.event_id))
+        .collect();
+
+    if items.is_empty() && detached.is_empty() {
+        return rsx! { p { class: "text-xs text-slate-500 italic", "暂无召回结果" } };
     }
 
+    let max_score = items
+        .iter()
+        .chain(detached.iter().copied())
+        .map(|item| item.score)
+        .fold(0.0_f32, f32::max)
+        .max(f32::EPSILON);
+    let is_pinned = |event_id: u64| pinned.iter().any(|pin| pin.event_id == event_id);
+
     rsx! {
-        div { class: "space-y-3",
-            h3 { class: "text-sm font-semibold text-slate-800", {format!("因果链 root #{:?}", graph.root_event_id)} }
-            for (depth, nodes) in grouped.iter() {
-                div { class: "flex flex-col gap-2",
-                    span { class: "text-[11px] font-semibold uppercase tracking-wide text-slate-500", {format!("Depth {depth}")} }
-                    div { class: "flex flex-wrap gap-2",
-                        for node in nodes.iter() {
-                            div { class: "min-w-[180px] flex-1 rounded border border-slate-200 bg-slate-50 p-3 text-xs text-slate-600 shadow-inner",
-                                p { class: "font-semibold text-slate-800", {format!("事件 #{:?}", node.event_id)} }
-                                if let Some(label) = node.label.as_ref() {
-                                    p { class: "text-[11px] text-slate-500", "{label}" }
-                                }
-                                if let Some(summary) = node.summary.as_ref() {
-                                    p { class: "", "{summary}" }
-                                }
-                                if let Some(score) = node.score {
-                                    p { class: "text-[11px] text-slate-400", {format!("Score: {}", format_score(score))} }
-                                }
-                            }
-                        }
-                    }
+        if items.is_empty() {
+            p { class: "text-xs text-slate-500 italic", "暂无召回结果" }
+        } else {
+            ul { class: "space-y-2",
+                for item in items.iter() {
+                    {render_recall_item(item, max_score, is_pinned(item.event_id), timeline_ids, root, actions)}
                 }
             }
-            if !graph.edges.is_empty() {
-                div { class: "space-y-1",
-                    span { class: "text-[11px] font-semibold text-slate-500", "边关系" }
-                    ul { class: "space-y-1 text-xs text-slate-500",
-                        for edge in graph.edges.iter() {
-                            li { {format!("#{} -> #{} ({})", edge.from, edge.to, edge.relation.clone().unwrap_or_else(|| "关联".into()))} }
-                        }
+        }
+        if !detached.is_empty() {
+            div { class: "space-y-2",
+                span { class: "text-[11px] font-semibold text-slate-500", "已固定（不在当前结果中）" }
+                ul { class: "space-y-2",
+                    for item in detached.iter() {
+                        {render_recall_item(item, max_score, true, timeline_ids, root, actions)}
                     }
                 }
             }
@@ -248,6 +432,105 @@ fn render_causal_graph(graph: &CausalGraphView) -> Element {
     }
 }
 
+fn render_recall_item(
+    item: &RecallResultView,
+    max_score: f32,
+    is_pinned: bool,
+    timeline_ids: &HashSet<u64>,
+    root: Option<u64>,
+    actions: &AppActions,
+) -> Element {
+    let event_id = item.event_id;
+    let in_timeline = timeline_ids.contains(&event_id);
+    let bar_width = (item.score / max_score * 100.0).clamp(0.0, 100.0);
+    let pin_actions = actions.clone();
+    let pin_item = item.clone();
+    let root_actions = actions.clone();
+    let focus_actions = actions.clone();
+    let pin_class = if is_pinned {
+        "rounded bg-sky-600 px-2 py-0.5 text-white hover:bg-sky-500 disabled:opacity-50"
+    } else {
+        "rounded border border-sky-300 px-2 py-0.5 text-sky-700 hover:bg-sky-50 disabled:opacity-50"
+    };
+    let focus_title = if in_timeline {
+        ""
+    } else {
+        "时间线尚未加载该事件"
+    };
+
+    rsx! {
+        li { class: "rounded border border-slate-200 bg-white p-3 text-xs text-slate-600 shadow-sm space-y-1",
+            div { class: "flex items-center justify-between",
+                span { class: "font-semibold text-slate-800", {format!("事件 #{}", event_id)} }
+                span { class: "rounded bg-slate-100 px-2 py-0.5 text-[11px] text-slate-600", {format_score(item.score)} }
+            }
+            div { class: "h-1.5 w-full overflow-hidden rounded bg-slate-100",
+                div { class: "h-1.5 bg-sky-500", style: "width: {bar_width:.1}%" }
+            }
+            if let Some(label) = item.label.as_ref() {
+                p { class: "text-[11px] text-slate-500", "{label}" }
+            }
+            if let Some(snippet) = item.snippet.as_ref() {
+                p { class: "text-slate-500", "{snippet}" }
+            }
+            if let Some(reason) = item.reason.as_ref() {
+                p { class: "text-[11px] text-slate-400", {format!("来源: {reason}")} }
+            }
+            div { class: "flex flex-wrap gap-2 pt-1 text-[11px]",
+                button {
+                    class: pin_class,
+                    disabled: !is_pinned && (root.is_none() || root == Some(event_id)),
+                    onclick: move |_| pin_actions.toggle_recall_pin(pin_item.clone()),
+                    if is_pinned { "取消固定" } else { "固定到因果图" }
+                }
+                button {
+                    class: "rounded border border-slate-300 px-2 py-0.5 text-slate-700 hover:bg-slate-100",
+                    onclick: move |_| root_actions.set_graph_root(Some(event_id)),
+                    "设为 root"
+                }
+                button {
+                    class: "rounded border border-emerald-300 px-2 py-0.5 text-emerald-700 hover:bg-emerald-50 disabled:opacity-50",
+                    disabled: !in_timeline,
+                    title: focus_title,
+                    onclick: move |_| focus_timeline_event(&focus_actions, event_id),
+                    "在时间线中打开"
+                }
+            }
+        }
+    }
+}
+
+/// 把固定的召回结果作为 root 的“召回”邻居并入因果图，已在图中的事件只保留原节点
+fn with_pinned_recall(graph: &CausalGraphView, pinned: &[RecallResultView]) -> CausalGraphView {
+    let mut merged = graph.clone();
+    for item in pinned.iter() {
+        if item.event_id == graph.root_event_id
+            || merged
+                .nodes
+                .iter()
+                .any(|node| node.event_id == item.event_id)
+        {
+            continue;
+        }
+        merged.nodes.push(CausalGraphNode {
+            event_id: item.event_id,
+            event_type: None,
+            scenario: None,
+            label: item.label.clone(),
+            summary: item.snippet.clone(),
+            timestamp_ms: None,
+            depth: None,
+            score: Some(item.score),
+        });
+        merged.edges.push(CausalGraphEdge {
+            from: graph.root_event_id,
+            to: item.event_id,
+            relation: Some(format!("召回 {}", format_score(item.score))),
+        });
+    }
+    merged
+}
+
 fn format_score(score: f32) -> String {
     format!("{:.2}", score)
 }
@@ -2,12 +2,18 @@
 //!
 //! 展示图谱节点详情、边关系等增强功能
 
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+
 use dioxus::prelude::*;
 
-use crate::models::{GraphEdgeRef, GraphNodeDetail};
-use crate::state::use_app_state;
+use crate::api::ThinWaistClient;
+use crate::models::{GraphEdgeDetail, GraphEdgeRef, GraphEdgesQuery, GraphNodeDetail, NodeRef};
+use crate::state::{use_app_state, AppSignal};
+use crate::ui::graph_export::{ExportGraph, GraphExportMenu};
 use crate::{API_CLIENT, APP_CONFIG};
 
+const EDGE_PAGE_SIZES: &[u32] = &[20, 50, 100];
+
 /// 图谱节点查询状态
 #[derive(Clone, Debug, Default)]
 struct NodeQueryState {
@@ -31,53 +37,15 @@ pub fn GraphEnhancedPanel() -> Element {
             return;
         }
 
-        // 获取 tenant_id
-        let snapshot = state_store.read();
-        let tenant_id = snapshot.tenant_id.clone();
-        drop(snapshot);
-
-        let tenant = tenant_id.or_else(|| {
-            APP_CONFIG
-                .get()
-                .and_then(|cfg| cfg.default_tenant_id.clone())
-        });
-
-        let Some(tenant) = tenant else {
-            query_state.write().error = Some("请先选择租户".into());
-            return;
-        };
-
-        let Some(client) = API_CLIENT.get().cloned() else {
-            query_state.write().error = Some("API 客户端未初始化".into());
-            return;
+        let (tenant, client) = match resolve_client(state_store) {
+            Ok(resolved) => resolved,
+            Err(message) => {
+                query_state.write().error = Some(message);
+                return;
+            }
         };
 
-        // 设置加载状态
-        query_state.write().loading = true;
-        query_state.write().error = None;
-
-        // 发起异步请求
-        spawn(async move {
-            tracing::info!("开始查询图谱节点: tenant={}, node_id={}", tenant, current_id);
-
-            match client
-                .get_graph_node::<GraphNodeDetail>(&tenant, &current_id)
-                .await
-            {
-                Ok(env) => {
-                    tracing::info!("图谱节点查询成功: {:?}", env.data);
-                    let mut state = query_state.write();
-                    state.node = env.data;
-                    state.loading = false;
-                }
-                Err(err) => {
-                    tracing::error!("图谱节点加载失败: {err}");
-                    let mut state = query_state.write();
-                    state.error = Some(format!("加载失败: {err}"));
-                    state.loading = false;
-                }
-            }
-        });
+        load_graph_node(query_state, tenant, client, current_id);
     };
 
     rsx! {
@@ -159,6 +127,9 @@ fn render_node_detail(node: &GraphNodeDetail) -> Element {
                     "{node.node_type}"
                 }
             }
+            div { class: "mb-4",
+                GraphExportMenu { graph: ExportGraph::from(node), target: "graph:node_neighborhood" }
+            }
             // 基本信息
             div { class: "grid grid-cols-2 md:grid-cols-3 gap-4 mb-4",
                 div { class: "text-center p-3 bg-slate-50 rounded",
@@ -232,18 +203,469 @@ fn render_edge_ref(edge: &GraphEdgeRef, direction: &str) -> Element {
     }
 }
 
-/// 图谱边浏览器组件 - 简化版
+/// 解析当前租户与 API 客户端
+fn resolve_client(state_store: AppSignal) -> Result<(String, ThinWaistClient), String> {
+    let tenant = state_store.read().tenant_id.clone().or_else(|| {
+        APP_CONFIG
+            .get()
+            .and_then(|cfg| cfg.default_tenant_id.clone())
+    });
+    let Some(tenant) = tenant else {
+        return Err("请先选择租户".into());
+    };
+    let Some(client) = API_CLIENT.get().cloned() else {
+        return Err("API 客户端未初始化".into());
+    };
+    Ok((tenant, client))
+}
+
+/// 异步加载节点详情并写入查询状态
+fn load_graph_node(
+    mut query_state: Signal<NodeQueryState>,
+    tenant: String,
+    client: ThinWaistClient,
+    node_id: String,
+) {
+    // 设置加载状态
+    query_state.write().loading = true;
+    query_state.write().error = None;
+
+    // 发起异步请求
+    spawn(async move {
+        tracing::info!("开始查询图谱节点: tenant={}, node_id={}", tenant, node_id);
+
+        match client
+            .get_graph_node::<GraphNodeDetail>(&tenant, &node_id)
+            .await
+        {
+            Ok(env) => {
+                tracing::info!("图谱节点查询成功: {:?}", env.data);
+                let mut state = query_state.write();
+                state.node = env.data;
+                state.loading = false;
+            }
+            Err(err) => {
+                tracing::error!("图谱节点加载失败: {err}");
+                let mut state = query_state.write();
+                state.error = Some(format!("加载失败: {err}"));
+                state.loading = false;
+            }
+        }
+    });
+}
+
+/// 边浏览器查询状态
+#[derive(Clone, Debug, Default)]
+struct EdgeQueryState {
+    loading: bool,
+    error: Option<String>,
+    edges: Vec<GraphEdgeDetail>,
+    /// 最近一次查询使用的筛选条件，翻页时沿用
+    query: GraphEdgesQuery,
+    /// 服务端已返回的条数，作为下一页 offset；去重后的 `edges` 可能少于该值
+    next_offset: u32,
+    /// 已无更多数据
+    exhausted: bool,
+}
+
+fn non_empty(value: &str) -> Option<String> {
+    let trimmed = value.trim();
+    (!trimmed.is_empty()).then(|| trimmed.to_string())
+}
+
+/// 拉取一页边数据；`append` 为真时追加到已有结果并按 edge_id 去重
+fn load_edge_page(
+    mut edge_state: Signal<EdgeQueryState>,
+    tenant: String,
+    client: ThinWaistClient,
+    query: GraphEdgesQuery,
+    append: bool,
+) {
+    {
+        let mut state = edge_state.write();
+        state.loading = true;
+        state.error = None;
+        state.query = query.clone();
+        if !append {
+            state.edges.clear();
+            state.next_offset = 0;
+            state.exhausted = false;
+        }
+    }
+
+    spawn(async move {
+        match client
+            .get_graph_edges::<_, Vec<GraphEdgeDetail>>(&tenant, Some(&query))
+            .await
+        {
+            Ok(env) => {
+                let page = env.data.unwrap_or_default();
+                let page_len = page.len() as u32;
+                let mut state = edge_state.write();
+                // 期间已发起新的查询，丢弃过期的响应
+                if state.query != query {
+                    return;
+                }
+                state.next_offset = query.offset.unwrap_or(0).saturating_add(page_len);
+                let known: HashSet<String> = state
+                    .edges
+                    .iter()
+                    .map(|edge| edge.edge_id.clone())
+                    .collect();
+                let fresh: Vec<GraphEdgeDetail> = page
+                    .into_iter()
+                    .filter(|edge| !known.contains(&edge.edge_id))
+                    .collect();
+                // 不足一页或整页重复（服务端忽略 offset）都视为到底
+                state.exhausted =
+                    page_len < query.limit.unwrap_or(u32::MAX) || (append && fresh.is_empty());
+                state.edges.extend(fresh);
+                state.loading = false;
+            }
+            Err(err) => {
+                tracing::error!("图谱边加载失败: {err}");
+                let mut state = edge_state.write();
+                if state.query != query {
+                    return;
+                }
+                state.error = Some(format!("加载失败: {err}"));
+                state.loading = false;
+            }
+        }
+    });
+}
+
+/// 图谱边浏览器组件
 #[component]
 pub fn GraphEdgeBrowser() -> Element {
+    let state_store = use_app_state();
+    let mut edge_type_input = use_signal(String::new);
+    let mut from_input = use_signal(String::new);
+    let mut to_input = use_signal(String::new);
+    let mut page_size = use_signal(|| EDGE_PAGE_SIZES[0]);
+    let mut edge_state = use_signal(EdgeQueryState::default);
+    let mut selected_edge = use_signal(|| Option::<String>::None);
+    let mut collapsed = use_signal(HashSet::<String>::new);
+    let node_state = use_signal(NodeQueryState::default);
+
+    let mut run_query = move |append: bool| {
+        let (tenant, client) = match resolve_client(state_store) {
+            Ok(resolved) => resolved,
+            Err(message) => {
+                edge_state.write().error = Some(message);
+                return;
+            }
+        };
+        let query = if append {
+            let current = edge_state.read();
+            GraphEdgesQuery {
+                offset: Some(current.next_offset),
+                ..current.query.clone()
+            }
+        } else {
+            GraphEdgesQuery {
+                edge_type: non_empty(&edge_type_input.read()),
+                from_node: non_empty(&from_input.read()),
+                to_node: non_empty(&to_input.read()),
+                limit: Some(*page_size.read()),
+                offset: None,
+            }
+        };
+        load_edge_page(edge_state, tenant, client, query, append);
+    };
+
+    let open_node = move |node_id: String| match resolve_client(state_store) {
+        Ok((tenant, client)) => load_graph_node(node_state, tenant, client, node_id),
+        Err(message) => {
+            let mut node_state = node_state;
+            node_state.write().error = Some(message);
+        }
+    };
+
+    let snapshot = edge_state.read().clone();
+    let mut families: BTreeMap<String, Vec<GraphEdgeDetail>> = BTreeMap::new();
+    for edge in snapshot.edges.iter() {
+        families
+            .entry(edge.edge_family.clone())
+            .or_default()
+            .push(edge.clone());
+    }
+    let edge_types: Vec<String> = snapshot
+        .edges
+        .iter()
+        .map(|edge| edge.edge_type.clone())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect();
+    let current_selected = selected_edge.read().clone();
+    let collapsed_families = collapsed.read().clone();
+    let node_snapshot = node_state.read().clone();
+
     rsx! {
         section { class: "space-y-3",
             header { class: "flex flex-col gap-1",
                 h2 { class: "text-lg font-semibold text-slate-900", "边浏览器" }
                 p { class: "text-xs text-slate-500", "按条件筛选图谱边" }
             }
-            div { class: "rounded-lg border border-slate-200 bg-slate-50 p-6 text-center",
-                p { class: "text-xs text-slate-500 italic", "边浏览功能开发中..." }
+            // 筛选条件
+            form {
+                class: "rounded-lg border border-slate-200 bg-white p-4 shadow-sm flex flex-wrap items-end gap-2 text-xs",
+                onsubmit: move |evt: FormEvent| {
+                    evt.prevent_default();
+                    selected_edge.set(None);
+                    run_query(false);
+                },
+                div { class: "flex flex-col gap-1",
+                    label { class: "text-[11px] font-semibold text-slate-600", "edge_type" }
+                    input {
+                        class: "w-32 rounded border border-slate-300 px-2 py-1",
+                        list: "graph-edge-types",
+                        value: "{edge_type_input}",
+                        oninput: move |evt| edge_type_input.set(evt.value()),
+                        placeholder: "全部",
+                    }
+                    datalist { id: "graph-edge-types",
+                        for edge_type in edge_types.iter() {
+                            option { value: "{edge_type}" }
+                        }
+                    }
+                }
+                div { class: "flex flex-col gap-1",
+                    label { class: "text-[11px] font-semibold text-slate-600", "from_node" }
+                    input {
+                        class: "w-36 rounded border border-slate-300 px-2 py-1 font-mono",
+                        value: "{from_input}",
+                        oninput: move |evt| from_input.set(evt.value()),
+                    }
+                }
+                div { class: "flex flex-col gap-1",
+                    label { class: "text-[11px] font-semibold text-slate-600", "to_node" }
+                    input {
+                        class: "w-36 rounded border border-slate-300 px-2 py-1 font-mono",
+                        value: "{to_input}",
+                        oninput: move |evt| to_input.set(evt.value()),
+                    }
+                }
+                div { class: "flex flex-col gap-1",
+                    label { class: "text-[11px] font-semibold text-slate-600", "每页" }
+                    select {
+                        class: "rounded border border-slate-300 px-2 py-1",
+                        onchange: move |evt: FormEvent| {
+                            if let Ok(size) = evt.value().parse::<u32>() {
+                                page_size.set(size);
+                            }
+                        },
+                        for size in EDGE_PAGE_SIZES.iter() {
+                            option { value: "{size}", selected: *size == *page_size.read(), "{size}" }
+                        }
+                    }
+                }
+                button {
+                    class: "rounded bg-slate-900 px-3 py-1.5 font-semibold text-white hover:bg-slate-800 disabled:opacity-50",
+                    r#type: "submit",
+                    disabled: snapshot.loading,
+                    "查询"
+                }
+                button {
+                    class: "rounded border border-slate-300 px-3 py-1.5 text-slate-700 hover:bg-slate-100",
+                    r#type: "button",
+                    onclick: move |_| {
+                        edge_type_input.set(String::new());
+                        from_input.set(String::new());
+                        to_input.set(String::new());
+                    },
+                    "清空条件"
+                }
+            }
+
+            if let Some(err) = snapshot.error.as_ref() {
+                p { class: "text-xs text-red-500", "{err}" }
+            }
+
+            if snapshot.edges.is_empty() && !snapshot.loading {
+                div { class: "rounded-lg border border-slate-200 bg-slate-50 p-6 text-center",
+                    p { class: "text-xs text-slate-500 italic", "暂无边数据，设置条件后查询" }
+                }
+            } else {
+                div { class: "space-y-2",
+                    p { class: "text-[11px] text-slate-500",
+                        {format!("已加载 {} 条边 · {} 个边族", snapshot.edges.len(), families.len())}
+                    }
+                    for (family, edges) in families.iter() {
+                        {
+                            let is_collapsed = collapsed_families.contains(family);
+                            let weights: Vec<f32> = edges.iter().filter_map(|edge| edge.weight).collect();
+                            let avg_weight = (!weights.is_empty())
+                                .then(|| weights.iter().sum::<f32>() / weights.len() as f32);
+                            let family_key = family.clone();
+                            rsx! {
+                                div { class: "rounded-lg border border-slate-200 bg-white shadow-sm",
+                                    button {
+                                        class: "flex w-full items-center justify-between px-3 py-2 text-left text-xs hover:bg-slate-50",
+                                        onclick: move |_| {
+                                            let mut set = collapsed.write();
+                                            if !set.remove(&family_key) {
+                                                set.insert(family_key.clone());
+                                            }
+                                        },
+                                        span { class: "font-semibold text-slate-800",
+                                            {format!("{} {}", if is_collapsed { "▸" } else { "▾" }, family)}
+                                        }
+                                        span { class: "text-[11px] text-slate-500",
+                                            {format!(
+                                                "{} 条{}",
+                                                edges.len(),
+                                                avg_weight.map(|w| format!(" · 平均权重 {w:.2}")).unwrap_or_default()
+                                            )}
+                                        }
+                                    }
+                                    if !is_collapsed {
+                                        ul { class: "divide-y divide-slate-100 border-t border-slate-100",
+                                            for edge in edges.iter() {
+                                                {render_edge_row(
+                                                    edge,
+                                                    current_selected.as_deref() == Some(edge.edge_id.as_str()),
+                                                    selected_edge,
+                                                    open_node,
+                                                )}
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    if snapshot.loading {
+                        p { class: "text-xs text-slate-500", "正在加载边数据..." }
+                    } else if !snapshot.exhausted {
+                        button {
+                            class: "w-full rounded border border-slate-300 py-1.5 text-xs text-slate-700 hover:bg-slate-100",
+                            onclick: move |_| run_query(true),
+                            "加载更多"
+                        }
+                    } else {
+                        p { class: "text-center text-[11px] text-slate-400", "已加载全部匹配的边" }
+                    }
+                }
             }
+
+            // 端点节点详情
+            if node_snapshot.loading {
+                p { class: "text-xs text-slate-500", "正在加载节点详情..." }
+            } else if let Some(err) = node_snapshot.error.as_ref() {
+                p { class: "text-xs text-red-500", "{err}" }
+            } else if let Some(node) = node_snapshot.node.as_ref() {
+                {
+                    let out_id = node.node_id.clone();
+                    let in_id = node.node_id.clone();
+                    rsx! {
+                        div { class: "space-y-2",
+                            div { class: "flex flex-wrap gap-2 text-xs",
+                                button {
+                                    class: "rounded border border-slate-300 px-2 py-1 text-slate-700 hover:bg-slate-100",
+                                    onclick: move |_| {
+                                        from_input.set(out_id.clone());
+                                        to_input.set(String::new());
+                                        run_query(false);
+                                    },
+                                    "筛选该节点出边"
+                                }
+                                button {
+                                    class: "rounded border border-slate-300 px-2 py-1 text-slate-700 hover:bg-slate-100",
+                                    onclick: move |_| {
+                                        to_input.set(in_id.clone());
+                                        from_input.set(String::new());
+                                        run_query(false);
+                                    },
+                                    "筛选该节点入边"
+                                }
+                            }
+                            {render_node_detail(node)}
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn render_edge_row(
+    edge: &GraphEdgeDetail,
+    is_selected: bool,
+    mut selected_edge: Signal<Option<String>>,
+    open_node: impl Fn(String) + Copy + 'static,
+) -> Element {
+    let edge_id = edge.edge_id.clone();
+    let weight_pct = edge.weight.map(|w| (w.clamp(0.0, 1.0) * 100.0) as u32);
+    let from_id = edge.from_node.node_id.clone();
+    let to_id = edge.to_node.node_id.clone();
+    let mut properties: Vec<(String, String)> = edge
+        .properties
+        .iter()
+        .map(|(key, value)| (key.clone(), value.to_string()))
+        .collect();
+    properties.sort();
+
+    rsx! {
+        li { class: "px-3 py-2 text-xs text-slate-600 space-y-1",
+            div { class: "flex flex-wrap items-center gap-2",
+                span { class: "px-2 py-0.5 bg-slate-200 text-slate-600 rounded", "{edge.edge_type}" }
+                {render_node_ref_button(&edge.from_node, move |_| open_node(from_id.clone()))}
+                span { class: "text-slate-400", "→" }
+                {render_node_ref_button(&edge.to_node, move |_| open_node(to_id.clone()))}
+                if let Some(weight) = edge.weight {
+                    span { class: "flex items-center gap-1 text-slate-500",
+                        span { class: "inline-block h-1.5 w-16 overflow-hidden rounded bg-slate-100",
+                            span {
+                                class: "block h-1.5 bg-blue-500",
+                                style: "width: {weight_pct.unwrap_or(0)}%",
+                            }
+                        }
+                        {format!("{weight:.2}")}
+                    }
+                }
+                button {
+                    class: "ml-auto text-[11px] text-blue-600 hover:underline",
+                    onclick: move |_| {
+                        let next = if is_selected { None } else { Some(edge_id.clone()) };
+                        selected_edge.set(next);
+                    },
+                    if is_selected { "收起属性" } else { "查看属性" }
+                }
+            }
+            if is_selected {
+                div { class: "rounded bg-slate-50 p-2 space-y-1",
+                    p { class: "font-mono text-[11px] text-slate-500", "{edge.edge_id} · 创建于 {edge.created_at_ms}" }
+                    if properties.is_empty() {
+                        p { class: "text-[11px] italic text-slate-400", "无属性" }
+                    } else {
+                        div { class: "grid grid-cols-2 gap-2",
+                            for (key, value) in properties.iter() {
+                                div { class: "p-2 bg-white rounded",
+                                    p { class: "text-xs text-slate-500", "{key}" }
+                                    p { class: "text-sm text-slate-700 font-mono break-all", "{value}" }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn render_node_ref_button(
+    node: &NodeRef,
+    mut onclick: impl FnMut(MouseEvent) + 'static,
+) -> Element {
+    let title = format!("{} · {}", node.node_type, node.node_id);
+    let text = node.label.clone().unwrap_or_else(|| node.node_id.clone());
+    rsx! {
+        button {
+            class: "font-mono text-blue-700 hover:underline",
+            title: "{title}",
+            onclick: move |evt| onclick(evt),
+            "{text}"
         }
     }
 }
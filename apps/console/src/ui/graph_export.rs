@@ -0,0 +1,536 @@
+//! 图谱导出
+//!
+//! 将因果图、节点邻域与因果链统一转换为 DOT / GraphML / Cytoscape.js JSON
+
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+
+use dioxus::prelude::*;
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+
+use crate::models::{CausalChainResponse, CausalGraphView, GraphEdgeRef, GraphNodeDetail};
+use crate::state::use_app_actions;
+use crate::ui::ace::export_text_as_file;
+
+/// 导出属性值，区分类型以便 GraphML 声明 `attr.type`
+#[derive(Clone, Debug, PartialEq)]
+pub enum AttrValue {
+    Str(String),
+    Int(i64),
+    Float(f64),
+    Bool(bool),
+}
+
+impl AttrValue {
+    fn graphml_type(&self) -> &'static str {
+        match self {
+            AttrValue::Str(_) => "string",
+            AttrValue::Int(_) => "long",
+            AttrValue::Float(_) => "double",
+            AttrValue::Bool(_) => "boolean",
+        }
+    }
+
+    fn to_text(&self) -> String {
+        match self {
+            AttrValue::Str(value) => value.clone(),
+            AttrValue::Int(value) => value.to_string(),
+            AttrValue::Float(value) => value.to_string(),
+            AttrValue::Bool(value) => value.to_string(),
+        }
+    }
+
+    fn to_json(&self) -> Value {
+        match self {
+            AttrValue::Str(value) => json!(value),
+            AttrValue::Int(value) => json!(value),
+            AttrValue::Float(value) => json!(value),
+            AttrValue::Bool(value) => json!(value),
+        }
+    }
+}
+
+type Attrs = BTreeMap<String, AttrValue>;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExportNode {
+    pub id: String,
+    pub attrs: Attrs,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExportEdge {
+    pub source: String,
+    pub target: String,
+    pub attrs: Attrs,
+}
+
+/// 与具体数据源无关的中间图结构
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ExportGraph {
+    pub name: String,
+    pub nodes: Vec<ExportNode>,
+    pub edges: Vec<ExportEdge>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GraphExportFormat {
+    Dot,
+    GraphMl,
+    Cytoscape,
+}
+
+impl GraphExportFormat {
+    pub const ALL: [GraphExportFormat; 3] = [
+        GraphExportFormat::Dot,
+        GraphExportFormat::GraphMl,
+        GraphExportFormat::Cytoscape,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            GraphExportFormat::Dot => "DOT",
+            GraphExportFormat::GraphMl => "GraphML",
+            GraphExportFormat::Cytoscape => "Cytoscape JSON",
+        }
+    }
+
+    fn extension(&self) -> &'static str {
+        match self {
+            GraphExportFormat::Dot => "dot",
+            GraphExportFormat::GraphMl => "graphml",
+            GraphExportFormat::Cytoscape => "cyjs.json",
+        }
+    }
+
+    fn slug(&self) -> &'static str {
+        match self {
+            GraphExportFormat::Dot => "dot",
+            GraphExportFormat::GraphMl => "graphml",
+            GraphExportFormat::Cytoscape => "cytoscape",
+        }
+    }
+
+    pub fn render(&self, graph: &ExportGraph) -> String {
+        match self {
+            GraphExportFormat::Dot => to_dot(graph),
+            GraphExportFormat::GraphMl => to_graphml(graph),
+            GraphExportFormat::Cytoscape => to_cytoscape(graph),
+        }
+    }
+}
+
+fn put_str(attrs: &mut Attrs, key: &str, value: Option<impl Into<String>>) {
+    if let Some(value) = value {
+        attrs.insert(key.to_string(), AttrValue::Str(value.into()));
+    }
+}
+
+fn put_int(attrs: &mut Attrs, key: &str, value: Option<i64>) {
+    if let Some(value) = value {
+        attrs.insert(key.to_string(), AttrValue::Int(value));
+    }
+}
+
+fn put_float(attrs: &mut Attrs, key: &str, value: Option<f64>) {
+    if let Some(value) = value {
+        attrs.insert(key.to_string(), AttrValue::Float(value));
+    }
+}
+
+/// 枚举优先使用序列化名（与后端一致），失败时退回 Debug
+fn enum_name<T: Serialize + Debug>(value: &T) -> String {
+    match serde_json::to_value(value) {
+        Ok(Value::String(name)) => name,
+        _ => format!("{value:?}"),
+    }
+}
+
+fn json_attr(value: &Value) -> AttrValue {
+    match value {
+        Value::String(text) => AttrValue::Str(text.clone()),
+        Value::Bool(flag) => AttrValue::Bool(*flag),
+        Value::Number(number) => number
+            .as_i64()
+            .map(AttrValue::Int)
+            .or_else(|| number.as_f64().map(AttrValue::Float))
+            .unwrap_or_else(|| AttrValue::Str(number.to_string())),
+        other => AttrValue::Str(other.to_string()),
+    }
+}
+
+impl From<&CausalGraphView> for ExportGraph {
+    fn from(graph: &CausalGraphView) -> Self {
+        let nodes = graph
+            .nodes
+            .iter()
+            .map(|node| {
+                let mut attrs = Attrs::new();
+                put_str(&mut attrs, "label", node.label.clone());
+                put_str(
+                    &mut attrs,
+                    "event_type",
+                    node.event_type.as_ref().map(enum_name),
+                );
+                put_str(
+                    &mut attrs,
+                    "scenario",
+                    node.scenario.as_ref().map(enum_name),
+                );
+                put_str(&mut attrs, "summary", node.summary.clone());
+                put_int(&mut attrs, "timestamp_ms", node.timestamp_ms);
+                put_int(&mut attrs, "depth", node.depth.map(i64::from));
+                put_float(&mut attrs, "score", node.score.map(f64::from));
+                if node.event_id == graph.root_event_id {
+                    attrs.insert("is_root".into(), AttrValue::Bool(true));
+                }
+                ExportNode {
+                    id: node.event_id.to_string(),
+                    attrs,
+                }
+            })
+            .collect();
+        let edges = graph
+            .edges
+            .iter()
+            .map(|edge| {
+                let mut attrs = Attrs::new();
+                put_str(&mut attrs, "relation", edge.relation.clone());
+                ExportEdge {
+                    source: edge.from.to_string(),
+                    target: edge.to.to_string(),
+                    attrs,
+                }
+            })
+            .collect();
+        ExportGraph {
+            name: format!("causal_{}", graph.root_event_id),
+            nodes,
+            edges,
+        }
+    }
+}
+
+impl From<&GraphNodeDetail> for ExportGraph {
+    /// 以节点为中心的一跳邻域
+    fn from(detail: &GraphNodeDetail) -> Self {
+        let mut center = Attrs::new();
+        put_str(&mut center, "node_type", Some(detail.node_type.clone()));
+        put_int(&mut center, "created_at_ms", Some(detail.created_at_ms));
+        put_int(&mut center, "updated_at_ms", detail.updated_at_ms);
+        for (key, value) in detail.properties.iter() {
+            center.insert(format!("prop_{key}"), json_attr(value));
+        }
+        center.insert("is_center".into(), AttrValue::Bool(true));
+
+        let mut nodes = vec![ExportNode {
+            id: detail.node_id.clone(),
+            attrs: center,
+        }];
+        let mut edges = Vec::new();
+        let mut push_neighbor = |edge: &GraphEdgeRef, incoming: bool| {
+            if !nodes.iter().any(|node| node.id == edge.other_node_id) {
+                let mut attrs = Attrs::new();
+                put_str(&mut attrs, "node_type", Some(edge.other_node_type.clone()));
+                nodes.push(ExportNode {
+                    id: edge.other_node_id.clone(),
+                    attrs,
+                });
+            }
+            let mut attrs = Attrs::new();
+            put_str(&mut attrs, "edge_id", Some(edge.edge_id.clone()));
+            put_str(&mut attrs, "relation", Some(edge.edge_type.clone()));
+            put_float(&mut attrs, "weight", edge.weight.map(f64::from));
+            let (source, target) = if incoming {
+                (edge.other_node_id.clone(), detail.node_id.clone())
+            } else {
+                (detail.node_id.clone(), edge.other_node_id.clone())
+            };
+            edges.push(ExportEdge {
+                source,
+                target,
+                attrs,
+            });
+        };
+        for edge in detail.incoming_edges.iter() {
+            push_neighbor(edge, true);
+        }
+        for edge in detail.outgoing_edges.iter() {
+            push_neighbor(edge, false);
+        }
+
+        ExportGraph {
+            name: format!("node_{}", detail.node_id),
+            nodes,
+            edges,
+        }
+    }
+}
+
+impl From<&CausalChainResponse> for ExportGraph {
+    fn from(chain: &CausalChainResponse) -> Self {
+        let root_cause = chain.root_cause.as_ref().map(|node| node.event_id.as_str());
+        let nodes = chain
+            .nodes
+            .iter()
+            .map(|node| {
+                let mut attrs = Attrs::new();
+                put_str(&mut attrs, "event_type", Some(node.event_type.clone()));
+                put_int(&mut attrs, "timestamp_ms", Some(node.occurred_at_ms));
+                put_int(&mut attrs, "depth", Some(i64::from(node.depth)));
+                put_str(&mut attrs, "summary", node.summary.clone());
+                if node.event_id == chain.event_id {
+                    attrs.insert("is_root".into(), AttrValue::Bool(true));
+                }
+                if root_cause == Some(node.event_id.as_str()) {
+                    attrs.insert("is_root_cause".into(), AttrValue::Bool(true));
+                }
+                ExportNode {
+                    id: node.event_id.clone(),
+                    attrs,
+                }
+            })
+            .collect();
+        let edges = chain
+            .edges
+            .iter()
+            .map(|edge| {
+                let mut attrs = Attrs::new();
+                put_str(&mut attrs, "relation", Some(edge.edge_type.clone()));
+                put_float(&mut attrs, "strength", Some(f64::from(edge.strength)));
+                put_int(&mut attrs, "delay_ms", edge.delay_ms);
+                ExportEdge {
+                    source: edge.from.clone(),
+                    target: edge.to.clone(),
+                    attrs,
+                }
+            })
+            .collect();
+        ExportGraph {
+            name: format!("causal_chain_{}", chain.event_id),
+            nodes,
+            edges,
+        }
+    }
+}
+
+fn dot_escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+fn dot_attr_list(attrs: &Attrs, label: Option<&str>) -> String {
+    let mut parts: Vec<String> = Vec::new();
+    if let Some(label) = label {
+        parts.push(format!("label=\"{}\"", dot_escape(label)));
+    }
+    for (key, value) in attrs.iter() {
+        // DOT 中 label 有渲染含义，原始 label 属性已并入上方
+        if key == "label" {
+            continue;
+        }
+        parts.push(format!(
+            "\"{}\"=\"{}\"",
+            dot_escape(key),
+            dot_escape(&value.to_text())
+        ));
+    }
+    parts.join(", ")
+}
+
+pub fn to_dot(graph: &ExportGraph) -> String {
+    let mut out = format!("digraph \"{}\" {{\n", dot_escape(&graph.name));
+    out.push_str("  node [shape=box];\n");
+    for node in graph.nodes.iter() {
+        let label = match node.attrs.get("label") {
+            Some(label) => format!("#{} {}", node.id, label.to_text()),
+            None => format!("#{}", node.id),
+        };
+        out.push_str(&format!(
+            "  \"{}\" [{}];\n",
+            dot_escape(&node.id),
+            dot_attr_list(&node.attrs, Some(&label))
+        ));
+    }
+    for edge in graph.edges.iter() {
+        let label = edge.attrs.get("relation").map(AttrValue::to_text);
+        out.push_str(&format!(
+            "  \"{}\" -> \"{}\" [{}];\n",
+            dot_escape(&edge.source),
+            dot_escape(&edge.target),
+            dot_attr_list(&edge.attrs, label.as_deref())
+        ));
+    }
+    out.push_str("}\n");
+    out
+}
+
+fn xml_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// 收集某一域（node / edge）出现过的属性及其类型；类型冲突时降级为 string
+fn graphml_keys<'a>(attrs: impl Iterator<Item = &'a Attrs>) -> BTreeMap<String, &'static str> {
+    let mut keys: BTreeMap<String, &'static str> = BTreeMap::new();
+    for attr_map in attrs {
+        for (key, value) in attr_map.iter() {
+            let kind = value.graphml_type();
+            keys.entry(key.clone())
+                .and_modify(|existing| {
+                    if *existing != kind {
+                        *existing = "string";
+                    }
+                })
+                .or_insert(kind);
+        }
+    }
+    keys
+}
+
+fn graphml_data(out: &mut String, prefix: &str, attrs: &Attrs) {
+    for (key, value) in attrs.iter() {
+        out.push_str(&format!(
+            "      <data key=\"{prefix}_{}\">{}</data>\n",
+            xml_escape(key),
+            xml_escape(&value.to_text())
+        ));
+    }
+}
+
+pub fn to_graphml(graph: &ExportGraph) -> String {
+    let node_keys = graphml_keys(graph.nodes.iter().map(|node| &node.attrs));
+    let edge_keys = graphml_keys(graph.edges.iter().map(|edge| &edge.attrs));
+
+    let mut out = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    out.push_str("<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n");
+    for (domain, prefix, keys) in [("node", "n", &node_keys), ("edge", "e", &edge_keys)] {
+        for (key, kind) in keys.iter() {
+            let key = xml_escape(key);
+            out.push_str(&format!(
+                "  <key id=\"{prefix}_{key}\" for=\"{domain}\" attr.name=\"{key}\" attr.type=\"{kind}\"/>\n"
+            ));
+        }
+    }
+    out.push_str(&format!(
+        "  <graph id=\"{}\" edgedefault=\"directed\">\n",
+        xml_escape(&graph.name)
+    ));
+    for node in graph.nodes.iter() {
+        out.push_str(&format!("    <node id=\"{}\">\n", xml_escape(&node.id)));
+        graphml_data(&mut out, "n", &node.attrs);
+        out.push_str("    </node>\n");
+    }
+    for (idx, edge) in graph.edges.iter().enumerate() {
+        out.push_str(&format!(
+            "    <edge id=\"e{idx}\" source=\"{}\" target=\"{}\">\n",
+            xml_escape(&edge.source),
+            xml_escape(&edge.target)
+        ));
+        graphml_data(&mut out, "e", &edge.attrs);
+        out.push_str("    </edge>\n");
+    }
+    out.push_str("  </graph>\n</graphml>\n");
+    out
+}
+
+fn cytoscape_data(id_fields: &[(&str, &str)], attrs: &Attrs) -> Value {
+    let mut data = Map::new();
+    for (key, value) in attrs.iter() {
+        data.insert(key.clone(), value.to_json());
+    }
+    // id / source / target 为 Cytoscape 保留字段，最后写入以免被同名属性覆盖
+    for (key, value) in id_fields {
+        data.insert((*key).to_string(), json!(value));
+    }
+    json!({ "data": Value::Object(data) })
+}
+
+pub fn to_cytoscape(graph: &ExportGraph) -> String {
+    let nodes: Vec<Value> = graph
+        .nodes
+        .iter()
+        .map(|node| cytoscape_data(&[("id", &node.id)], &node.attrs))
+        .collect();
+    let edges: Vec<Value> = graph
+        .edges
+        .iter()
+        .enumerate()
+        .map(|(idx, edge)| {
+            let id = format!("e{idx}");
+            cytoscape_data(
+                &[
+                    ("id", &id),
+                    ("source", &edge.source),
+                    ("target", &edge.target),
+                ],
+                &edge.attrs,
+            )
+        })
+        .collect();
+    let document = json!({
+        "data": { "name": graph.name },
+        "elements": { "nodes": nodes, "edges": edges },
+    });
+    serde_json::to_string_pretty(&document).unwrap_or_else(|_| "{}".into())
+}
+
+#[derive(Props, Clone)]
+#[props(no_eq)]
+pub struct GraphExportMenuProps {
+    graph: ExportGraph,
+    /// 审计记录中的目标前缀，例如 `graph:causal`
+    target: String,
+}
+
+impl PartialEq for GraphExportMenuProps {
+    fn eq(&self, _other: &Self) -> bool {
+        false
+    }
+}
+
+impl Eq for GraphExportMenuProps {}
+
+/// 导出按钮组，下载走 `export_text_as_file`，同时记录审计条目
+#[component]
+pub fn GraphExportMenu(props: GraphExportMenuProps) -> Element {
+    let actions = use_app_actions();
+    let disabled = props.graph.nodes.is_empty();
+
+    rsx! {
+        div { class: "flex flex-wrap items-center gap-1 text-[11px]",
+            span { class: "text-slate-500", "导出" }
+            for format in GraphExportFormat::ALL {
+                {
+                    let actions = actions.clone();
+                    let graph = props.graph.clone();
+                    let target = format!("{}:{}", props.target, format.slug());
+                    rsx! {
+                        button {
+                            class: "rounded border border-slate-300 px-2 py-0.5 text-slate-700 hover:bg-slate-100 disabled:opacity-50",
+                            r#type: "button",
+                            disabled,
+                            onclick: move |_| {
+                                let filename = format!("{}.{}", graph.name, format.extension());
+                                let label = format!("图谱 {}", format.label());
+                                export_text_as_file(
+                                    actions.clone(),
+                                    &label,
+                                    &target,
+                                    filename,
+                                    format.render(&graph),
+                                );
+                            },
+                            {format.label()}
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
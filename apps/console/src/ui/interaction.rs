@@ -2,12 +2,15 @@
 use crate::api::ClientError;
 #[cfg(target_arch = "wasm32")]
 use crate::hooks::cycle_runner::{extract_budget_hint, extract_indices_from_details};
-use crate::hooks::cycle_runner::{use_cycle_runner, CycleTriggerParams};
+use crate::hooks::cycle_runner::{
+    is_resumable_stage, use_cycle_runner, CycleRunnerHandle, CycleTriggerParams,
+};
 #[cfg(target_arch = "wasm32")]
 use crate::models::CycleSnapshotView;
 use crate::state::{
-    use_app_actions, use_app_state, AppActions, AuditActionKind, AuditLogEntry, OperationStageKind,
-    OperationStageStatus, OperationState,
+    use_app_actions, use_app_state, AppActions, AuditActionKind, AuditLogEntry, OperationKind,
+    OperationOutcome, OperationRecord, OperationStage, OperationStageKind, OperationStageStatus,
+    OperationState,
 };
 #[cfg(target_arch = "wasm32")]
 use crate::{API_CLIENT, APP_CONFIG};
@@ -35,6 +38,7 @@ pub fn InteractionPanel() -> Element {
 
     let snapshot = state.read();
     let operation_state = snapshot.operation.clone();
+    let operation_history_state = operation_state.clone();
     let cycles_state = snapshot.ace.cycles.clone();
     let selected_cycle_state = snapshot.ace.selected_cycle_id.clone();
     let audit_entries = snapshot.audit.entries.clone();
@@ -266,14 +270,15 @@ pub fn InteractionPanel() -> Element {
 
             let note = injection_input.with(|value| value.trim().to_string());
             if note.is_empty() {
-                actions.set_operation_error("请输入注入说明".to_string());
+                actions.operation_reject(OperationKind::Injection, "请输入注入说明".to_string());
                 return;
             }
 
             let cycle_label = match injection_cycle.with(|value| value.clone()) {
                 Some(value) if !value.trim().is_empty() => value,
                 _ => {
-                    actions.set_operation_error("请选择目标周期".to_string());
+                    actions
+                        .operation_reject(OperationKind::Injection, "请选择目标周期".to_string());
                     return;
                 }
             };
@@ -281,11 +286,16 @@ pub fn InteractionPanel() -> Element {
             let cycle_id = match cycle_label.trim().parse::<u64>() {
                 Ok(value) => value,
                 Err(_) => {
-                    actions.set_operation_error("当前周期 ID 无法解析，请确认选择".to_string());
+                    actions.operation_reject(
+                        OperationKind::Injection,
+                        "当前周期 ID 无法解析，请确认选择".to_string(),
+                    );
                     return;
                 }
             };
 
+            let actions = actions.operation_begin(OperationKind::Injection);
+
             let priority_value = injection_priority.with(|value| value.clone());
             let author_role_value = injection_author_role.with(|value| value.clone());
             let seq = injection_seq.with(|value| *value);
@@ -334,7 +344,6 @@ pub fn InteractionPanel() -> Element {
                     return;
                 };
 
-                actions_clone.operation_stage_reset();
                 actions_clone.operation_stage_start(
                     OperationStageKind::HitlSubmit,
                     format!("提交 HITL 注入至周期 {cycle_label_for_async}"),
@@ -466,7 +475,11 @@ pub fn InteractionPanel() -> Element {
             header { class: "flex flex-col gap-1",
                 h2 { class: "text-lg font-semibold text-slate-900", "人机交互入口" }
                 p { class: "text-xs text-slate-500", "快速模拟对话事件与 HITL 注入，验证前后端流程。" }
-                OperationStatus { status: operation_state, actions: actions.clone() }
+                OperationStatus {
+                    status: operation_state,
+                    actions: actions.clone(),
+                    runner: cycle_runner.clone(),
+                }
             }
 
             div { class: "grid gap-4 md:grid-cols-2",
@@ -691,6 +704,7 @@ pub fn InteractionPanel() -> Element {
                     }
                 }
             }
+            OperationHistoryPanel { status: operation_history_state, actions: actions.clone() }
             AuditLogPanel {
                 entries: audit_entries.clone(),
                 actions: actions.clone(),
@@ -705,6 +719,7 @@ pub fn InteractionPanel() -> Element {
 struct OperationStatusProps {
     status: OperationState,
     actions: AppActions,
+    runner: CycleRunnerHandle,
 }
 
 impl PartialEq for OperationStatusProps {
@@ -719,21 +734,14 @@ impl Eq for OperationStatusProps {}
 fn OperationStatus(props: OperationStatusProps) -> Element {
     let status = props.status.clone();
     let actions = props.actions.clone();
-
-    let mut stage_order = if !status.stages.is_empty()
-        && status
-            .stages
-            .iter()
-            .all(|stage| stage.kind == OperationStageKind::HitlSubmit)
-    {
-        vec![OperationStageKind::HitlSubmit]
-    } else {
-        vec![
-            OperationStageKind::TriggerSubmit,
-            OperationStageKind::StreamAwait,
-            OperationStageKind::SnapshotRefresh,
-            OperationStageKind::OutboxReady,
-        ]
+    let runner = props.runner.clone();
+    let runner_busy = *runner.is_running.read();
+    let can_resume = status.last_cycle_id.is_some() && !runner_busy;
+
+    let mut stage_order = match status.current_kind.as_ref() {
+        Some(kind) => kind.expected_stages(),
+        None if status.stages.is_empty() => OperationKind::Trigger.expected_stages(),
+        None => Vec::new(),
     };
 
     for stage in status.stages.iter() {
@@ -770,6 +778,7 @@ fn OperationStatus(props: OperationStatusProps) -> Element {
                 .unwrap_or(false);
 
             StageView {
+                kind,
                 label,
                 status: status_value,
                 detail,
@@ -840,6 +849,23 @@ fn OperationStatus(props: OperationStatusProps) -> Element {
     let has_indices = !status.last_indices_used.is_empty();
     let has_observability = has_indices || !observability_rows.is_empty();
 
+    // 被新操作顶替但仍在进行的操作，结束后自动归档到历史
+    let background_rows: Vec<String> = status
+        .background
+        .iter()
+        .map(|operation| {
+            let kind = operation
+                .current_kind
+                .as_ref()
+                .map(|kind| kind.label())
+                .unwrap_or("操作");
+            match operation.context.as_ref() {
+                Some(context) => format!("{kind} · {context}"),
+                None => kind.to_string(),
+            }
+        })
+        .collect();
+
     let actions_clear = actions.clone();
 
     rsx! {
@@ -866,12 +892,39 @@ fn OperationStatus(props: OperationStatusProps) -> Element {
                 p { class: "text-[11px] text-slate-500 italic", "尚未执行操作" }
             }
 
+            if !background_rows.is_empty() {
+                div { class: "space-y-1 rounded border border-slate-200 bg-slate-50 p-2",
+                    span { class: "text-[11px] font-semibold text-slate-500", "后台进行中" }
+                    for row in background_rows.iter() {
+                        p { class: "text-[11px] text-slate-600", "{row}" }
+                    }
+                }
+            }
+
             if !stage_views.is_empty() {
                 div { class: "space-y-2",
                     span { class: "text-[11px] font-semibold uppercase tracking-wide text-slate-500", "阶段进度" }
                     div { class: "grid gap-2 md:grid-cols-2",
                         for view in stage_views.iter() {
-                            { render_stage_view(view) }
+                            {
+                                let resumable = can_resume
+                                    && view.status == OperationStageStatus::Failed
+                                    && is_resumable_stage(&view.kind);
+                                let stage_kind = view.kind.clone();
+                                let runner_resume = runner.clone();
+                                rsx! {
+                                    div { class: "space-y-1",
+                                        { render_stage_view(view) }
+                                        if resumable {
+                                            button {
+                                                class: "rounded border border-indigo-300 px-2 py-0.5 text-[11px] text-indigo-600 hover:bg-indigo-50",
+                                                onclick: move |_| runner_resume.resume_stage(stage_kind.clone()),
+                                                "{resume_stage_label(&view.kind)}"
+                                            }
+                                        }
+                                    }
+                                }
+                            }
                         }
                     }
                 }
@@ -917,6 +970,7 @@ fn OperationStatus(props: OperationStatusProps) -> Element {
 
 #[derive(Clone)]
 struct StageView {
+    kind: OperationStageKind,
     label: String,
     status: OperationStageStatus,
     detail: Option<String>,
@@ -970,10 +1024,23 @@ fn default_stage_label(kind: &OperationStageKind) -> &'static str {
         OperationStageKind::OutboxReady => "Outbox 拉取",
         OperationStageKind::HitlSubmit => "HITL 注入",
         OperationStageKind::ContextSync => "上下文同步",
+        OperationStageKind::AutonomousSubmit => "自主延续请求",
+        OperationStageKind::IndexSubmit => "索引提交",
+        OperationStageKind::CompactionSubmit => "压缩提交",
         OperationStageKind::Unknown => "其他阶段",
     }
 }
 
+fn resume_stage_label(kind: &OperationStageKind) -> &'static str {
+    match kind {
+        OperationStageKind::StreamAwait => "重新订阅流",
+        OperationStageKind::SnapshotRefresh => "重新刷新",
+        OperationStageKind::ContextSync => "重新同步上下文",
+        OperationStageKind::OutboxReady => "重新拉取 Outbox",
+        _ => "重试",
+    }
+}
+
 fn stage_status_text(status: &OperationStageStatus) -> &'static str {
     match status {
         OperationStageStatus::Pending => "待开始",
@@ -1011,6 +1078,241 @@ fn stage_container_class(status: &OperationStageStatus, is_current: bool) -> Str
         highlight, base
     )
 }
+fn stage_view_from(stage: &OperationStage, is_current: bool) -> StageView {
+    let label = if stage.label.trim().is_empty() {
+        default_stage_label(&stage.kind).to_string()
+    } else {
+        stage.label.trim().to_string()
+    };
+
+    StageView {
+        kind: stage.kind.clone(),
+        label,
+        status: stage.status.clone(),
+        detail: stage.detail.clone(),
+        started_at: stage.started_at.clone(),
+        finished_at: stage.finished_at.clone(),
+        duration_ms: stage.duration_ms,
+        is_current,
+    }
+}
+
+#[derive(Props, Clone)]
+#[props(no_eq)]
+struct OperationHistoryPanelProps {
+    status: OperationState,
+    actions: AppActions,
+}
+
+impl PartialEq for OperationHistoryPanelProps {
+    fn eq(&self, _other: &Self) -> bool {
+        false
+    }
+}
+
+impl Eq for OperationHistoryPanelProps {}
+
+#[component]
+fn OperationHistoryPanel(props: OperationHistoryPanelProps) -> Element {
+    let actions = props.actions.clone();
+    let mut expanded = use_signal(|| Option::<u64>::None);
+    let mut kind_filter = use_signal(|| Option::<OperationKind>::None);
+
+    let stats = props.status.stage_duration_stats(&[
+        OperationStageKind::TriggerSubmit,
+        OperationStageKind::StreamAwait,
+        OperationStageKind::SnapshotRefresh,
+    ]);
+
+    let filter_value = kind_filter.read().clone();
+    let records: Vec<OperationRecord> = props
+        .status
+        .history
+        .iter()
+        .filter(|record| {
+            filter_value
+                .as_ref()
+                .map(|kind| &record.kind == kind)
+                .unwrap_or(true)
+        })
+        .cloned()
+        .collect();
+    let total = props.status.history.len();
+    let expanded_id = *expanded.read();
+    let filter_str = filter_value
+        .as_ref()
+        .map(operation_kind_value)
+        .unwrap_or("all");
+
+    rsx! {
+        section { class: "space-y-3",
+            header { class: "flex flex-wrap items-center justify-between gap-2",
+                div { class: "space-y-1",
+                    h3 { class: "text-sm font-semibold text-slate-900", "操作历史" }
+                    span { class: "text-[11px] text-slate-500", "共 {total} 条记录（保留最近 50 条）" }
+                }
+                div { class: "flex items-center gap-2",
+                    select {
+                        class: "rounded border border-slate-300 px-2 py-1 text-[11px] text-slate-600",
+                        value: "{filter_str}",
+                        onchange: move |evt| kind_filter.set(parse_operation_kind(&evt.value())),
+                        option { value: "all", "全部类型" }
+                        for kind in ALL_OPERATION_KINDS.iter() {
+                            option { value: operation_kind_value(kind), "{kind.label()}" }
+                        }
+                    }
+                    button {
+                        class: "rounded border border-slate-300 px-3 py-1 text-[11px] text-slate-600 hover:bg-slate-100",
+                        onclick: move |_| actions.clone().clear_operation_history(),
+                        "清空"
+                    }
+                }
+            }
+
+            div { class: "grid gap-2 md:grid-cols-3",
+                for stat in stats.iter() {
+                    div { class: "rounded border border-slate-200 bg-slate-50 px-3 py-2 text-[11px] text-slate-600 space-y-1",
+                        span { class: "font-semibold text-slate-800", "{default_stage_label(&stat.kind)}" }
+                        p { "样本 {stat.samples}" }
+                        p { class: "font-mono",
+                            "p50 {format_optional_duration(stat.p50_ms)} · p95 {format_optional_duration(stat.p95_ms)}"
+                        }
+                    }
+                }
+            }
+
+            if records.is_empty() {
+                p { class: "text-xs text-slate-500 italic", "暂无已归档的操作" }
+            } else {
+                ul { class: "space-y-2",
+                    for record in records.into_iter().take(20) {
+                        {
+                            let record_id = record.id;
+                            let is_expanded = expanded_id == Some(record_id);
+                            let title = record
+                                .context
+                                .clone()
+                                .unwrap_or_else(|| record.kind.label().to_string());
+                            let mut meta: Vec<String> = Vec::new();
+                            if let Some(cycle) = record.cycle_id.as_ref() {
+                                meta.push(format!("周期 {cycle}"));
+                            }
+                            if let Some(code) = record.http_status {
+                                meta.push(format!("HTTP {code}"));
+                            }
+                            if let Some(trace) = record.trace_id.as_ref() {
+                                meta.push(format!("trace_id {trace}"));
+                            }
+                            if let Some(total) = record.total_elapsed_ms {
+                                meta.push(format!("耗时 {}", humanize_duration(total)));
+                            }
+                            let meta_line = meta.join(" · ");
+                            let stage_views: Vec<StageView> = record
+                                .stages
+                                .iter()
+                                .map(|stage| stage_view_from(stage, false))
+                                .collect();
+                            rsx! {
+                                li { key: "{record_id}", class: "rounded border border-slate-200 bg-white p-3 shadow-sm text-xs text-slate-600 space-y-1",
+                                    div { class: "flex flex-wrap items-center justify-between gap-2",
+                                        div { class: "flex items-center gap-2",
+                                            span { class: "rounded bg-slate-100 px-2 py-0.5 text-[11px] text-slate-700", "{record.kind.label()}" }
+                                            span { class: format!("rounded px-2 py-0.5 text-[11px] font-medium {}", operation_outcome_badge_class(&record.outcome)), "{operation_outcome_label(&record.outcome)}" }
+                                            span { class: "font-semibold text-slate-800", "{title}" }
+                                        }
+                                        button {
+                                            class: "text-[11px] text-blue-600 hover:underline",
+                                            onclick: move |_| {
+                                                if is_expanded {
+                                                    expanded.set(None);
+                                                } else {
+                                                    expanded.set(Some(record_id));
+                                                }
+                                            },
+                                            if is_expanded { "收起阶段" } else { "查看阶段" }
+                                        }
+                                    }
+                                    if let Some(message) = record.message.as_ref() {
+                                        p { class: "text-[11px] text-slate-600", "{message}" }
+                                    }
+                                    if !meta_line.is_empty() {
+                                        p { class: "font-mono text-[11px] text-slate-500 break-all", "{meta_line}" }
+                                    }
+                                    p { class: "text-[11px] text-slate-400",
+                                        "#{record.id} · {record.triggered_at.clone().unwrap_or_else(|| record.archived_at.clone())}"
+                                    }
+                                    if is_expanded {
+                                        if stage_views.is_empty() {
+                                            p { class: "text-[11px] text-slate-500 italic", "该操作未记录阶段" }
+                                        } else {
+                                            div { class: "grid gap-2 md:grid-cols-2",
+                                                for view in stage_views.iter() {
+                                                    { render_stage_view(view) }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+const ALL_OPERATION_KINDS: [OperationKind; 7] = [
+    OperationKind::Trigger,
+    OperationKind::Injection,
+    OperationKind::AutonomousStart,
+    OperationKind::AutonomousStop,
+    OperationKind::AutonomousAdjust,
+    OperationKind::Index,
+    OperationKind::Compaction,
+];
+
+fn operation_kind_value(kind: &OperationKind) -> &'static str {
+    match kind {
+        OperationKind::Trigger => "trigger",
+        OperationKind::Injection => "injection",
+        OperationKind::AutonomousStart => "autonomous_start",
+        OperationKind::AutonomousStop => "autonomous_stop",
+        OperationKind::AutonomousAdjust => "autonomous_adjust",
+        OperationKind::Index => "index",
+        OperationKind::Compaction => "compaction",
+    }
+}
+
+fn parse_operation_kind(value: &str) -> Option<OperationKind> {
+    ALL_OPERATION_KINDS
+        .iter()
+        .find(|kind| operation_kind_value(kind) == value)
+        .cloned()
+}
+
+fn operation_outcome_label(outcome: &OperationOutcome) -> &'static str {
+    match outcome {
+        OperationOutcome::Succeeded => "成功",
+        OperationOutcome::Failed => "失败",
+        OperationOutcome::Interrupted => "未完成",
+    }
+}
+
+fn operation_outcome_badge_class(outcome: &OperationOutcome) -> &'static str {
+    match outcome {
+        OperationOutcome::Succeeded => "bg-emerald-100 text-emerald-700",
+        OperationOutcome::Failed => "bg-red-100 text-red-700",
+        OperationOutcome::Interrupted => "bg-amber-100 text-amber-800",
+    }
+}
+
+fn format_optional_duration(duration_ms: Option<u64>) -> String {
+    duration_ms
+        .map(humanize_duration)
+        .unwrap_or_else(|| "-".to_string())
+}
+
 #[derive(Props, Clone)]
 #[props(no_eq)]
 struct AuditLogPanelProps {
@@ -1076,6 +1378,7 @@ fn audit_action_badge_class(action: &AuditActionKind) -> &'static str {
     match action {
         AuditActionKind::Copy => "bg-amber-100 text-amber-800",
         AuditActionKind::Export => "bg-indigo-100 text-indigo-700",
+        AuditActionKind::Terminate => "bg-red-100 text-red-700",
     }
 }
 
@@ -1083,6 +1386,7 @@ fn audit_action_badge_label(action: &AuditActionKind) -> &'static str {
     match action {
         AuditActionKind::Copy => "复制",
         AuditActionKind::Export => "导出",
+        AuditActionKind::Terminate => "终止",
     }
 }
 
@@ -1175,12 +1479,12 @@ const AUTHOR_ROLE_OPTIONS: &[AuthorRoleOption] = &[
 ];
 
 #[derive(Clone, Copy)]
-struct ScenarioOption {
-    value: &'static str,
-    label: &'static str,
+pub(crate) struct ScenarioOption {
+    pub(crate) value: &'static str,
+    pub(crate) label: &'static str,
 }
 
-const SCENARIO_OPTIONS: &[ScenarioOption] = &[
+pub(crate) const SCENARIO_OPTIONS: &[ScenarioOption] = &[
     ScenarioOption {
         value: "human_to_human",
         label: "人类 ↔ 人类",
@@ -1219,7 +1523,7 @@ const SCENARIO_OPTIONS: &[ScenarioOption] = &[
     },
 ];
 
-fn parse_scenario(value: &str) -> Option<ConversationScenario> {
+pub(crate) fn parse_scenario(value: &str) -> Option<ConversationScenario> {
     match value {
         "human_to_human" => Some(ConversationScenario::HumanToHuman),
         "human_group" => Some(ConversationScenario::HumanGroup),
@@ -1234,7 +1538,7 @@ fn parse_scenario(value: &str) -> Option<ConversationScenario> {
     }
 }
 
-fn scenario_value(value: &ConversationScenario) -> &'static str {
+pub(crate) fn scenario_value(value: &ConversationScenario) -> &'static str {
     match value {
         ConversationScenario::HumanToHuman => "human_to_human",
         ConversationScenario::HumanGroup => "human_group",
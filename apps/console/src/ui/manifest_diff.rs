@@ -0,0 +1,456 @@
+use crate::models::{ContextBundleView, ManifestDigestRecord};
+use crate::ui::context::format_degradation_label;
+use crate::ui::cycle_diff::{diff_json, render_json_diff_entries};
+use dioxus::prelude::*;
+use std::collections::{BTreeMap, BTreeSet};
+
+#[derive(Clone, Copy, PartialEq)]
+enum ChangeKind {
+    Added,
+    Removed,
+    Changed,
+    Unchanged,
+}
+
+impl ChangeKind {
+    fn row_class(&self) -> &'static str {
+        match self {
+            ChangeKind::Added => "bg-emerald-50 text-emerald-700",
+            ChangeKind::Removed => "bg-red-50 text-red-700",
+            ChangeKind::Changed => "bg-amber-50 text-amber-800",
+            ChangeKind::Unchanged => "bg-white text-slate-600",
+        }
+    }
+
+    fn marker(&self) -> &'static str {
+        match self {
+            ChangeKind::Added => "+",
+            ChangeKind::Removed => "-",
+            ChangeKind::Changed => "~",
+            ChangeKind::Unchanged => "=",
+        }
+    }
+}
+
+#[derive(Clone)]
+struct ItemChange {
+    ci_id: String,
+    kind: ChangeKind,
+    left_level: Option<String>,
+    right_level: Option<String>,
+    left_tokens: Option<u32>,
+    right_tokens: Option<u32>,
+}
+
+#[derive(Clone)]
+struct PartitionDiff {
+    partition: String,
+    kind: ChangeKind,
+    left_tokens: u32,
+    right_tokens: u32,
+    items: Vec<ItemChange>,
+}
+
+impl PartitionDiff {
+    fn token_delta(&self) -> i64 {
+        self.right_tokens as i64 - self.left_tokens as i64
+    }
+}
+
+struct BundleDiff {
+    partitions: Vec<PartitionDiff>,
+    left_target: Option<u32>,
+    right_target: Option<u32>,
+    left_projected: Option<u32>,
+    right_projected: Option<u32>,
+    indices_added: Vec<String>,
+    indices_removed: Vec<String>,
+    query_hash: Option<(String, String)>,
+    degradation: Option<(String, String)>,
+}
+
+fn bundle_degradation(bundle: &ContextBundleView) -> String {
+    bundle
+        .degradation_reason
+        .as_ref()
+        .or_else(|| bundle.explain.degradation_reason.as_ref())
+        .map(|code| format_degradation_label(code))
+        .unwrap_or_else(|| "正常".into())
+}
+
+/// ci_id → (summary_level, tokens)
+type PartitionItems = BTreeMap<String, (Option<String>, u32)>;
+
+fn partition_items(bundle: &ContextBundleView) -> BTreeMap<String, PartitionItems> {
+    let mut partitions: BTreeMap<String, PartitionItems> = BTreeMap::new();
+    for segment in bundle.segments.iter() {
+        let entry = partitions.entry(segment.partition.clone()).or_default();
+        for item in segment.items.iter() {
+            entry.insert(
+                item.ci_id.clone(),
+                (item.summary_level.clone(), item.tokens),
+            );
+        }
+    }
+    partitions
+}
+
+fn diff_partition_items(
+    left: Option<&PartitionItems>,
+    right: Option<&PartitionItems>,
+) -> Vec<ItemChange> {
+    let empty = BTreeMap::new();
+    let left = left.unwrap_or(&empty);
+    let right = right.unwrap_or(&empty);
+    let ids: BTreeSet<&String> = left.keys().chain(right.keys()).collect();
+
+    ids.into_iter()
+        .filter_map(|ci_id| {
+            let l = left.get(ci_id);
+            let r = right.get(ci_id);
+            let kind = match (l, r) {
+                (None, Some(_)) => ChangeKind::Added,
+                (Some(_), None) => ChangeKind::Removed,
+                (Some(lv), Some(rv)) if lv.0 != rv.0 || lv.1 != rv.1 => ChangeKind::Changed,
+                _ => return None,
+            };
+            Some(ItemChange {
+                ci_id: ci_id.clone(),
+                kind,
+                left_level: l.and_then(|(level, _)| level.clone()),
+                right_level: r.and_then(|(level, _)| level.clone()),
+                left_tokens: l.map(|(_, tokens)| *tokens),
+                right_tokens: r.map(|(_, tokens)| *tokens),
+            })
+        })
+        .collect()
+}
+
+fn diff_bundles(left: &ContextBundleView, right: &ContextBundleView) -> BundleDiff {
+    let left_parts = partition_items(left);
+    let right_parts = partition_items(right);
+    let names: BTreeSet<&String> = left_parts.keys().chain(right_parts.keys()).collect();
+
+    let partitions = names
+        .into_iter()
+        .map(|name| {
+            let l = left_parts.get(name);
+            let r = right_parts.get(name);
+            let items = diff_partition_items(l, r);
+            let left_tokens = l
+                .map(|items| items.values().map(|(_, t)| *t).sum())
+                .unwrap_or(0);
+            let right_tokens = r
+                .map(|items| items.values().map(|(_, t)| *t).sum())
+                .unwrap_or(0);
+            let kind = match (l, r) {
+                (None, Some(_)) => ChangeKind::Added,
+                (Some(_), None) => ChangeKind::Removed,
+                _ if !items.is_empty() => ChangeKind::Changed,
+                _ => ChangeKind::Unchanged,
+            };
+            PartitionDiff {
+                partition: name.clone(),
+                kind,
+                left_tokens,
+                right_tokens,
+                items,
+            }
+        })
+        .collect();
+
+    let left_indices: BTreeSet<&String> = left.explain.indices_used.iter().collect();
+    let right_indices: BTreeSet<&String> = right.explain.indices_used.iter().collect();
+    let indices_added = right_indices
+        .difference(&left_indices)
+        .map(|idx| (*idx).clone())
+        .collect();
+    let indices_removed = left_indices
+        .difference(&right_indices)
+        .map(|idx| (*idx).clone())
+        .collect();
+
+    let query_hash = (left.explain.query_hash != right.explain.query_hash).then(|| {
+        (
+            left.explain
+                .query_hash
+                .clone()
+                .unwrap_or_else(|| "-".into()),
+            right
+                .explain
+                .query_hash
+                .clone()
+                .unwrap_or_else(|| "-".into()),
+        )
+    });
+    let left_degrade = bundle_degradation(left);
+    let right_degrade = bundle_degradation(right);
+    let degradation = (left_degrade != right_degrade).then_some((left_degrade, right_degrade));
+
+    BundleDiff {
+        partitions,
+        left_target: left.budget.as_ref().map(|budget| budget.target_tokens),
+        right_target: right.budget.as_ref().map(|budget| budget.target_tokens),
+        left_projected: left.budget.as_ref().map(|budget| budget.projected_tokens),
+        right_projected: right.budget.as_ref().map(|budget| budget.projected_tokens),
+        indices_added,
+        indices_removed,
+        query_hash,
+        degradation,
+    }
+}
+
+fn format_delta(delta: i64) -> String {
+    if delta > 0 {
+        format!("+{delta}")
+    } else {
+        delta.to_string()
+    }
+}
+
+fn share_of_target(tokens: u32, target: Option<u32>) -> String {
+    match target {
+        Some(target) if target > 0 => format!("{:.1}%", tokens as f64 / target as f64 * 100.0),
+        _ => "--".into(),
+    }
+}
+
+fn option_label(value: Option<u32>) -> String {
+    value.map(|v| v.to_string()).unwrap_or_else(|| "-".into())
+}
+
+fn record_label(record: &ManifestDigestRecord) -> String {
+    let short: String = record.manifest_digest.chars().take(12).collect();
+    if record.cycle_ids.is_empty() {
+        short
+    } else {
+        format!("{short} · 周期 {}", record.cycle_ids.join(","))
+    }
+}
+
+#[derive(Props, Clone)]
+#[props(no_eq)]
+pub struct ManifestDiffPanelProps {
+    history: Vec<ManifestDigestRecord>,
+}
+
+impl PartialEq for ManifestDiffPanelProps {
+    fn eq(&self, _other: &Self) -> bool {
+        false
+    }
+}
+
+impl Eq for ManifestDiffPanelProps {}
+
+#[component]
+pub fn ManifestDiffPanel(props: ManifestDiffPanelProps) -> Element {
+    let mut left_choice = use_signal(|| Option::<String>::None);
+    let mut right_choice = use_signal(|| Option::<String>::None);
+
+    let history = &props.history;
+    if history.len() < 2 {
+        return rsx! {};
+    }
+
+    // manifest_history 按时间倒序，默认比较上一版本 → 最新版本
+    let find = |digest: Option<String>| {
+        digest.and_then(|digest| {
+            history
+                .iter()
+                .find(|record| record.manifest_digest == digest)
+        })
+    };
+    let right = find(right_choice.read().clone()).unwrap_or(&history[0]);
+    let left = find(left_choice.read().clone())
+        .filter(|record| record.manifest_digest != right.manifest_digest)
+        .or_else(|| {
+            history
+                .iter()
+                .find(|record| record.manifest_digest != right.manifest_digest)
+        })
+        .unwrap_or(&history[1]);
+    let left_digest = left.manifest_digest.clone();
+    let right_digest = right.manifest_digest.clone();
+
+    let body = match (left.bundle.as_ref(), right.bundle.as_ref()) {
+        (Some(l), Some(r)) => render_bundle_diff(&diff_bundles(l, r)),
+        _ => {
+            // 缺少解析后的 bundle 时退化为原始 manifest 的结构化比较
+            let null = serde_json::Value::Null;
+            let entries = diff_json(
+                left.raw_manifest.as_ref().unwrap_or(&null),
+                right.raw_manifest.as_ref().unwrap_or(&null),
+            );
+            rsx! {
+                div { class: "space-y-2",
+                    p { class: "text-[11px] text-slate-500 italic", "至少一个版本缺少 ContextBundle，改为比较原始 Manifest" }
+                    { render_json_diff_entries(&entries) }
+                }
+            }
+        }
+    };
+
+    rsx! {
+        div { class: "rounded-lg border border-slate-200 bg-white p-4 shadow-sm space-y-3 text-xs text-slate-600",
+            header { class: "flex flex-wrap items-center justify-between gap-2",
+                h3 { class: "text-sm font-semibold text-slate-800", "Manifest 版本对比" }
+                div { class: "flex flex-wrap items-center gap-2 text-[11px]",
+                    select {
+                        class: "rounded border border-slate-300 px-2 py-1 text-[11px] text-slate-600",
+                        value: "{left_digest}",
+                        onchange: move |evt| left_choice.set(Some(evt.value())),
+                        for record in history.iter() {
+                            option { value: "{record.manifest_digest}", "{record_label(record)}" }
+                        }
+                    }
+                    span { "→" }
+                    select {
+                        class: "rounded border border-slate-300 px-2 py-1 text-[11px] text-slate-600",
+                        value: "{right_digest}",
+                        onchange: move |evt| right_choice.set(Some(evt.value())),
+                        for record in history.iter() {
+                            option { value: "{record.manifest_digest}", "{record_label(record)}" }
+                        }
+                    }
+                }
+            }
+            if left_digest == right_digest {
+                p { class: "text-[11px] text-slate-500 italic", "请选择两个不同的 Manifest 版本" }
+            } else {
+                {body}
+            }
+        }
+    }
+}
+
+fn render_bundle_diff(diff: &BundleDiff) -> Element {
+    let changed_partitions: Vec<&PartitionDiff> = diff
+        .partitions
+        .iter()
+        .filter(|partition| partition.kind != ChangeKind::Unchanged)
+        .collect();
+    let left_total: u32 = diff.partitions.iter().map(|p| p.left_tokens).sum();
+    let right_total: u32 = diff.partitions.iter().map(|p| p.right_tokens).sum();
+    let total_delta = format_delta(right_total as i64 - left_total as i64);
+    let target_label = if diff.left_target == diff.right_target {
+        option_label(diff.right_target)
+    } else {
+        format!(
+            "{} → {}",
+            option_label(diff.left_target),
+            option_label(diff.right_target)
+        )
+    };
+    let projected_label = format!(
+        "{} → {}",
+        option_label(diff.left_projected),
+        option_label(diff.right_projected)
+    );
+
+    rsx! {
+        div { class: "space-y-3",
+            div { class: "flex flex-wrap gap-2 text-[11px]",
+                span { class: "rounded bg-slate-100 px-2 py-0.5 text-slate-700", "Tokens {left_total} → {right_total} ({total_delta})" }
+                span { class: "rounded bg-emerald-100 px-2 py-0.5 text-emerald-700", "目标 {target_label}" }
+                span { class: "rounded bg-emerald-50 px-2 py-0.5 text-emerald-700", "预计使用 {projected_label}" }
+            }
+
+            div { class: "space-y-1",
+                h4 { class: "text-[11px] font-semibold uppercase tracking-wide text-slate-500", "分区 Token 变化" }
+                table { class: "w-full text-left text-[11px]",
+                    thead {
+                        tr { class: "text-slate-500",
+                            th { class: "py-1", "分区" }
+                            th { class: "py-1", "Tokens" }
+                            th { class: "py-1", "变化" }
+                            th { class: "py-1", "占目标" }
+                        }
+                    }
+                    tbody {
+                        for partition in diff.partitions.iter() {
+                            tr { class: format!("border-t border-slate-100 {}", partition.kind.row_class()),
+                                td { class: "py-1 font-mono", "{partition.kind.marker()} {partition.partition}" }
+                                td { class: "py-1 font-mono", "{partition.left_tokens} → {partition.right_tokens}" }
+                                td { class: "py-1 font-mono", "{format_delta(partition.token_delta())}" }
+                                td { class: "py-1 font-mono",
+                                    "{share_of_target(partition.left_tokens, diff.left_target)} → {share_of_target(partition.right_tokens, diff.right_target)}"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            if changed_partitions.is_empty() {
+                p { class: "text-[11px] text-slate-500 italic", "分区条目无变化" }
+            }
+            for partition in changed_partitions.iter() {
+                div { class: "rounded border border-slate-100 bg-slate-50 p-3 space-y-1",
+                    h5 { class: "text-[11px] font-semibold text-slate-700", "分区 {partition.partition} · {partition.items.len()} 项变化" }
+                    ul { class: "space-y-1 font-mono text-[11px]",
+                        for item in partition.items.iter() {
+                            {
+                                let level = if item.left_level != item.right_level {
+                                    format!(
+                                        "汇总层级 {} → {}",
+                                        item.left_level.clone().unwrap_or_else(|| "-".into()),
+                                        item.right_level.clone().unwrap_or_else(|| "-".into())
+                                    )
+                                } else {
+                                    String::new()
+                                };
+                                let tokens = format!(
+                                    "Tokens {} → {}",
+                                    option_label(item.left_tokens),
+                                    option_label(item.right_tokens)
+                                );
+                                rsx! {
+                                    li { class: format!("rounded px-2 py-1 break-all {}", item.kind.row_class()),
+                                        span { class: "font-semibold", "{item.kind.marker()} {item.ci_id}" }
+                                        span { class: "ml-2", "{tokens}" }
+                                        if !level.is_empty() {
+                                            span { class: "ml-2", "{level}" }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            div { class: "rounded border border-slate-100 bg-slate-50 p-3 space-y-1 text-[11px]",
+                h4 { class: "font-semibold text-slate-700", "Explain 变化" }
+                if diff.indices_added.is_empty() && diff.indices_removed.is_empty() {
+                    p { class: "text-slate-500", "indices_used 无变化" }
+                }
+                if !diff.indices_added.is_empty() {
+                    div { class: "flex flex-wrap gap-1",
+                        span { class: "text-slate-500", "新增索引" }
+                        for idx in diff.indices_added.iter() {
+                            span { class: "rounded bg-emerald-100 px-2 py-0.5 text-emerald-700", "{idx}" }
+                        }
+                    }
+                }
+                if !diff.indices_removed.is_empty() {
+                    div { class: "flex flex-wrap gap-1",
+                        span { class: "text-slate-500", "移除索引" }
+                        for idx in diff.indices_removed.iter() {
+                            span { class: "rounded bg-red-100 px-2 py-0.5 text-red-700", "{idx}" }
+                        }
+                    }
+                }
+                if let Some((left, right)) = diff.query_hash.as_ref() {
+                    p { class: "font-mono break-all", "query_hash: {left} → {right}" }
+                } else {
+                    p { class: "text-slate-500", "query_hash 无变化" }
+                }
+                if let Some((left, right)) = diff.degradation.as_ref() {
+                    p { class: "text-amber-700", "降级状态: {left} → {right}" }
+                } else {
+                    p { class: "text-slate-500", "降级状态无变化" }
+                }
+            }
+        }
+    }
+}
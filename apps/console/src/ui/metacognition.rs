@@ -2,23 +2,203 @@
 //!
 //! 展示元认知分析结果
 
+use std::collections::{HashMap, HashSet};
+
 use dioxus::prelude::*;
 
-use crate::hooks::metacognition::use_metacognition_analysis;
-use crate::models::{AnalysisResultResponse, InsightResponse};
+use crate::hooks::metacognition::{use_causal_chain, use_metacognition_analysis, AnalysisRun};
+use crate::models::{
+    AnalysisResultResponse, CausalChainResponse, CausalEdgeResponse, CausalNodeResponse,
+    InsightResponse, MetacognitionAnalysisQuery,
+};
+use crate::state::{use_app_actions, use_app_state, AppActions};
+use crate::ui::ace::format_timestamp_ms;
+use crate::ui::decision_audit::DecisionAuditViewer;
+use crate::ui::graph_export::{ExportGraph, GraphExportMenu};
+use crate::ui::pattern_dashboard::PatternDashboard;
+use crate::ui::util::{focus_timeline_event, scroll_to_element};
+
+/// 因果链探索器的 DOM id，供事件卡片选中后滚动定位
+pub const CAUSAL_CHAIN_DOM_ID: &str = "causal-chain-explorer";
+
+/// 因果链方向选项：(查询值, 展示名)
+const CHAIN_DIRECTIONS: &[(&str, &str)] = &[
+    ("both", "双向"),
+    ("upstream", "上游"),
+    ("downstream", "下游"),
+];
+
+/// 分析模式建议：(查询值, 展示名)，输入框同样接受其它模式
+const ANALYSIS_MODES: &[(&str, &str)] = &[
+    ("comprehensive", "综合"),
+    ("performance", "性能"),
+    ("decision", "决策"),
+    ("pattern", "模式"),
+    ("causal", "因果"),
+];
+
+/// 时间窗口预设：(分钟数, 展示名)，0 表示不限
+const TIME_WINDOWS: &[(u32, &str)] = &[
+    (0, "不限"),
+    (15, "最近 15 分钟"),
+    (60, "最近 1 小时"),
+    (1440, "最近 24 小时"),
+    (10080, "最近 7 天"),
+];
+
+/// 分析范围
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum AnalysisScope {
+    All,
+    CurrentSession,
+    Cycle,
+}
+
+impl Default for AnalysisScope {
+    fn default() -> Self {
+        Self::CurrentSession
+    }
+}
+
+impl AnalysisScope {
+    const ALL: [AnalysisScope; 3] = [Self::CurrentSession, Self::Cycle, Self::All];
+
+    fn value(self) -> &'static str {
+        match self {
+            Self::All => "all",
+            Self::CurrentSession => "session",
+            Self::Cycle => "cycle",
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::All => "全部",
+            Self::CurrentSession => "当前会话",
+            Self::Cycle => "指定 AC",
+        }
+    }
+
+    fn parse(value: &str) -> Self {
+        Self::ALL
+            .into_iter()
+            .find(|scope| scope.value() == value)
+            .unwrap_or_default()
+    }
+}
+
+/// 已提交的分析条件；会话范围在渲染时解析，切换会话后自动重新分析
+#[derive(Clone, Debug, Default, PartialEq)]
+struct AppliedAnalysis {
+    mode: Option<String>,
+    scope: AnalysisScope,
+    ac_id: Option<String>,
+    window: Option<(i64, i64)>,
+}
+
+impl AppliedAnalysis {
+    fn to_query(&self, current_session: Option<&str>) -> MetacognitionAnalysisQuery {
+        MetacognitionAnalysisQuery {
+            mode: self.mode.clone(),
+            ac_id: match self.scope {
+                AnalysisScope::Cycle => self.ac_id.clone(),
+                _ => None,
+            },
+            session_id: match self.scope {
+                AnalysisScope::CurrentSession => current_session.map(str::to_string),
+                _ => None,
+            },
+            time_window_start: self.window.map(|(start, _)| start),
+            time_window_end: self.window.map(|(_, end)| end),
+        }
+    }
+}
+
+fn describe_query(query: &MetacognitionAnalysisQuery) -> String {
+    let mode = query.mode.clone().unwrap_or_else(|| "默认模式".into());
+    let scope = if let Some(ref ac_id) = query.ac_id {
+        format!("AC {ac_id}")
+    } else if let Some(ref session_id) = query.session_id {
+        format!("会话 {session_id}")
+    } else {
+        "全部".into()
+    };
+    match (query.time_window_start, query.time_window_end) {
+        (Some(start), Some(end)) => {
+            let minutes = ((end - start) / 60_000).max(0) as u32;
+            let window = TIME_WINDOWS
+                .iter()
+                .find(|(preset, _)| *preset == minutes)
+                .map(|(_, label)| label.to_string())
+                .unwrap_or_else(|| format!("{minutes} 分钟"));
+            format!("{mode} · {scope} · {window}")
+        }
+        _ => format!("{mode} · {scope}"),
+    }
+}
 
 /// 元认知分析面板组件
 #[component]
 pub fn MetacognitionPanel() -> Element {
-    let analysis_state = use_metacognition_analysis();
+    let state_store = use_app_state();
+    let mut mode_input = use_signal(String::new);
+    let mut scope = use_signal(AnalysisScope::default);
+    let mut ac_input = use_signal(String::new);
+    let mut window_minutes = use_signal(|| 0u32);
+    let mut form_error = use_signal(|| Option::<String>::None);
+    let mut applied = use_signal(AppliedAnalysis::default);
+    let mut run = use_signal(|| 0u32);
+    let mut viewed_run = use_signal(|| Option::<u32>::None);
+    let baseline_run = use_signal(|| Option::<u32>::None);
+
+    let snapshot = state_store.read();
+    let current_session = snapshot.session_id.clone();
+    let cycle_ids: Vec<String> = snapshot
+        .ace
+        .cycles
+        .iter()
+        .take(30)
+        .map(|cycle| cycle.cycle_id.clone())
+        .collect();
+    let selected_cycle = snapshot.ace.selected_cycle_id.clone();
+    drop(snapshot);
+
+    let query = applied.read().to_query(current_session.as_deref());
+    let analysis_state = use_metacognition_analysis(query, *run.read());
 
+    let scope_value = *scope.read();
+    let window_value = *window_minutes.read();
+    let form_error_text = form_error.read().clone();
+    let submit_cycle = selected_cycle.clone();
     let body = {
         let state = analysis_state.read();
-        if state.loading {
+        let viewed = viewed_run
+            .read()
+            .and_then(|id| state.history.iter().find(|entry| entry.run_id == id))
+            .or_else(|| state.history.last());
+        let baseline = baseline_run
+            .read()
+            .and_then(|id| state.history.iter().find(|entry| entry.run_id == id));
+        let comparison = match (baseline, viewed) {
+            (Some(baseline), Some(viewed)) if baseline.run_id != viewed.run_id => {
+                Some(render_analysis_comparison(baseline, viewed))
+            }
+            _ => None,
+        };
+        let history = render_analysis_history(
+            &state.history,
+            viewed.map(|entry| entry.run_id),
+            viewed_run,
+            baseline_run,
+        );
+        let result = if state.loading && viewed.is_none() {
             rsx! { p { class: "text-xs text-slate-500", "正在加载元认知分析..." } }
         } else if let Some(ref err) = state.error {
             rsx! { p { class: "text-xs text-red-500", "加载失败: {err}" } }
-        } else if let Some(ref analysis) = state.analysis {
+        } else if let Some(analysis) = viewed
+            .map(|entry| &entry.result)
+            .or(state.analysis.as_ref())
+        {
             rsx! {
                 div { class: "space-y-4",
                     {render_analysis_overview(analysis)}
@@ -27,8 +207,18 @@ pub fn MetacognitionPanel() -> Element {
             }
         } else {
             rsx! { p { class: "text-xs text-slate-500 italic", "暂无元认知分析数据" } }
+        };
+        rsx! {
+            div { class: "space-y-4",
+                {history}
+                if let Some(comparison) = comparison {
+                    {comparison}
+                }
+                {result}
+            }
         }
     };
+    let loading = analysis_state.read().loading;
 
     rsx! {
         section { class: "space-y-3",
@@ -36,7 +226,270 @@ pub fn MetacognitionPanel() -> Element {
                 h2 { class: "text-lg font-semibold text-slate-900", "元认知分析" }
                 p { class: "text-xs text-slate-500", "AI 自我思考过程的深度分析与模式识别" }
             }
+            form {
+                class: "flex flex-wrap items-end gap-2 rounded-lg border border-slate-200 bg-white p-3 text-xs shadow-sm",
+                onsubmit: move |evt: FormEvent| {
+                    evt.prevent_default();
+                    let scope_value = *scope.read();
+                    let ac_id = if scope_value == AnalysisScope::Cycle {
+                        let typed = ac_input.read().trim().to_string();
+                        let ac_id = if typed.is_empty() { submit_cycle.clone() } else { Some(typed) };
+                        if ac_id.is_none() {
+                            form_error.set(Some("请输入 AC ID".into()));
+                            return;
+                        }
+                        ac_id
+                    } else {
+                        None
+                    };
+                    let window = match *window_minutes.read() {
+                        0 => None,
+                        minutes => {
+                            let end = web_sys::js_sys::Date::now() as i64;
+                            Some((end - i64::from(minutes) * 60_000, end))
+                        }
+                    };
+                    let mode = mode_input.read().trim().to_string();
+                    form_error.set(None);
+                    applied.set(AppliedAnalysis {
+                        mode: (!mode.is_empty()).then_some(mode),
+                        scope: scope_value,
+                        ac_id,
+                        window,
+                    });
+                    let next = run.peek().wrapping_add(1);
+                    run.set(next);
+                    viewed_run.set(None);
+                },
+                div { class: "flex flex-col gap-1",
+                    label { class: "text-[11px] font-semibold text-slate-600", "分析模式" }
+                    input {
+                        class: "w-32 rounded border border-slate-300 px-2 py-1",
+                        list: "metacognition-analysis-modes",
+                        value: "{mode_input}",
+                        oninput: move |evt| mode_input.set(evt.value()),
+                        placeholder: "默认",
+                    }
+                    datalist { id: "metacognition-analysis-modes",
+                        for (value, label) in ANALYSIS_MODES.iter() {
+                            option { value: *value, "{label}" }
+                        }
+                    }
+                }
+                div { class: "flex flex-col gap-1",
+                    label { class: "text-[11px] font-semibold text-slate-600", "范围" }
+                    select {
+                        class: "rounded border border-slate-300 px-2 py-1",
+                        onchange: move |evt: FormEvent| scope.set(AnalysisScope::parse(&evt.value())),
+                        for option_scope in AnalysisScope::ALL {
+                            option {
+                                value: option_scope.value(),
+                                selected: scope_value == option_scope,
+                                {option_scope.label()}
+                            }
+                        }
+                    }
+                }
+                if scope_value == AnalysisScope::Cycle {
+                    div { class: "flex flex-col gap-1",
+                        label { class: "text-[11px] font-semibold text-slate-600", "AC ID" }
+                        input {
+                            class: "w-40 rounded border border-slate-300 px-2 py-1 font-mono",
+                            list: "metacognition-analysis-cycles",
+                            value: "{ac_input}",
+                            oninput: move |evt| ac_input.set(evt.value()),
+                            placeholder: selected_cycle.clone().unwrap_or_else(|| "ac_id".into()),
+                        }
+                        datalist { id: "metacognition-analysis-cycles",
+                            for cycle_id in cycle_ids.iter() {
+                                option { value: "{cycle_id}" }
+                            }
+                        }
+                    }
+                }
+                div { class: "flex flex-col gap-1",
+                    label { class: "text-[11px] font-semibold text-slate-600", "时间窗口" }
+                    select {
+                        class: "rounded border border-slate-300 px-2 py-1",
+                        onchange: move |evt: FormEvent| {
+                            if let Ok(minutes) = evt.value().parse::<u32>() {
+                                window_minutes.set(minutes);
+                            }
+                        },
+                        for (minutes, label) in TIME_WINDOWS.iter() {
+                            option { value: "{minutes}", selected: window_value == *minutes, "{label}" }
+                        }
+                    }
+                }
+                button {
+                    class: "rounded bg-slate-900 px-3 py-1.5 font-semibold text-white hover:bg-slate-800 disabled:opacity-50",
+                    r#type: "submit",
+                    disabled: loading,
+                    if loading { "分析中..." } else { "运行分析" }
+                }
+                if let Some(err) = form_error_text {
+                    span { class: "text-red-500", "{err}" }
+                }
+            }
             {body}
+            CausalChainExplorer {}
+            PatternDashboard {}
+            DecisionAuditViewer {}
+        }
+    }
+}
+
+/// 渲染历史分析记录，可切换查看或设为对比基线
+fn render_analysis_history(
+    history: &[AnalysisRun],
+    viewed_id: Option<u32>,
+    mut viewed_run: Signal<Option<u32>>,
+    mut baseline_run: Signal<Option<u32>>,
+) -> Element {
+    if history.is_empty() {
+        return rsx! {};
+    }
+    let baseline_id = *baseline_run.read();
+
+    rsx! {
+        div { class: "rounded-lg border border-slate-200 bg-white p-3 shadow-sm space-y-1 text-xs",
+            div { class: "flex items-center justify-between",
+                h3 { class: "text-sm font-semibold text-slate-800", "分析历史" }
+                if baseline_id.is_some() {
+                    button {
+                        class: "text-[11px] text-slate-500 hover:underline",
+                        onclick: move |_| baseline_run.set(None),
+                        "清除对比基线"
+                    }
+                }
+            }
+            for entry in history.iter().rev() {
+                {
+                    let run_id = entry.run_id;
+                    let is_viewed = viewed_id == Some(run_id);
+                    let is_baseline = baseline_id == Some(run_id);
+                    let row_class = if is_viewed {
+                        "flex flex-wrap items-center gap-2 rounded border border-blue-200 bg-blue-50 px-2 py-1"
+                    } else {
+                        "flex flex-wrap items-center gap-2 rounded border border-slate-100 px-2 py-1"
+                    };
+                    let requested_at = format_timestamp_ms(entry.requested_at_ms);
+                    let description = describe_query(&entry.query);
+                    rsx! {
+                        div { class: row_class,
+                            span { class: "font-mono text-slate-400", "#{run_id}" }
+                            span { class: "text-slate-400", "{requested_at}" }
+                            span { class: "flex-1 truncate text-slate-700", title: "{description}", "{description}" }
+                            span { class: "text-slate-500", {format!("{} 条洞见", entry.result.insights.len())} }
+                            span { class: "font-mono text-purple-600", {format!("{}ms", entry.result.execution_time_ms)} }
+                            if is_baseline {
+                                span { class: "rounded bg-amber-100 px-1.5 py-0.5 text-amber-700", "基线" }
+                            }
+                            button {
+                                class: "text-blue-700 hover:underline disabled:opacity-50",
+                                disabled: is_viewed,
+                                onclick: move |_| viewed_run.set(Some(run_id)),
+                                "查看"
+                            }
+                            button {
+                                class: "text-amber-700 hover:underline disabled:opacity-50",
+                                disabled: is_baseline,
+                                onclick: move |_| baseline_run.set(Some(run_id)),
+                                "设为基线"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// 当前查看的分析与基线之间的差异
+fn render_analysis_comparison(baseline: &AnalysisRun, current: &AnalysisRun) -> Element {
+    let insight_key =
+        |insight: &InsightResponse| (insight.insight_type.clone(), insight.title.clone());
+    let baseline_insights: HashMap<(String, String), &InsightResponse> = baseline
+        .result
+        .insights
+        .iter()
+        .map(|insight| (insight_key(insight), insight))
+        .collect();
+    let current_keys: HashSet<(String, String)> =
+        current.result.insights.iter().map(insight_key).collect();
+
+    let added: Vec<&InsightResponse> = current
+        .result
+        .insights
+        .iter()
+        .filter(|insight| !baseline_insights.contains_key(&insight_key(insight)))
+        .collect();
+    let removed: Vec<&InsightResponse> = baseline
+        .result
+        .insights
+        .iter()
+        .filter(|insight| !current_keys.contains(&insight_key(insight)))
+        .collect();
+    let changed: Vec<(&InsightResponse, f32)> = current
+        .result
+        .insights
+        .iter()
+        .filter_map(|insight| {
+            let previous = baseline_insights.get(&insight_key(insight))?;
+            let delta = insight.confidence - previous.confidence;
+            (delta.abs() >= 0.01).then_some((insight, delta))
+        })
+        .collect();
+
+    let time_delta =
+        current.result.execution_time_ms as i64 - baseline.result.execution_time_ms as i64;
+    let time_class = if time_delta <= 0 {
+        "text-green-600"
+    } else {
+        "text-red-600"
+    };
+    let count_delta = current.result.insights.len() as i64 - baseline.result.insights.len() as i64;
+
+    rsx! {
+        div { class: "rounded-lg border border-amber-200 bg-amber-50 p-3 space-y-2 text-xs text-slate-600",
+            h3 { class: "text-sm font-semibold text-slate-800",
+                {format!("对比 #{} → #{}", baseline.run_id, current.run_id)}
+            }
+            div { class: "flex flex-wrap gap-4",
+                span { "执行时间 "
+                    span { class: "font-mono {time_class}", {format!("{time_delta:+}ms")} }
+                }
+                span { "洞见数量 "
+                    span { class: "font-mono", {format!("{count_delta:+}")} }
+                }
+            }
+            if !added.is_empty() {
+                div { class: "space-y-0.5",
+                    span { class: "font-medium text-green-700", "新增洞见" }
+                    for insight in added.iter() {
+                        p { class: "pl-2", {format!("+ [{}] {}", insight.insight_type, insight.title)} }
+                    }
+                }
+            }
+            if !removed.is_empty() {
+                div { class: "space-y-0.5",
+                    span { class: "font-medium text-red-700", "消失的洞见" }
+                    for insight in removed.iter() {
+                        p { class: "pl-2", {format!("- [{}] {}", insight.insight_type, insight.title)} }
+                    }
+                }
+            }
+            if !changed.is_empty() {
+                div { class: "space-y-0.5",
+                    span { class: "font-medium text-slate-700", "置信度变化" }
+                    for (insight, delta) in changed.iter() {
+                        p { class: "pl-2", {format!("[{}] {} {:+.0}%", insight.insight_type, insight.title, delta * 100.0)} }
+                    }
+                }
+            }
+            if added.is_empty() && removed.is_empty() && changed.is_empty() {
+                p { class: "italic text-slate-400", "两次分析的洞见一致" }
+            }
         }
     }
 }
@@ -157,3 +610,365 @@ fn render_insight(insight: &InsightResponse) -> Element {
         }
     }
 }
+
+/// 在因果链探索器中查看事件并滚动到探索器
+pub(crate) fn open_causal_chain(actions: &AppActions, event_id: String) {
+    actions.explore_causal_chain(Some(event_id));
+    scroll_to_element(CAUSAL_CHAIN_DOM_ID.to_string());
+}
+
+/// 因果链探索组件，选中的事件保存在 `AppState::causal_chain`，事件卡片可直接选中
+#[component]
+pub fn CausalChainExplorer() -> Element {
+    let state_store = use_app_state();
+    let actions = use_app_actions();
+    // 输入框草稿连同编辑时的选中事件一起保存，选中事件在别处变化后草稿自动失效
+    let mut event_draft = use_signal(|| Option::<(Option<String>, String)>::None);
+    let mut direction = use_signal(|| CHAIN_DIRECTIONS[0].0.to_string());
+    let mut max_depth = use_signal(|| 5u32);
+
+    let selected_event = state_store.read().causal_chain.selected_event.clone();
+    let chain_state = use_causal_chain(
+        selected_event.clone(),
+        direction.read().clone(),
+        *max_depth.read(),
+    );
+
+    let event_input = match event_draft.read().as_ref() {
+        Some((base, draft)) if *base == selected_event => draft.clone(),
+        _ => selected_event.clone().unwrap_or_default(),
+    };
+
+    let snapshot = state_store.read();
+    let recent_events: Vec<u64> = snapshot
+        .timeline
+        .events
+        .iter()
+        .take(20)
+        .map(|event| event.event_id.as_u64())
+        .collect();
+    let timeline_ids: HashSet<String> = snapshot
+        .timeline
+        .events
+        .iter()
+        .map(|event| event.event_id.as_u64().to_string())
+        .collect();
+    let session_titles: HashMap<String, String> = snapshot
+        .workspace
+        .tenants
+        .iter()
+        .flat_map(|tenant| tenant.pinned_sessions.iter())
+        .map(|session| {
+            let title = session
+                .title
+                .clone()
+                .unwrap_or_else(|| session.session_id.clone());
+            (session.session_id.clone(), title)
+        })
+        .collect();
+    let current_session = snapshot.session_id.clone();
+    let graph_root = snapshot.graph.query.root_event_id;
+    drop(snapshot);
+
+    let direction_value = direction.read().clone();
+    let depth_value = *max_depth.read();
+    let submit_input = event_input.clone();
+    let draft_base = selected_event.clone();
+    let submit_actions = actions.clone();
+    let root_actions = actions.clone();
+    let body = {
+        let state = chain_state.read();
+        if selected_event.is_none() {
+            rsx! { p { class: "text-xs text-slate-500 italic", "选择一个事件以查看因果链" } }
+        } else if state.loading {
+            rsx! { p { class: "text-xs text-slate-500", "正在加载因果链..." } }
+        } else if let Some(ref err) = state.error {
+            rsx! { p { class: "text-xs text-red-500", "{err}" } }
+        } else if let Some(ref chain) = state.chain {
+            render_causal_chain(
+                chain,
+                &timeline_ids,
+                &session_titles,
+                current_session.as_deref(),
+                &actions,
+            )
+        } else {
+            rsx! { p { class: "text-xs text-slate-500 italic", "该事件暂无因果链数据" } }
+        }
+    };
+
+    rsx! {
+        div { id: CAUSAL_CHAIN_DOM_ID, class: "rounded-lg border border-slate-200 bg-white p-4 shadow-sm space-y-3",
+            h3 { class: "text-sm font-semibold text-slate-800", "因果链探索" }
+            form {
+                class: "flex flex-wrap items-end gap-2 text-xs",
+                onsubmit: move |evt: FormEvent| {
+                    evt.prevent_default();
+                    let value = submit_input.trim().to_string();
+                    event_draft.set(None);
+                    submit_actions.explore_causal_chain((!value.is_empty()).then_some(value));
+                },
+                div { class: "flex flex-col gap-1",
+                    label { class: "text-[11px] font-semibold text-slate-600", "事件 ID" }
+                    input {
+                        class: "w-36 rounded border border-slate-300 px-2 py-1 font-mono",
+                        list: "causal-chain-events",
+                        value: "{event_input}",
+                        oninput: move |evt| event_draft.set(Some((draft_base.clone(), evt.value()))),
+                        placeholder: "event_id",
+                    }
+                    datalist { id: "causal-chain-events",
+                        for event_id in recent_events.iter() {
+                            option { value: "{event_id}" }
+                        }
+                    }
+                }
+                div { class: "flex flex-col gap-1",
+                    label { class: "text-[11px] font-semibold text-slate-600", "方向" }
+                    select {
+                        class: "rounded border border-slate-300 px-2 py-1",
+                        onchange: move |evt: FormEvent| direction.set(evt.value()),
+                        for (value, label) in CHAIN_DIRECTIONS.iter() {
+                            option { value: *value, selected: direction_value == *value, "{label}" }
+                        }
+                    }
+                }
+                div { class: "flex flex-col gap-1",
+                    label { class: "text-[11px] font-semibold text-slate-600", "最大深度" }
+                    input {
+                        class: "w-16 rounded border border-slate-300 px-2 py-1",
+                        r#type: "number",
+                        min: "1",
+                        max: "20",
+                        value: "{depth_value}",
+                        oninput: move |evt| {
+                            if let Ok(depth) = evt.value().parse::<u32>() {
+                                max_depth.set(depth.clamp(1, 20));
+                            }
+                        },
+                    }
+                }
+                button {
+                    class: "rounded bg-slate-900 px-3 py-1.5 font-semibold text-white hover:bg-slate-800",
+                    r#type: "submit",
+                    "查看因果链"
+                }
+                if let Some(root) = graph_root {
+                    button {
+                        class: "rounded border border-slate-300 px-3 py-1.5 text-slate-700 hover:bg-slate-100",
+                        r#type: "button",
+                        onclick: move |_| root_actions.explore_causal_chain(Some(root.to_string())),
+                        "使用图谱 root #{root}"
+                    }
+                }
+            }
+            {body}
+        }
+    }
+}
+
+fn format_delay(delay_ms: i64) -> String {
+    if delay_ms.abs() < 1000 {
+        format!("{delay_ms}ms")
+    } else {
+        format!("{:.1}s", delay_ms as f64 / 1000.0)
+    }
+}
+
+fn render_causal_chain(
+    chain: &CausalChainResponse,
+    timeline_ids: &HashSet<String>,
+    session_titles: &HashMap<String, String>,
+    current_session: Option<&str>,
+    actions: &AppActions,
+) -> Element {
+    let root_cause_id = chain.root_cause.as_ref().map(|node| node.event_id.clone());
+    let mut nodes: Vec<&CausalNodeResponse> = chain.nodes.iter().collect();
+    nodes.sort_by(|a, b| {
+        a.depth
+            .cmp(&b.depth)
+            .then(a.occurred_at_ms.cmp(&b.occurred_at_ms))
+    });
+    let scope = &chain.impact_scope;
+
+    rsx! {
+        div { class: "space-y-3",
+            div { class: "flex flex-wrap items-center justify-between gap-2",
+                span { class: "text-xs text-slate-500",
+                    {format!("事件 {} · {} 个节点 · {} 条边", chain.event_id, chain.nodes.len(), chain.edges.len())}
+                }
+                GraphExportMenu { graph: ExportGraph::from(chain), target: "metacognition:causal_chain" }
+            }
+            if let Some(ref root_cause) = chain.root_cause {
+                div { class: "rounded border border-red-200 bg-red-50 p-3 text-xs text-red-700",
+                    p { class: "font-semibold", {format!("根因 #{} · {}", root_cause.event_id, root_cause.event_type)} }
+                    if let Some(ref summary) = root_cause.summary {
+                        p { class: "mt-1 text-red-600", "{summary}" }
+                    }
+                }
+            }
+            // 节点
+            div { class: "space-y-1",
+                for node in nodes.iter() {
+                    {render_chain_node(
+                        node,
+                        node.event_id == chain.event_id,
+                        root_cause_id.as_deref() == Some(node.event_id.as_str()),
+                        timeline_ids.contains(&node.event_id),
+                        actions,
+                    )}
+                }
+            }
+            // 边
+            if !chain.edges.is_empty() {
+                div { class: "border-t border-slate-100 pt-3",
+                    h4 { class: "text-xs font-medium text-slate-600 mb-2", "因果边" }
+                    div { class: "space-y-1",
+                        for edge in chain.edges.iter() {
+                            {render_chain_edge(edge)}
+                        }
+                    }
+                }
+            }
+            // 影响范围
+            div { class: "border-t border-slate-100 pt-3 space-y-2",
+                h4 { class: "text-xs font-medium text-slate-600", "影响范围" }
+                div { class: "grid grid-cols-2 md:grid-cols-4 gap-4",
+                    div { class: "text-center p-2 bg-slate-50 rounded",
+                        p { class: "text-lg font-bold text-blue-600", "{scope.total_affected_events}" }
+                        p { class: "text-xs text-slate-500", "受影响事件" }
+                    }
+                    div { class: "text-center p-2 bg-slate-50 rounded",
+                        p { class: "text-lg font-bold text-purple-600", "{scope.max_depth}" }
+                        p { class: "text-xs text-slate-500", "最大深度" }
+                    }
+                    div { class: "text-center p-2 bg-slate-50 rounded",
+                        p { class: "text-lg font-bold text-green-600", {format!("{}", scope.affected_sessions.len())} }
+                        p { class: "text-xs text-slate-500", "会话" }
+                    }
+                    div { class: "text-center p-2 bg-slate-50 rounded",
+                        p { class: "text-lg font-bold text-orange-600", {format!("{}", scope.affected_actors.len())} }
+                        p { class: "text-xs text-slate-500", "参与者" }
+                    }
+                }
+                if !scope.affected_sessions.is_empty() {
+                    div { class: "flex flex-wrap items-center gap-1 text-xs",
+                        span { class: "text-slate-500", "会话:" }
+                        for session_id in scope.affected_sessions.iter() {
+                            {
+                                let is_current = current_session == Some(session_id.as_str());
+                                let title = session_titles.get(session_id).cloned().unwrap_or_else(|| session_id.clone());
+                                let target = session_id.clone();
+                                let actions = actions.clone();
+                                let chip_class = if is_current {
+                                    "px-2 py-0.5 rounded bg-blue-600 text-white"
+                                } else {
+                                    "px-2 py-0.5 rounded border border-blue-200 text-blue-700 hover:bg-blue-50"
+                                };
+                                rsx! {
+                                    button {
+                                        class: chip_class,
+                                        title: "切换到会话 {session_id}",
+                                        disabled: is_current,
+                                        onclick: move |_| actions.set_session(Some(target.clone())),
+                                        "{title}"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                if !scope.affected_actors.is_empty() {
+                    div { class: "flex flex-wrap items-center gap-1 text-xs",
+                        span { class: "text-slate-500", "参与者:" }
+                        for actor in scope.affected_actors.iter() {
+                            span { class: "px-2 py-0.5 bg-slate-200 text-slate-600 rounded", "{actor}" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn render_chain_node(
+    node: &CausalNodeResponse,
+    is_selected: bool,
+    is_root_cause: bool,
+    in_timeline: bool,
+    actions: &AppActions,
+) -> Element {
+    let container = if is_root_cause {
+        "border-red-300 bg-red-50"
+    } else if is_selected {
+        "border-blue-300 bg-blue-50"
+    } else {
+        "border-slate-100 bg-slate-50"
+    };
+    let event_id = node.event_id.clone();
+    let numeric_id = node.event_id.parse::<u64>().ok();
+    let select_actions = actions.clone();
+    let focus_actions = actions.clone();
+    let root_actions = actions.clone();
+    let timeline_title = if in_timeline {
+        ""
+    } else {
+        "时间线尚未加载该事件"
+    };
+
+    rsx! {
+        div { class: format!("flex flex-wrap items-center gap-2 p-2 rounded border text-xs {}", container),
+            span { class: "w-14 text-slate-400", {format!("深度 {}", node.depth)} }
+            button {
+                class: "font-mono text-blue-700 hover:underline",
+                onclick: move |_| select_actions.explore_causal_chain(Some(event_id.clone())),
+                "#{node.event_id}"
+            }
+            span { class: "px-2 py-0.5 bg-slate-200 text-slate-600 rounded", "{node.event_type}" }
+            if is_root_cause {
+                span { class: "px-2 py-0.5 bg-red-600 text-white rounded", "根因" }
+            }
+            if let Some(ref summary) = node.summary {
+                span { class: "flex-1 truncate text-slate-600", title: "{summary}", "{summary}" }
+            }
+            span { class: "text-slate-400", "{node.occurred_at_ms}" }
+            if let Some(id) = numeric_id {
+                button {
+                    class: "text-[11px] text-emerald-700 hover:underline disabled:opacity-50",
+                    disabled: !in_timeline,
+                    title: timeline_title,
+                    onclick: move |_| focus_timeline_event(&focus_actions, id),
+                    "时间线"
+                }
+                button {
+                    class: "text-[11px] text-slate-600 hover:underline",
+                    onclick: move |_| root_actions.set_graph_root(Some(id)),
+                    "设为图谱 root"
+                }
+            }
+        }
+    }
+}
+
+fn render_chain_edge(edge: &CausalEdgeResponse) -> Element {
+    let strength_pct = (edge.strength.clamp(0.0, 1.0) * 100.0) as u32;
+    let delay = edge.delay_ms.map(format_delay);
+
+    rsx! {
+        div { class: "flex flex-wrap items-center gap-2 p-2 bg-slate-50 rounded text-xs",
+            span { class: "font-mono text-slate-600", "#{edge.from}" }
+            span { class: "text-slate-400", "→" }
+            span { class: "font-mono text-slate-600", "#{edge.to}" }
+            span { class: "px-2 py-0.5 bg-slate-200 text-slate-500 rounded", "{edge.edge_type}" }
+            span { class: "flex items-center gap-1 text-slate-500",
+                span { class: "inline-block h-1.5 w-16 overflow-hidden rounded bg-slate-200",
+                    span { class: "block h-1.5 bg-purple-500", style: "width: {strength_pct}%" }
+                }
+                {format!("强度 {:.2}", edge.strength)}
+            }
+            if let Some(delay) = delay {
+                span { class: "text-slate-400", "延迟 {delay}" }
+            }
+        }
+    }
+}
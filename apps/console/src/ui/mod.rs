@@ -1,18 +1,35 @@
 pub mod ace;
+pub mod budget;
+pub mod causal_canvas;
 pub mod context;
+pub mod cycle_diff;
+pub mod cycle_lineage;
+pub mod decision_audit;
 pub mod explain;
 pub mod graph;
+pub mod graph_export;
 pub mod interaction;
+pub mod manifest_diff;
 pub mod notifications;
+pub mod outbox_inspector;
+pub mod pattern_dashboard;
+pub mod performance_profile;
+pub mod router_explorer;
+pub mod severity;
 pub mod timeline;
+pub mod token_map;
 pub mod tools;
+pub mod util;
 pub mod workspace;
 
 // 新增功能模块
 pub mod autonomous;
+pub mod autonomous_monitor;
 pub mod dfr;
+pub mod dfr_decision;
 pub mod evolution;
 pub mod graph_enhanced;
 pub mod metacognition;
+pub mod scenario_stack;
 pub mod surreal;
 pub mod version_chain;
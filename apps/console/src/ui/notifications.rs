@@ -173,6 +173,65 @@ pub fn NotificationCenter() -> Element {
         });
     }
 
+    let pending_budget_alerts: Vec<_> = snapshot
+        .budget
+        .alerts
+        .iter()
+        .filter(|alert| !alert.toast_dismissed)
+        .collect();
+    if let Some(latest) = pending_budget_alerts.last() {
+        let details: Vec<(String, String)> = pending_budget_alerts
+            .iter()
+            .rev()
+            .skip(1)
+            .take(3)
+            .map(|alert| (format!("周期 {}", alert.cycle_id), alert.message.clone()))
+            .collect();
+        let app_actions = actions.clone();
+        toasts.push(rsx! {
+            Toast {
+                key: "budget-alert",
+                kind: ToastKind::Warning,
+                title: format!("预算告警（{} 条未读）", pending_budget_alerts.len()),
+                message: latest.message.clone(),
+                details,
+                on_close: move |_| app_actions.clone().dismiss_budget_alert_toasts(),
+            }
+        });
+    }
+
+    let pending_guardrail_violations: Vec<_> = snapshot
+        .guardrail
+        .violations
+        .iter()
+        .filter(|violation| !violation.toast_dismissed)
+        .collect();
+    if let Some(latest) = pending_guardrail_violations.last() {
+        let details: Vec<(String, String)> = pending_guardrail_violations
+            .iter()
+            .rev()
+            .skip(1)
+            .take(3)
+            .map(|violation| {
+                (
+                    format!("会话 {}", violation.orchestration_id),
+                    violation.message.clone(),
+                )
+            })
+            .collect();
+        let app_actions = actions.clone();
+        toasts.push(rsx! {
+            Toast {
+                key: "guardrail-alert",
+                kind: ToastKind::Warning,
+                title: format!("护栏告警（{} 条未读）", pending_guardrail_violations.len()),
+                message: latest.message.clone(),
+                details,
+                on_close: move |_| app_actions.clone().dismiss_guardrail_toasts(),
+            }
+        });
+    }
+
     if let Some(error) = snapshot.timeline.error.clone() {
         let app_actions = actions.clone();
         toasts.push(rsx! {
@@ -0,0 +1,707 @@
+use crate::models::{AwarenessDegradationReason, AwarenessEvent, DialogueEvent, OutboxMessageView};
+use crate::state::{use_app_state, AppActions};
+use crate::ui::ace::{
+    awareness_degradation_label, copy_text_to_clipboard, export_text_as_file, format_timestamp_ms,
+    truncate_text,
+};
+use crate::ui::util::{parse_datetime_ms, scroll_to_element};
+use dioxus::prelude::*;
+use serde_json::Value;
+use std::collections::{BTreeSet, HashSet};
+use std::rc::Rc;
+use time::{Date, PrimitiveDateTime, Time, UtcOffset};
+
+const FILTER_ALL: &str = "__all__";
+const FILTER_DEGRADED: &str = "__degraded__";
+const FILTER_CLEAN: &str = "__clean__";
+
+/// 解析时尝试的目标类型及其关键字段（字段名, 期望的 JSON 类型）
+const AWARENESS_FIELDS: &[(&str, FieldKind)] = &[
+    ("event_id", FieldKind::Id),
+    ("cycle_id", FieldKind::Id),
+    ("event_type", FieldKind::Text),
+    ("occurred_at_ms", FieldKind::Number),
+    ("payload", FieldKind::Any),
+];
+const DIALOGUE_FIELDS: &[(&str, FieldKind)] = &[
+    ("event_id", FieldKind::Id),
+    ("session_id", FieldKind::Id),
+    ("event_type", FieldKind::Text),
+    ("timestamp_ms", FieldKind::Number),
+    ("sequence_number", FieldKind::Number),
+];
+
+#[derive(Clone, Copy)]
+enum FieldKind {
+    Id,
+    Text,
+    Number,
+    Any,
+}
+
+impl FieldKind {
+    fn accepts(&self, value: &Value) -> bool {
+        match self {
+            FieldKind::Id => value.is_string() || value.is_u64(),
+            FieldKind::Text => value.is_string(),
+            FieldKind::Number => value.is_i64() || value.is_u64(),
+            FieldKind::Any => true,
+        }
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            FieldKind::Id => "ID（字符串或整数）",
+            FieldKind::Text => "字符串",
+            FieldKind::Number => "整数",
+            FieldKind::Any => "任意值",
+        }
+    }
+}
+
+#[derive(Clone)]
+enum DecodedEvent {
+    Awareness(Box<AwarenessEvent>),
+    Dialogue(Box<DialogueEvent>),
+    Undecoded,
+}
+
+impl DecodedEvent {
+    fn event_id(&self) -> Option<u64> {
+        match self {
+            DecodedEvent::Awareness(event) => Some(event.event_id.as_u64()),
+            DecodedEvent::Dialogue(event) => Some(event.event_id.as_u64()),
+            DecodedEvent::Undecoded => None,
+        }
+    }
+
+    fn type_label(&self) -> &'static str {
+        match self {
+            DecodedEvent::Awareness(_) => "AwarenessEvent",
+            DecodedEvent::Dialogue(_) => "DialogueEvent",
+            DecodedEvent::Undecoded => "未解析",
+        }
+    }
+
+    /// 时间线中对应条目的 DOM id，与 ui::timeline 保持一致
+    fn dom_id(&self) -> Option<String> {
+        match self {
+            DecodedEvent::Awareness(event) => {
+                Some(format!("awareness-event-{}", event.event_id.as_u64()))
+            }
+            DecodedEvent::Dialogue(event) => {
+                Some(format!("timeline-event-{}", event.event_id.as_u64()))
+            }
+            DecodedEvent::Undecoded => None,
+        }
+    }
+}
+
+#[derive(Clone)]
+struct DecodeIssue {
+    target: &'static str,
+    field: String,
+    message: String,
+}
+
+fn decode_outbox_event(value: &Value) -> (DecodedEvent, Vec<DecodeIssue>) {
+    let awareness_err = match serde_json::from_value::<AwarenessEvent>(value.clone()) {
+        Ok(event) => return (DecodedEvent::Awareness(Box::new(event)), Vec::new()),
+        Err(err) => err,
+    };
+    let dialogue_err = match serde_json::from_value::<DialogueEvent>(value.clone()) {
+        Ok(event) => return (DecodedEvent::Dialogue(Box::new(event)), Vec::new()),
+        Err(err) => err,
+    };
+
+    let mut issues = field_issues(value, "AwarenessEvent", AWARENESS_FIELDS);
+    issues.push(serde_issue("AwarenessEvent", &awareness_err));
+    issues.extend(field_issues(value, "DialogueEvent", DIALOGUE_FIELDS));
+    issues.push(serde_issue("DialogueEvent", &dialogue_err));
+    (DecodedEvent::Undecoded, issues)
+}
+
+fn field_issues(
+    value: &Value,
+    target: &'static str,
+    fields: &[(&str, FieldKind)],
+) -> Vec<DecodeIssue> {
+    let Some(obj) = value.as_object() else {
+        return vec![DecodeIssue {
+            target,
+            field: "$".into(),
+            message: "payload 不是 JSON 对象".into(),
+        }];
+    };
+    fields
+        .iter()
+        .filter_map(|(name, kind)| match obj.get(*name) {
+            None => Some(DecodeIssue {
+                target,
+                field: (*name).to_string(),
+                message: "缺少字段".into(),
+            }),
+            Some(found) if !kind.accepts(found) => Some(DecodeIssue {
+                target,
+                field: (*name).to_string(),
+                message: format!(
+                    "期望{}，实际为 {}",
+                    kind.label(),
+                    truncate_text(&found.to_string(), 48)
+                ),
+            }),
+            Some(_) => None,
+        })
+        .collect()
+}
+
+/// serde 的错误信息中字段名以反引号包裹（missing field `x` / unknown field `x`）
+fn serde_issue(target: &'static str, err: &serde_json::Error) -> DecodeIssue {
+    let message = err.to_string();
+    let field = message
+        .split('`')
+        .nth(1)
+        .filter(|_| message.contains("field"))
+        .map(|name| name.to_string())
+        .unwrap_or_else(|| "$".into());
+    DecodeIssue {
+        target,
+        field,
+        message,
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum SyncPosition {
+    BeforeWindow,
+    InWindow,
+    AfterSync,
+    Unknown,
+}
+
+impl SyncPosition {
+    fn label(&self) -> &'static str {
+        match self {
+            SyncPosition::BeforeWindow => "同步窗口前",
+            SyncPosition::InWindow => "同步窗口内",
+            SyncPosition::AfterSync => "同步点后",
+            SyncPosition::Unknown => "时间未知",
+        }
+    }
+
+    fn badge_class(&self) -> &'static str {
+        match self {
+            SyncPosition::BeforeWindow => "rounded bg-slate-200 px-2 py-0.5 text-slate-700",
+            SyncPosition::InWindow => "rounded bg-sky-100 px-2 py-0.5 text-sky-700",
+            SyncPosition::AfterSync => "rounded bg-emerald-100 px-2 py-0.5 text-emerald-700",
+            SyncPosition::Unknown => "rounded bg-slate-100 px-2 py-0.5 text-slate-500",
+        }
+    }
+}
+
+/// timeframe 为 (OffsetDateTime, OffsetDateTime) 序列化后的数组，换算为毫秒区间。
+/// 两端可以是 time 的紧凑数组格式，也可以是 RFC3339 字符串
+fn parse_timeframe_ms(timeframe: &Value) -> Option<(i64, i64)> {
+    let arr = timeframe.as_array()?;
+    if arr.len() < 2 {
+        return None;
+    }
+    Some((
+        offset_datetime_value_to_ms(&arr[0])?,
+        offset_datetime_value_to_ms(&arr[1])?,
+    ))
+}
+
+fn offset_datetime_value_to_ms(value: &Value) -> Option<i64> {
+    match value {
+        Value::String(text) => parse_datetime_ms(text),
+        other => offset_datetime_array_to_ms(other),
+    }
+}
+
+fn offset_datetime_array_to_ms(value: &Value) -> Option<i64> {
+    // [year, day_of_year, hour, minute, second, nanosecond, offset_h, offset_m, offset_s]
+    let parts: Vec<i64> = value
+        .as_array()?
+        .iter()
+        .map(|part| part.as_i64())
+        .collect::<Option<Vec<_>>>()?;
+    if parts.len() < 6 {
+        return None;
+    }
+    let date = Date::from_ordinal_date(parts[0] as i32, parts[1] as u16).ok()?;
+    let time = Time::from_hms_nano(
+        parts[2] as u8,
+        parts[3] as u8,
+        parts[4] as u8,
+        parts[5] as u32,
+    )
+    .ok()?;
+    let offset = UtcOffset::from_hms(
+        parts.get(6).copied().unwrap_or(0) as i8,
+        parts.get(7).copied().unwrap_or(0) as i8,
+        parts.get(8).copied().unwrap_or(0) as i8,
+    )
+    .ok()?;
+    let nanos = PrimitiveDateTime::new(date, time)
+        .assume_offset(offset)
+        .unix_timestamp_nanos();
+    Some((nanos / 1_000_000) as i64)
+}
+
+#[derive(Clone)]
+struct InspectorRow {
+    event_id: String,
+    cycle_id: String,
+    event_type: String,
+    occurred_at_ms: Option<i64>,
+    degradation: Option<String>,
+    payload_summary: String,
+    payload_json: Option<Rc<String>>,
+    event_json: Option<Rc<String>>,
+    decoded: DecodedEvent,
+    issues: Vec<DecodeIssue>,
+    position: SyncPosition,
+    /// 相对同步点（timeframe 结束时刻）的偏移
+    offset_ms: Option<i64>,
+    /// 按发生时间排序后的序号（从 1 开始）
+    order: usize,
+}
+
+fn build_inspector_rows(outbox: &[OutboxMessageView], timeframe: &Value) -> Vec<InspectorRow> {
+    let window = parse_timeframe_ms(timeframe);
+    let mut rows: Vec<InspectorRow> = outbox
+        .iter()
+        .map(|item| {
+            let event = &item.payload;
+            let (decoded, issues) = decode_outbox_event(event);
+
+            let event_type = event
+                .get("event_type")
+                .and_then(|v| v.as_str())
+                .unwrap_or("Unknown")
+                .to_string();
+            let occurred_at_ms = match &decoded {
+                DecodedEvent::Awareness(ev) => Some(ev.occurred_at_ms),
+                DecodedEvent::Dialogue(ev) => Some(ev.timestamp_ms),
+                DecodedEvent::Undecoded => event
+                    .get("occurred_at_ms")
+                    .or_else(|| event.get("timestamp_ms"))
+                    .and_then(|v| v.as_i64()),
+            };
+            let degradation = event
+                .get("degradation_reason")
+                .and_then(|v| serde_json::from_value::<AwarenessDegradationReason>(v.clone()).ok())
+                .map(|reason| awareness_degradation_label(&reason).to_string());
+
+            let payload_value = event.get("payload").cloned().unwrap_or(Value::Null);
+            let payload_summary = summarize_outbox_payload(&payload_value);
+            let payload_json = serde_json::to_string_pretty(&payload_value)
+                .ok()
+                .map(Rc::new);
+            let event_json = serde_json::to_string_pretty(event).ok().map(Rc::new);
+
+            let (position, offset_ms) = match (occurred_at_ms, window) {
+                (Some(at), Some((start, end))) => {
+                    let position = if at < start {
+                        SyncPosition::BeforeWindow
+                    } else if at <= end {
+                        SyncPosition::InWindow
+                    } else {
+                        SyncPosition::AfterSync
+                    };
+                    (position, Some(at - end))
+                }
+                _ => (SyncPosition::Unknown, None),
+            };
+
+            InspectorRow {
+                event_id: item.event_id.clone(),
+                cycle_id: item.cycle_id.clone(),
+                event_type,
+                occurred_at_ms,
+                degradation,
+                payload_summary,
+                payload_json,
+                event_json,
+                decoded,
+                issues,
+                position,
+                offset_ms,
+                order: 0,
+            }
+        })
+        .collect();
+
+    rows.sort_by_key(|row| (row.occurred_at_ms.is_none(), row.occurred_at_ms));
+    for (idx, row) in rows.iter_mut().enumerate() {
+        row.order = idx + 1;
+    }
+    rows
+}
+
+fn summarize_outbox_payload(value: &Value) -> String {
+    if value.is_null() {
+        return "-".into();
+    }
+    if let Some(obj) = value.as_object() {
+        if let Some(kind) = obj.get("kind").and_then(|v| v.as_str()) {
+            return kind.to_string();
+        }
+        if let Some(content) = obj
+            .get("message")
+            .and_then(|v| v.as_object())
+            .and_then(|msg| msg.get("content"))
+            .and_then(|v| v.as_str())
+        {
+            return truncate_text(content, 96);
+        }
+        if let Some(summary) = obj.get("summary").and_then(|v| v.as_str()) {
+            return truncate_text(summary, 96);
+        }
+        if let Some(status) = obj.get("status").and_then(|v| v.as_str()) {
+            return status.to_string();
+        }
+        let keys: Vec<_> = obj.keys().take(3).cloned().collect();
+        if !keys.is_empty() {
+            return format!("{{{}}}", keys.join(", "));
+        }
+    }
+    truncate_text(&value.to_string(), 96)
+}
+
+fn matches_filters(row: &InspectorRow, type_filter: &str, degradation_filter: &str) -> bool {
+    let type_ok = type_filter == FILTER_ALL || row.event_type == type_filter;
+    let degradation_ok = match degradation_filter {
+        FILTER_ALL => true,
+        FILTER_DEGRADED => row.degradation.is_some(),
+        FILTER_CLEAN => row.degradation.is_none(),
+        label => row.degradation.as_deref() == Some(label),
+    };
+    type_ok && degradation_ok
+}
+
+/// 写入时间线；append_timeline 按 event_id 去重，返回本次新增条数
+fn push_to_timeline(actions: &AppActions, rows: &[&InspectorRow], present: &HashSet<u64>) -> usize {
+    let mut dialogue = Vec::new();
+    let mut awareness = Vec::new();
+    let mut seen = BTreeSet::new();
+    for row in rows {
+        let Some(id) = row.decoded.event_id() else {
+            continue;
+        };
+        if present.contains(&id) || !seen.insert(id) {
+            continue;
+        }
+        match &row.decoded {
+            DecodedEvent::Awareness(event) => awareness.push((**event).clone()),
+            DecodedEvent::Dialogue(event) => dialogue.push((**event).clone()),
+            DecodedEvent::Undecoded => {}
+        }
+    }
+    let added = dialogue.len() + awareness.len();
+    if added > 0 {
+        actions.append_timeline(dialogue, awareness, None);
+    }
+    added
+}
+
+#[derive(Props, Clone)]
+#[props(no_eq)]
+pub struct OutboxInspectorProps {
+    outbox: Vec<OutboxMessageView>,
+    timeframe: Value,
+    actions: AppActions,
+}
+
+impl PartialEq for OutboxInspectorProps {
+    fn eq(&self, _other: &Self) -> bool {
+        false
+    }
+}
+
+impl Eq for OutboxInspectorProps {}
+
+#[component]
+pub fn OutboxInspector(props: OutboxInspectorProps) -> Element {
+    let app_state = use_app_state();
+    let mut type_filter = use_signal(|| FILTER_ALL.to_string());
+    let mut degradation_filter = use_signal(|| FILTER_ALL.to_string());
+
+    let snapshot = app_state.read();
+    let present: HashSet<u64> = snapshot
+        .timeline
+        .events
+        .iter()
+        .map(|event| event.event_id.as_u64())
+        .chain(
+            snapshot
+                .timeline
+                .awareness
+                .iter()
+                .map(|event| event.event_id.as_u64()),
+        )
+        .collect();
+    let focused_event_id = snapshot.timeline.focused_event_id;
+    drop(snapshot);
+
+    let rows = build_inspector_rows(&props.outbox, &props.timeframe);
+    let type_options: BTreeSet<String> = rows.iter().map(|row| row.event_type.clone()).collect();
+    let degradation_options: BTreeSet<String> = rows
+        .iter()
+        .filter_map(|row| row.degradation.clone())
+        .collect();
+    let type_value = type_filter.read().clone();
+    let degradation_value = degradation_filter.read().clone();
+    let visible: Vec<InspectorRow> = rows
+        .iter()
+        .filter(|row| matches_filters(row, &type_value, &degradation_value))
+        .cloned()
+        .collect();
+    let pending_count = visible
+        .iter()
+        .filter(|row| {
+            row.decoded
+                .event_id()
+                .is_some_and(|id| !present.contains(&id))
+        })
+        .count();
+    let failed_count = rows
+        .iter()
+        .filter(|row| matches!(row.decoded, DecodedEvent::Undecoded))
+        .count();
+    let summary_label = format!("Outbox 消息 ({})", props.outbox.len());
+
+    let bulk_actions = props.actions.clone();
+    let bulk_rows = visible.clone();
+    let bulk_present = present.clone();
+
+    let button_row = serde_json::to_string_pretty(&props.outbox)
+        .ok()
+        .map(Rc::new)
+        .map(|json| {
+            let copy_actions = props.actions.clone();
+            let export_actions = props.actions.clone();
+            let json_for_copy = json.clone();
+            let json_for_export = json.clone();
+            let filename = Rc::new("outbox-messages.json".to_string());
+            rsx! {
+                div { class: "flex flex-wrap gap-2",
+                    button {
+                        class: "rounded bg-slate-900 px-2 py-1 text-[11px] font-semibold text-white hover:bg-slate-800",
+                        onclick: move |_| copy_text_to_clipboard(copy_actions.clone(), "Outbox JSON", "ace:outbox:list", (*json_for_copy).clone()),
+                        "复制 Outbox JSON"
+                    }
+                    button {
+                        class: "rounded border border-slate-300 px-2 py-1 text-[11px] text-slate-700 hover:bg-slate-100",
+                        onclick: move |_| export_text_as_file(export_actions.clone(), "Outbox JSON", "ace:outbox:list", (*filename).clone(), (*json_for_export).clone()),
+                        "导出 Outbox JSON"
+                    }
+                }
+            }
+        });
+
+    rsx! {
+        details { class: "rounded border border-slate-200 bg-white p-3 text-xs text-slate-700 space-y-2",
+            summary { class: "cursor-pointer text-[11px] font-semibold text-slate-800", "{summary_label}" }
+            if rows.is_empty() {
+                p { class: "text-[11px] text-slate-500", "暂无 Outbox 信息" }
+            } else {
+                div { class: "flex flex-wrap items-center gap-2 text-[11px]",
+                    select {
+                        class: "rounded border border-slate-300 px-2 py-1 text-[11px] text-slate-600",
+                        value: "{type_value}",
+                        onchange: move |evt| type_filter.set(evt.value()),
+                        option { value: FILTER_ALL, "全部事件类型" }
+                        for label in type_options.iter() {
+                            option { value: "{label}", "{label}" }
+                        }
+                    }
+                    select {
+                        class: "rounded border border-slate-300 px-2 py-1 text-[11px] text-slate-600",
+                        value: "{degradation_value}",
+                        onchange: move |evt| degradation_filter.set(evt.value()),
+                        option { value: FILTER_ALL, "全部降级状态" }
+                        option { value: FILTER_DEGRADED, "仅降级" }
+                        option { value: FILTER_CLEAN, "无降级" }
+                        for label in degradation_options.iter() {
+                            option { value: "{label}", "{label}" }
+                        }
+                    }
+                    span { class: "text-slate-500", "显示 {visible.len()} / {rows.len()}" }
+                    if failed_count > 0 {
+                        span { class: "rounded bg-red-100 px-2 py-0.5 text-red-700", "{failed_count} 条无法解析" }
+                    }
+                    button {
+                        class: "rounded bg-emerald-600 px-2 py-1 text-[11px] font-semibold text-white hover:bg-emerald-500 disabled:opacity-50",
+                        disabled: pending_count == 0,
+                        onclick: move |_| {
+                            let refs: Vec<&InspectorRow> = bulk_rows.iter().collect();
+                            let added = push_to_timeline(&bulk_actions, &refs, &bulk_present);
+                            bulk_actions.set_operation_success(format!("已将 {added} 条 Outbox 事件加入时间线"));
+                        },
+                        "全部加入时间线 ({pending_count})"
+                    }
+                }
+                if visible.is_empty() {
+                    p { class: "text-[11px] text-slate-500 italic", "当前筛选条件下没有 Outbox 事件" }
+                }
+                div { class: "space-y-2",
+                    for row in visible.iter().cloned() {
+                        { render_inspector_row(row, &present, focused_event_id, props.actions.clone()) }
+                    }
+                }
+            }
+            if let Some(row) = button_row {
+                {row}
+            }
+        }
+    }
+}
+
+fn render_inspector_row(
+    row: InspectorRow,
+    present: &HashSet<u64>,
+    focused_event_id: Option<u64>,
+    actions: AppActions,
+) -> Element {
+    let decoded_id = row.decoded.event_id();
+    let in_timeline = decoded_id.is_some_and(|id| present.contains(&id));
+    let is_focused = decoded_id.is_some() && decoded_id == focused_event_id;
+    let occurred_at = row
+        .occurred_at_ms
+        .map(format_timestamp_ms)
+        .unwrap_or_else(|| "-".into());
+    let offset_label = row.offset_ms.map(|offset| {
+        if offset > 0 {
+            format!("同步点 +{offset} ms")
+        } else {
+            format!("同步点 {offset} ms")
+        }
+    });
+    let row_class = if is_focused {
+        "rounded border border-emerald-300 bg-emerald-50 p-2 space-y-1"
+    } else {
+        "rounded border border-slate-200 bg-slate-50 p-2 space-y-1"
+    };
+
+    let add_actions = actions.clone();
+    let add_row = row.clone();
+    let add_present = present.clone();
+    let jump_actions = actions.clone();
+    let jump_row = row.clone();
+    let jump_present = present.clone();
+
+    let event_buttons = row.event_json.clone().map(|json| {
+        let copy_actions = actions.clone();
+        let export_actions = actions.clone();
+        let json_for_copy = json.clone();
+        let json_for_export = json.clone();
+        let filename = Rc::new(format!("outbox-event-{}.json", row.event_id));
+        rsx! {
+            Fragment {
+                button {
+                    class: "rounded border border-slate-300 px-2 py-1 text-[11px] text-slate-700 hover:bg-slate-100",
+                    onclick: move |_| copy_text_to_clipboard(copy_actions.clone(), "Outbox Event JSON", "ace:outbox:event", (*json_for_copy).clone()),
+                    "复制事件 JSON"
+                }
+                button {
+                    class: "rounded border border-slate-300 px-2 py-1 text-[11px] text-slate-700 hover:bg-slate-100",
+                    onclick: move |_| export_text_as_file(export_actions.clone(), "Outbox Event JSON", "ace:outbox:event", (*filename).clone(), (*json_for_export).clone()),
+                    "导出事件 JSON"
+                }
+            }
+        }
+    });
+
+    let payload_buttons = row.payload_json.clone().map(|json| {
+        let copy_actions = actions.clone();
+        let export_actions = actions.clone();
+        let json_for_copy = json.clone();
+        let json_for_export = json.clone();
+        let filename = Rc::new(format!("outbox-payload-{}.json", row.event_id));
+        rsx! {
+            Fragment {
+                button {
+                    class: "rounded border border-slate-300 px-2 py-1 text-[11px] text-slate-700 hover:bg-slate-100",
+                    onclick: move |_| copy_text_to_clipboard(copy_actions.clone(), "Outbox Payload JSON", "ace:outbox:payload", (*json_for_copy).clone()),
+                    "复制 Payload"
+                }
+                button {
+                    class: "rounded border border-slate-300 px-2 py-1 text-[11px] text-slate-700 hover:bg-slate-100",
+                    onclick: move |_| export_text_as_file(export_actions.clone(), "Outbox Payload JSON", "ace:outbox:payload", (*filename).clone(), (*json_for_export).clone()),
+                    "导出 Payload"
+                }
+            }
+        }
+    });
+
+    rsx! {
+        div { class: row_class,
+            div { class: "flex flex-wrap items-center justify-between gap-2",
+                div { class: "flex flex-wrap items-center gap-2",
+                    span { class: "font-mono text-[11px] text-slate-500", "{row.order}." }
+                    span { class: "font-semibold", "{row.event_type}" }
+                    span { class: "rounded bg-slate-100 px-2 py-0.5 text-[10px] text-slate-600", "{row.decoded.type_label()}" }
+                    span { class: row.position.badge_class(), "{row.position.label()}" }
+                    if in_timeline {
+                        span { class: "rounded bg-indigo-100 px-2 py-0.5 text-[10px] text-indigo-700", "已在时间线" }
+                    }
+                }
+                span { class: "text-[11px] text-slate-500", "# {row.event_id}" }
+            }
+            div { class: "flex flex-wrap gap-3 text-[10px] text-slate-500",
+                span { "Cycle ID: {row.cycle_id}" }
+                span { "{occurred_at}" }
+                if let Some(offset) = offset_label {
+                    span { class: "font-mono", "{offset}" }
+                }
+            }
+            if let Some(degrade) = row.degradation.clone() {
+                p { class: "text-[10px] text-amber-600", "降级: {degrade}" }
+            }
+            p { class: "text-[11px] text-slate-600 break-words", "{row.payload_summary}" }
+            if !row.issues.is_empty() {
+                ul { class: "rounded border border-red-200 bg-red-50 p-2 text-[10px] text-red-700 space-y-0.5",
+                    for issue in row.issues.iter() {
+                        li { class: "break-all",
+                            span { class: "font-semibold", "{issue.target}" }
+                            span { class: "font-mono", " · {issue.field}" }
+                            ": {issue.message}"
+                        }
+                    }
+                }
+            }
+            div { class: "flex flex-wrap gap-2 pt-1",
+                button {
+                    class: "rounded bg-emerald-600 px-2 py-1 text-[11px] font-semibold text-white hover:bg-emerald-500 disabled:opacity-50",
+                    disabled: decoded_id.is_none() || in_timeline,
+                    onclick: move |_| {
+                        if push_to_timeline(&add_actions, &[&add_row], &add_present) > 0 {
+                            add_actions.set_operation_success(format!("Outbox 事件 {} 已写入时间线", add_row.event_id));
+                        }
+                    },
+                    "加入时间线"
+                }
+                button {
+                    class: "rounded border border-emerald-300 px-2 py-1 text-[11px] text-emerald-700 hover:bg-emerald-50 disabled:opacity-50",
+                    disabled: decoded_id.is_none(),
+                    onclick: move |_| {
+                        push_to_timeline(&jump_actions, &[&jump_row], &jump_present);
+                        jump_actions.focus_timeline_event(jump_row.decoded.event_id());
+                        if let Some(dom_id) = jump_row.decoded.dom_id() {
+                            scroll_to_element(dom_id);
+                        }
+                    },
+                    "定位事件"
+                }
+                if let Some(buttons) = event_buttons {
+                    {buttons}
+                }
+                if let Some(buttons) = payload_buttons {
+                    {buttons}
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,401 @@
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+
+use dioxus::prelude::*;
+
+use crate::hooks::metacognition::{pattern_filter_key, use_pattern_detection, HealthSample};
+use crate::models::{AnomalyInfo, DetectedPattern, PatternSummary};
+use crate::state::{use_app_actions, use_app_state, AppActions};
+use crate::ui::ace::format_timestamp_ms;
+use crate::ui::severity::Severity;
+use crate::ui::util::render_related_event;
+
+const PATTERN_LIMITS: &[u32] = &[20, 50, 100];
+const EXAMPLE_PREVIEW: usize = 3;
+const SPARKLINE_WIDTH: f32 = 240.0;
+const SPARKLINE_HEIGHT: f32 = 48.0;
+
+fn health_class(score: f32) -> &'static str {
+    if score >= 0.8 {
+        "text-green-600"
+    } else if score >= 0.5 {
+        "text-amber-600"
+    } else {
+        "text-red-600"
+    }
+}
+
+/// 模式与异常检测仪表盘
+#[component]
+pub fn PatternDashboard() -> Element {
+    let state_store = use_app_state();
+    let actions = use_app_actions();
+    let mut session_filter = use_signal(|| Option::<String>::None);
+    let mut type_filter = use_signal(|| Option::<String>::None);
+    let mut limit = use_signal(|| PATTERN_LIMITS[1]);
+    let mut run = use_signal(|| 0u32);
+    let mut known_types = use_signal(BTreeSet::<String>::new);
+
+    let detection = use_pattern_detection(
+        type_filter.read().clone(),
+        session_filter.read().clone(),
+        *limit.read(),
+        *run.read(),
+    );
+
+    // 记住出现过的模式类型，按类型筛选后下拉框仍能切换到其它类型
+    use_effect(move || {
+        let state = detection.read();
+        let Some(response) = state.response.as_ref() else {
+            return;
+        };
+        let unseen: Vec<String> = response
+            .patterns
+            .iter()
+            .map(|pattern| pattern.pattern_type.clone())
+            .filter(|pattern_type| !known_types.peek().contains(pattern_type))
+            .collect();
+        if !unseen.is_empty() {
+            known_types.write().extend(unseen);
+        }
+    });
+
+    let snapshot = state_store.read();
+    let timeline_ids: HashSet<u64> = snapshot
+        .timeline
+        .events
+        .iter()
+        .map(|event| event.event_id.as_u64())
+        .collect();
+    let mut session_options: Vec<(String, String)> = Vec::new();
+    if let Some(current) = snapshot.session_id.clone() {
+        session_options.push((current.clone(), format!("当前会话 {current}")));
+    }
+    for session in snapshot
+        .workspace
+        .tenants
+        .iter()
+        .flat_map(|tenant| tenant.pinned_sessions.iter())
+    {
+        if session_options
+            .iter()
+            .any(|(id, _)| *id == session.session_id)
+        {
+            continue;
+        }
+        let title = session
+            .title
+            .clone()
+            .unwrap_or_else(|| session.session_id.clone());
+        session_options.push((session.session_id.clone(), title));
+    }
+    drop(snapshot);
+
+    let session_value = session_filter.read().clone().unwrap_or_default();
+    let type_value = type_filter.read().clone().unwrap_or_default();
+    let limit_value = *limit.read();
+    let type_options: Vec<String> = known_types.read().iter().cloned().collect();
+    let filter_key = pattern_filter_key(
+        type_filter.read().as_deref(),
+        session_filter.read().as_deref(),
+    );
+
+    let body = {
+        let state = detection.read();
+        let trend: Vec<HealthSample> = state
+            .health_history
+            .iter()
+            .filter(|sample| sample.filter_key == filter_key)
+            .cloned()
+            .collect();
+        if state.loading && state.response.is_none() {
+            rsx! { p { class: "text-xs text-slate-500", "正在检测模式..." } }
+        } else if let Some(ref err) = state.error {
+            rsx! { p { class: "text-xs text-red-500", "{err}" } }
+        } else if let Some(ref response) = state.response {
+            rsx! {
+                div { class: "space-y-4",
+                    div { class: "grid gap-3 md:grid-cols-2",
+                        {render_summary(&response.summary)}
+                        {render_health_trend(&trend)}
+                    }
+                    {render_pattern_groups(&response.patterns)}
+                    {render_anomaly_feed(&response.anomalies, &timeline_ids, &actions)}
+                }
+            }
+        } else {
+            rsx! { p { class: "text-xs text-slate-500 italic", "暂无模式检测数据" } }
+        }
+    };
+    let loading = detection.read().loading;
+
+    rsx! {
+        div { class: "rounded-lg border border-slate-200 bg-white p-4 shadow-sm space-y-3",
+            h3 { class: "text-sm font-semibold text-slate-800", "模式与异常检测" }
+            div { class: "flex flex-wrap items-end gap-2 text-xs",
+                div { class: "flex flex-col gap-1",
+                    label { class: "text-[11px] font-semibold text-slate-600", "会话" }
+                    select {
+                        class: "rounded border border-slate-300 px-2 py-1",
+                        onchange: move |evt: FormEvent| {
+                            let value = evt.value();
+                            session_filter.set((!value.is_empty()).then_some(value));
+                        },
+                        option { value: "", selected: session_value.is_empty(), "全部会话" }
+                        for (session_id, label) in session_options.iter() {
+                            option {
+                                value: "{session_id}",
+                                selected: session_value == *session_id,
+                                "{label}"
+                            }
+                        }
+                    }
+                }
+                div { class: "flex flex-col gap-1",
+                    label { class: "text-[11px] font-semibold text-slate-600", "模式类型" }
+                    select {
+                        class: "rounded border border-slate-300 px-2 py-1",
+                        onchange: move |evt: FormEvent| {
+                            let value = evt.value();
+                            type_filter.set((!value.is_empty()).then_some(value));
+                        },
+                        option { value: "", selected: type_value.is_empty(), "全部类型" }
+                        for pattern_type in type_options.iter() {
+                            option {
+                                value: "{pattern_type}",
+                                selected: type_value == *pattern_type,
+                                "{pattern_type}"
+                            }
+                        }
+                    }
+                }
+                div { class: "flex flex-col gap-1",
+                    label { class: "text-[11px] font-semibold text-slate-600", "数量上限" }
+                    select {
+                        class: "rounded border border-slate-300 px-2 py-1",
+                        onchange: move |evt: FormEvent| {
+                            if let Ok(value) = evt.value().parse::<u32>() {
+                                limit.set(value);
+                            }
+                        },
+                        for value in PATTERN_LIMITS.iter() {
+                            option { value: "{value}", selected: limit_value == *value, "{value}" }
+                        }
+                    }
+                }
+                button {
+                    class: "rounded bg-slate-900 px-3 py-1.5 font-semibold text-white hover:bg-slate-800 disabled:opacity-50",
+                    disabled: loading,
+                    onclick: move |_| {
+                        let next = run.peek().wrapping_add(1);
+                        run.set(next);
+                    },
+                    if loading { "检测中..." } else { "重新检测" }
+                }
+            }
+            {body}
+        }
+    }
+}
+
+fn render_summary(summary: &PatternSummary) -> Element {
+    let score_class = health_class(summary.health_score);
+    let score_pct = summary.health_score * 100.0;
+
+    rsx! {
+        div { class: "rounded border border-slate-100 bg-slate-50 p-3 space-y-2 text-xs text-slate-600",
+            div { class: "flex items-baseline gap-2",
+                span { class: "text-slate-500", "健康度" }
+                span { class: "text-2xl font-semibold {score_class}", {format!("{score_pct:.0}")} }
+                span { class: "text-slate-400", "/ 100" }
+            }
+            div { class: "flex flex-wrap gap-3",
+                span { "模式 {summary.total_patterns}" }
+                span { "异常 {summary.total_anomalies}" }
+                if let Some(ref dominant) = summary.dominant_pattern_type {
+                    span { "主导类型 "
+                        span { class: "rounded bg-slate-200 px-1.5 py-0.5 text-slate-700", "{dominant}" }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// 同一筛选条件下多次检测的健康度折线
+fn render_health_trend(samples: &[HealthSample]) -> Element {
+    if samples.len() < 2 {
+        return rsx! {
+            div { class: "rounded border border-dashed border-slate-200 p-3 text-[11px] italic text-slate-400",
+                "重复检测后将在此显示健康度趋势"
+            }
+        };
+    }
+
+    let step = SPARKLINE_WIDTH / (samples.len() - 1) as f32;
+    let points = samples
+        .iter()
+        .enumerate()
+        .map(|(idx, sample)| {
+            let y = (1.0 - sample.health_score.clamp(0.0, 1.0)) * SPARKLINE_HEIGHT;
+            format!("{:.1},{:.1}", idx as f32 * step, y)
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+    let last = &samples[samples.len() - 1];
+    let previous = &samples[samples.len() - 2];
+    let delta = (last.health_score - previous.health_score) * 100.0;
+    let delta_class = if delta >= 0.0 {
+        "text-green-600"
+    } else {
+        "text-red-600"
+    };
+    let first_at = format_timestamp_ms(samples[0].recorded_at_ms);
+    let last_at = format_timestamp_ms(last.recorded_at_ms);
+    let view_box = format!("0 0 {SPARKLINE_WIDTH} {SPARKLINE_HEIGHT}");
+
+    rsx! {
+        div { class: "rounded border border-slate-100 p-3 space-y-1 text-xs text-slate-600",
+            div { class: "flex items-center justify-between",
+                span { class: "font-semibold text-slate-700", {format!("健康度趋势（{} 次）", samples.len())} }
+                span { class: "font-mono {delta_class}", {format!("{delta:+.1}")} }
+            }
+            svg {
+                class: "h-12 w-full",
+                view_box: "{view_box}",
+                preserve_aspect_ratio: "none",
+                polyline {
+                    points: "{points}",
+                    fill: "none",
+                    stroke: "#2563eb",
+                    stroke_width: "1.5",
+                }
+            }
+            div { class: "flex justify-between text-[10px] text-slate-400",
+                span { "{first_at}" }
+                span { "{last_at}" }
+            }
+        }
+    }
+}
+
+fn render_pattern_groups(patterns: &[DetectedPattern]) -> Element {
+    if patterns.is_empty() {
+        return rsx! { p { class: "text-[11px] italic text-slate-400", "未检测到模式" } };
+    }
+
+    let mut groups: BTreeMap<&str, Vec<&DetectedPattern>> = BTreeMap::new();
+    for pattern in patterns.iter() {
+        groups
+            .entry(pattern.pattern_type.as_str())
+            .or_default()
+            .push(pattern);
+    }
+    for group in groups.values_mut() {
+        group.sort_by(|a, b| b.frequency.cmp(&a.frequency));
+    }
+
+    rsx! {
+        div { class: "space-y-3 text-xs text-slate-600",
+            span { class: "font-semibold text-slate-700", "检测到的模式" }
+            for (pattern_type, group) in groups.iter() {
+                {
+                    let total: u32 = group.iter().map(|pattern| pattern.frequency).sum();
+                    rsx! {
+                        div { class: "space-y-1",
+                            div { class: "flex items-center gap-2",
+                                span { class: "rounded bg-indigo-100 px-2 py-0.5 text-indigo-700", "{pattern_type}" }
+                                span { class: "text-[11px] text-slate-400", {format!("{} 个模式 · 共出现 {} 次", group.len(), total)} }
+                            }
+                            for pattern in group.iter() {
+                                {render_pattern(pattern)}
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn render_pattern(pattern: &DetectedPattern) -> Element {
+    let confidence_pct = (pattern.confidence.clamp(0.0, 1.0) * 100.0) as u32;
+    let first_seen = format_timestamp_ms(pattern.first_seen_at_ms);
+    let last_seen = format_timestamp_ms(pattern.last_seen_at_ms);
+    let hidden_examples = pattern.examples.len().saturating_sub(EXAMPLE_PREVIEW);
+
+    rsx! {
+        div { class: "rounded border border-slate-100 bg-slate-50 p-2 space-y-1",
+            div { class: "flex flex-wrap items-center gap-2",
+                span { class: "flex-1 text-slate-700", "{pattern.description}" }
+                span { class: "font-mono text-slate-500", "×{pattern.frequency}" }
+                span { class: "flex items-center gap-1 text-slate-500",
+                    span { class: "inline-block h-1.5 w-16 overflow-hidden rounded bg-slate-200",
+                        span { class: "block h-1.5 bg-indigo-500", style: "width: {confidence_pct}%" }
+                    }
+                    "{confidence_pct}%"
+                }
+            }
+            div { class: "flex flex-wrap gap-3 text-[11px] text-slate-400",
+                span { "首次 {first_seen}" }
+                span { "最近 {last_seen}" }
+            }
+            if !pattern.examples.is_empty() {
+                ul { class: "list-disc pl-4 text-[11px] text-slate-500",
+                    for example in pattern.examples.iter().take(EXAMPLE_PREVIEW) {
+                        li { class: "truncate", title: "{example}", "{example}" }
+                    }
+                    if hidden_examples > 0 {
+                        li { class: "list-none italic text-slate-400", "另有 {hidden_examples} 个示例" }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn render_anomaly_feed(
+    anomalies: &[AnomalyInfo],
+    timeline_ids: &HashSet<u64>,
+    actions: &AppActions,
+) -> Element {
+    if anomalies.is_empty() {
+        return rsx! { p { class: "text-[11px] italic text-slate-400", "未检测到异常" } };
+    }
+
+    let mut sorted: Vec<&AnomalyInfo> = anomalies.iter().collect();
+    sorted.sort_by(|a, b| {
+        Severity::parse(&a.severity)
+            .cmp(&Severity::parse(&b.severity))
+            .then(b.detected_at_ms.cmp(&a.detected_at_ms))
+    });
+
+    rsx! {
+        div { class: "space-y-2 text-xs text-slate-600",
+            span { class: "font-semibold text-slate-700", "异常动态" }
+            for anomaly in sorted.iter() {
+                {
+                    let badge = Severity::parse(&anomaly.severity).badge_class();
+                    let detected_at = format_timestamp_ms(anomaly.detected_at_ms);
+                    rsx! {
+                        div { class: "rounded border border-slate-100 p-2 space-y-1",
+                            div { class: "flex flex-wrap items-center gap-2",
+                                span { class: "rounded px-2 py-0.5 text-[11px] {badge}", "{anomaly.severity}" }
+                                span { class: "font-medium text-slate-700", "{anomaly.anomaly_type}" }
+                                span { class: "ml-auto text-[11px] text-slate-400", "{detected_at}" }
+                            }
+                            p { "{anomaly.description}" }
+                            if !anomaly.related_events.is_empty() {
+                                div { class: "flex flex-wrap items-center gap-1 text-[11px]",
+                                    span { class: "text-slate-400", "相关事件:" }
+                                    for event_id in anomaly.related_events.iter() {
+                                        {render_related_event(event_id, timeline_ids, actions)}
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,396 @@
+use crate::hooks::metacognition::{use_performance_profiles, ProfileLoad};
+use crate::models::{
+    AceCycleSummary, BottleneckInfo, LatencyBreakdown, PerformanceComparison,
+    PerformanceProfileResponse, ResourceUsageInfo,
+};
+use crate::ui::severity::Severity;
+use dioxus::prelude::*;
+
+/// 瀑布图中的一段延迟
+struct LatencySegment {
+    label: &'static str,
+    color: &'static str,
+    ms: u64,
+}
+
+/// 按 AC 执行顺序排列：上下文装配 → 路由决策 → LLM → 工具 → 其他
+fn latency_segments(breakdown: &LatencyBreakdown) -> Vec<LatencySegment> {
+    vec![
+        LatencySegment {
+            label: "上下文装配",
+            color: "bg-sky-400",
+            ms: breakdown.context_assembly_ms,
+        },
+        LatencySegment {
+            label: "路由决策",
+            color: "bg-violet-400",
+            ms: breakdown.decision_routing_ms,
+        },
+        LatencySegment {
+            label: "LLM",
+            color: "bg-emerald-400",
+            ms: breakdown.llm_ms,
+        },
+        LatencySegment {
+            label: "工具执行",
+            color: "bg-amber-400",
+            ms: breakdown.tool_execution_ms,
+        },
+        LatencySegment {
+            label: "其他",
+            color: "bg-slate-400",
+            ms: breakdown.other_ms,
+        },
+    ]
+}
+
+/// 瀑布刻度：total_ms 与分段之和取较大者，避免分段溢出
+fn waterfall_span(breakdown: &LatencyBreakdown) -> u64 {
+    let sum: u64 = latency_segments(breakdown).iter().map(|seg| seg.ms).sum();
+    breakdown.total_ms.max(sum).max(1)
+}
+
+fn pct(part: u64, whole: u64) -> f64 {
+    if whole == 0 {
+        0.0
+    } else {
+        part as f64 / whole as f64 * 100.0
+    }
+}
+
+/// 相对值按比例返回（0.12 表示比基准慢 12%）
+fn format_relative(value: f32) -> String {
+    format!("{:+.1}%", value * 100.0)
+}
+
+fn format_delta_ms(delta: i64) -> String {
+    if delta > 0 {
+        format!("+{delta}ms")
+    } else {
+        format!("{delta}ms")
+    }
+}
+
+#[derive(Props, Clone)]
+#[props(no_eq)]
+pub struct PerformanceProfilePanelProps {
+    cycles: Vec<AceCycleSummary>,
+    selected_cycle_id: Option<String>,
+}
+
+impl PartialEq for PerformanceProfilePanelProps {
+    fn eq(&self, _other: &Self) -> bool {
+        false
+    }
+}
+
+impl Eq for PerformanceProfilePanelProps {}
+
+#[component]
+pub fn PerformanceProfilePanel(props: PerformanceProfilePanelProps) -> Element {
+    let mut compare_id = use_signal(|| Option::<String>::None);
+    let mut refresh = use_signal(|| 0u32);
+
+    let primary_id = props.selected_cycle_id.clone();
+    let compare_value = compare_id
+        .read()
+        .clone()
+        .filter(|id| Some(id) != primary_id.as_ref());
+    let requested: Vec<String> = primary_id
+        .iter()
+        .chain(compare_value.iter())
+        .cloned()
+        .collect();
+    let profiles = use_performance_profiles(requested, *refresh.read());
+
+    let Some(primary_id) = primary_id else {
+        return rsx! {};
+    };
+
+    let loaded = profiles.read();
+    let primary = loaded.get(&primary_id).cloned().unwrap_or_default();
+    let compare = compare_value
+        .as_ref()
+        .map(|id| (id.clone(), loaded.get(id).cloned().unwrap_or_default()));
+    drop(loaded);
+
+    // 对比时两条瀑布共用刻度，长度才可直接比较
+    let span = [
+        primary.profile.as_ref(),
+        compare.as_ref().and_then(|(_, load)| load.profile.as_ref()),
+    ]
+    .into_iter()
+    .flatten()
+    .map(|profile| waterfall_span(&profile.latency_breakdown))
+    .max()
+    .unwrap_or(1);
+
+    let delta_rows = match (
+        primary.profile.as_ref(),
+        compare.as_ref().and_then(|(_, load)| load.profile.as_ref()),
+    ) {
+        (Some(left), Some(right)) => Some(segment_deltas(left, right)),
+        _ => None,
+    };
+    let compare_selected = compare_value.clone().unwrap_or_default();
+    let any_loading = primary.loading || compare.as_ref().is_some_and(|(_, load)| load.loading);
+    let any_failed = primary.error.is_some()
+        || compare
+            .as_ref()
+            .is_some_and(|(_, load)| load.error.is_some());
+    let columns_class = if compare.is_some() {
+        "grid gap-4 md:grid-cols-2"
+    } else {
+        "space-y-3"
+    };
+
+    rsx! {
+        div { class: "rounded-lg border border-slate-200 bg-white p-4 shadow-sm space-y-3",
+            div { class: "flex flex-wrap items-center justify-between gap-2",
+                h3 { class: "text-sm font-semibold text-slate-800", "性能剖析 · {primary_id}" }
+                div { class: "flex items-center gap-2",
+                    button {
+                        r#type: "button",
+                        class: "rounded border border-slate-300 px-2 py-1 text-xs text-slate-600 hover:bg-slate-100 disabled:opacity-50",
+                        disabled: any_loading,
+                        onclick: move |_| {
+                            let next = refresh.peek().wrapping_add(1);
+                            refresh.set(next);
+                        },
+                        if any_loading { "加载中…" } else if any_failed { "重试" } else { "刷新" }
+                    }
+                    label { class: "flex items-center gap-2 text-xs text-slate-600",
+                        span { "对比周期" }
+                        select {
+                            class: "rounded border border-slate-300 px-2 py-1",
+                            onchange: move |evt: FormEvent| {
+                                let value = evt.value();
+                                compare_id.set((!value.is_empty()).then_some(value));
+                            },
+                            option { value: "", selected: compare_selected.is_empty(), "不对比" }
+                            for cycle in props.cycles.iter().filter(|cycle| cycle.cycle_id != primary_id) {
+                                option {
+                                    value: "{cycle.cycle_id}",
+                                    selected: compare_selected == cycle.cycle_id,
+                                    "{cycle.cycle_id}"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            div { class: columns_class,
+                {render_profile_column(&primary_id, &primary, span)}
+                if let Some((compare_id, compare_load)) = compare.as_ref() {
+                    {render_profile_column(compare_id, compare_load, span)}
+                }
+            }
+
+            if let Some(rows) = delta_rows {
+                table { class: "w-full text-left text-[11px] text-slate-600",
+                    thead {
+                        tr { class: "text-slate-500",
+                            th { class: "py-1", "阶段" }
+                            th { class: "py-1", "{primary_id}" }
+                            th { class: "py-1", {compare_selected.clone()} }
+                            th { class: "py-1", "差值" }
+                        }
+                    }
+                    tbody {
+                        for (label, left, right) in rows.iter() {
+                            {
+                                let delta = *right as i64 - *left as i64;
+                                let delta_class = if delta > 0 {
+                                    "py-1 font-mono text-red-600"
+                                } else if delta < 0 {
+                                    "py-1 font-mono text-emerald-600"
+                                } else {
+                                    "py-1 font-mono text-slate-400"
+                                };
+                                rsx! {
+                                    tr { class: "border-t border-slate-100",
+                                        td { class: "py-1", "{label}" }
+                                        td { class: "py-1 font-mono", "{left}ms" }
+                                        td { class: "py-1 font-mono", "{right}ms" }
+                                        td { class: delta_class, {format_delta_ms(delta)} }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// (阶段, 主周期 ms, 对比周期 ms)，最后一行为总计
+fn segment_deltas(
+    left: &PerformanceProfileResponse,
+    right: &PerformanceProfileResponse,
+) -> Vec<(&'static str, u64, u64)> {
+    let mut rows: Vec<(&'static str, u64, u64)> = latency_segments(&left.latency_breakdown)
+        .into_iter()
+        .zip(latency_segments(&right.latency_breakdown))
+        .map(|(l, r)| (l.label, l.ms, r.ms))
+        .collect();
+    rows.push((
+        "总计",
+        left.latency_breakdown.total_ms,
+        right.latency_breakdown.total_ms,
+    ));
+    rows
+}
+
+fn render_profile_column(ac_id: &str, load: &ProfileLoad, span: u64) -> Element {
+    if load.loading {
+        return rsx! { p { class: "text-xs text-slate-500", "正在加载 {ac_id} 的性能剖析..." } };
+    }
+    if let Some(err) = load.error.as_ref() {
+        return rsx! { p { class: "text-xs text-red-500", "{ac_id}: {err}" } };
+    }
+    let Some(profile) = load.profile.as_ref() else {
+        return rsx! { p { class: "text-xs text-slate-500 italic", "{ac_id} 暂无性能剖析数据" } };
+    };
+
+    rsx! {
+        div { class: "space-y-3 text-xs text-slate-600",
+            {render_waterfall(&profile.latency_breakdown, span)}
+            {render_resource_usage(&profile.resource_usage)}
+            if let Some(comparison) = profile.comparison.as_ref() {
+                {render_comparison(comparison)}
+            }
+            {render_bottlenecks(&profile.bottlenecks)}
+        }
+    }
+}
+
+fn render_waterfall(breakdown: &LatencyBreakdown, span: u64) -> Element {
+    let segments = latency_segments(breakdown);
+    let mut offset = 0u64;
+    let rows: Vec<(LatencySegment, f64)> = segments
+        .into_iter()
+        .map(|segment| {
+            let start = pct(offset, span);
+            offset += segment.ms;
+            (segment, start)
+        })
+        .collect();
+    let total_pct = pct(breakdown.total_ms, span);
+
+    rsx! {
+        div { class: "space-y-1",
+            div { class: "flex items-center justify-between",
+                span { class: "font-semibold text-slate-700", "延迟瀑布" }
+                span { class: "font-mono", "总计 {breakdown.total_ms}ms" }
+            }
+            for (segment, start) in rows.iter() {
+                div { class: "flex items-center gap-2",
+                    span { class: "w-20 shrink-0 text-[11px] text-slate-500", "{segment.label}" }
+                    div { class: "relative h-3 flex-1 rounded bg-slate-100",
+                        div {
+                            class: "absolute top-0 h-3 rounded {segment.color}",
+                            style: "left: {start:.2}%; width: {pct(segment.ms, span):.2}%",
+                            title: "{segment.label} · {segment.ms}ms",
+                        }
+                        div {
+                            class: "absolute top-0 h-3 border-l border-dashed border-slate-500",
+                            style: "left: {total_pct:.2}%",
+                        }
+                    }
+                    span { class: "w-24 shrink-0 text-right font-mono text-[11px]",
+                        {format!("{}ms · {:.0}%", segment.ms, pct(segment.ms, breakdown.total_ms.max(1)))}
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn render_resource_usage(usage: &ResourceUsageInfo) -> Element {
+    let token_pct = pct(u64::from(usage.tokens_used), u64::from(usage.tokens_limit));
+    let bar_color = if token_pct >= 90.0 {
+        "bg-red-500"
+    } else if token_pct >= 70.0 {
+        "bg-amber-500"
+    } else {
+        "bg-emerald-500"
+    };
+
+    rsx! {
+        div { class: "space-y-1",
+            span { class: "font-semibold text-slate-700", "资源使用" }
+            div { class: "flex items-center gap-2",
+                span { class: "w-20 shrink-0 text-[11px] text-slate-500", "Tokens" }
+                div { class: "h-2 flex-1 overflow-hidden rounded bg-slate-100",
+                    div { class: "h-2 {bar_color}", style: "width: {token_pct.min(100.0):.1}%" }
+                }
+                span { class: "w-24 shrink-0 text-right font-mono text-[11px]",
+                    "{usage.tokens_used} / {usage.tokens_limit}"
+                }
+            }
+            div { class: "flex flex-wrap gap-2 text-[11px]",
+                span { class: "rounded bg-violet-100 px-2 py-0.5 font-mono text-violet-700", {format!("成本 ${:.4}", usage.cost_usd)} }
+                if let Some(memory) = usage.memory_mb {
+                    span { class: "rounded bg-slate-100 px-2 py-0.5 font-mono text-slate-600", {format!("内存 {memory:.1} MB")} }
+                }
+                if token_pct > 100.0 {
+                    span { class: "rounded bg-red-100 px-2 py-0.5 text-red-700", "超出 Token 上限" }
+                }
+            }
+        }
+    }
+}
+
+fn render_comparison(comparison: &PerformanceComparison) -> Element {
+    let avg_class = if comparison.vs_average > 0.0 {
+        "text-red-600"
+    } else {
+        "text-emerald-600"
+    };
+
+    rsx! {
+        div { class: "flex flex-wrap items-center gap-3 rounded border border-slate-100 bg-slate-50 p-2",
+            div { class: "text-center",
+                p { class: "text-lg font-bold text-blue-600", "P{comparison.percentile}" }
+                p { class: "text-[11px] text-slate-500", "历史分位" }
+            }
+            div { class: "text-center",
+                p { class: "text-sm font-semibold {avg_class}", {format_relative(comparison.vs_average)} }
+                p { class: "text-[11px] text-slate-500", "较历史平均" }
+            }
+            div { class: "text-center",
+                p { class: "text-sm font-semibold text-slate-700", {format_relative(comparison.vs_best)} }
+                p { class: "text-[11px] text-slate-500", "较历史最佳" }
+            }
+        }
+    }
+}
+
+fn render_bottlenecks(bottlenecks: &[BottleneckInfo]) -> Element {
+    if bottlenecks.is_empty() {
+        return rsx! { p { class: "text-[11px] italic text-slate-400", "未发现瓶颈" } };
+    }
+
+    let mut sorted: Vec<&BottleneckInfo> = bottlenecks.iter().collect();
+    sorted.sort_by(|a, b| b.latency_ms.cmp(&a.latency_ms));
+
+    rsx! {
+        div { class: "space-y-1",
+            span { class: "font-semibold text-slate-700", "瓶颈" }
+            for bottleneck in sorted.iter() {
+                div { class: "rounded border border-slate-100 p-2 space-y-0.5",
+                    div { class: "flex flex-wrap items-center gap-2",
+                        span { class: "rounded px-2 py-0.5 text-[11px] {Severity::parse(&bottleneck.severity).badge_class()}", "{bottleneck.severity}" }
+                        span { class: "font-medium text-slate-800", "{bottleneck.component}" }
+                        span { class: "font-mono text-[11px] text-slate-500",
+                            {format!("{}ms · {:.1}%", bottleneck.latency_ms, bottleneck.percentage)}
+                        }
+                    }
+                    p { class: "text-[11px] text-slate-600", "建议: {bottleneck.suggestion}" }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,322 @@
+use crate::models::{AceCycleSummary, AwarenessFork, CycleSnapshotView, RouterDecisionView};
+use crate::ui::ace::{
+    collect_degradation_reasons, extract_fork_scores, fork_display_name, ROUTER_FORKS,
+};
+use dioxus::prelude::*;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+struct ForkBar {
+    fork: AwarenessFork,
+    score: Option<f32>,
+    width_pct: f32,
+    degradation: Option<String>,
+    is_selected: bool,
+}
+
+fn build_fork_bars(decision: &RouterDecisionView) -> Vec<ForkBar> {
+    let scores = extract_fork_scores(&decision.plan.explain.diagnostics);
+    let degradation = collect_degradation_reasons(decision);
+    let max_score = scores
+        .values()
+        .map(|info| info.score.abs())
+        .fold(0.0_f32, f32::max);
+
+    ROUTER_FORKS
+        .iter()
+        .map(|fork| {
+            let score = scores.get(fork).map(|info| info.score);
+            let width_pct = match score {
+                Some(value) if max_score > 0.0 => (value.abs() / max_score * 100.0).min(100.0),
+                _ => 0.0,
+            };
+            ForkBar {
+                fork: *fork,
+                score,
+                width_pct,
+                degradation: degradation.get(fork).cloned(),
+                is_selected: *fork == decision.plan.fork,
+            }
+        })
+        .collect()
+}
+
+/// 路由拒绝列表：合并 decision 与 explain 中的条目并去重
+fn merged_rejections(decision: &RouterDecisionView) -> Vec<(String, String)> {
+    let mut seen = BTreeSet::new();
+    decision
+        .rejected
+        .iter()
+        .chain(decision.plan.explain.rejected.iter())
+        .filter(|entry| seen.insert((*entry).clone()))
+        .cloned()
+        .collect()
+}
+
+#[derive(Clone)]
+struct QueryRouteEntry {
+    cycle_id: String,
+    fork: AwarenessFork,
+    router_config_digest: String,
+    score: Option<f32>,
+}
+
+struct QueryRouteGroup {
+    query_hash: String,
+    entries: Vec<QueryRouteEntry>,
+}
+
+impl QueryRouteGroup {
+    fn distinct_forks(&self) -> usize {
+        let forks: BTreeSet<&'static str> = self
+            .entries
+            .iter()
+            .map(|entry| fork_display_name(&entry.fork))
+            .collect();
+        forks.len()
+    }
+
+    /// 同一配置版本下路由是否一致；返回 (config_digest, 分叉数)
+    fn per_config_consistency(&self) -> Vec<(String, usize, usize)> {
+        let mut grouped: BTreeMap<String, Vec<&QueryRouteEntry>> = BTreeMap::new();
+        for entry in self.entries.iter() {
+            grouped
+                .entry(entry.router_config_digest.clone())
+                .or_default()
+                .push(entry);
+        }
+        grouped
+            .into_iter()
+            .map(|(digest, entries)| {
+                let forks: BTreeSet<&'static str> = entries
+                    .iter()
+                    .map(|entry| fork_display_name(&entry.fork))
+                    .collect();
+                (digest, entries.len(), forks.len())
+            })
+            .collect()
+    }
+}
+
+fn group_by_query_hash(
+    cycle_ids: &[String],
+    snapshots: &HashMap<String, CycleSnapshotView>,
+) -> Vec<QueryRouteGroup> {
+    let mut groups: BTreeMap<String, Vec<QueryRouteEntry>> = BTreeMap::new();
+    for cycle_id in cycle_ids {
+        let Some(decision) = snapshots
+            .get(cycle_id)
+            .and_then(|snapshot| snapshot.schedule.router_decision.as_ref())
+        else {
+            continue;
+        };
+        let Some(query_hash) = decision.plan.explain.query_hash.clone() else {
+            continue;
+        };
+        let score = extract_fork_scores(&decision.plan.explain.diagnostics)
+            .get(&decision.plan.fork)
+            .map(|info| info.score);
+        groups.entry(query_hash).or_default().push(QueryRouteEntry {
+            cycle_id: cycle_id.clone(),
+            fork: decision.plan.fork,
+            router_config_digest: decision.plan.explain.router_config_digest.clone(),
+            score,
+        });
+    }
+
+    groups
+        .into_iter()
+        .map(|(query_hash, entries)| QueryRouteGroup {
+            query_hash,
+            entries,
+        })
+        .collect()
+}
+
+#[derive(Props, Clone)]
+#[props(no_eq)]
+pub struct RouterExplorerPanelProps {
+    cycles: Vec<AceCycleSummary>,
+    snapshots: HashMap<String, CycleSnapshotView>,
+    selected_cycle_id: Option<String>,
+}
+
+impl PartialEq for RouterExplorerPanelProps {
+    fn eq(&self, _other: &Self) -> bool {
+        false
+    }
+}
+
+impl Eq for RouterExplorerPanelProps {}
+
+#[component]
+pub fn RouterExplorerPanel(props: RouterExplorerPanelProps) -> Element {
+    let mut focus_cycle = use_signal(|| Option::<String>::None);
+
+    let mut routed_ids: Vec<String> = props
+        .cycles
+        .iter()
+        .map(|cycle| cycle.cycle_id.clone())
+        .filter(|id| props.snapshots.contains_key(id))
+        .collect();
+    for id in props.snapshots.keys() {
+        if !routed_ids.contains(id) {
+            routed_ids.push(id.clone());
+        }
+    }
+    routed_ids.retain(|id| {
+        props
+            .snapshots
+            .get(id)
+            .is_some_and(|snapshot| snapshot.schedule.router_decision.is_some())
+    });
+
+    if routed_ids.is_empty() {
+        return rsx! {
+            div { class: "rounded-lg border border-slate-200 bg-white p-4 shadow-sm space-y-1",
+                h3 { class: "text-sm font-semibold text-slate-800", "路由决策浏览" }
+                p { class: "text-xs text-slate-500 italic", "已加载的周期中暂无路由决策" }
+            }
+        };
+    }
+
+    let current_id = focus_cycle
+        .read()
+        .clone()
+        .filter(|id| routed_ids.contains(id))
+        .or_else(|| {
+            props
+                .selected_cycle_id
+                .clone()
+                .filter(|id| routed_ids.contains(id))
+        })
+        .unwrap_or_else(|| routed_ids[0].clone());
+    let decision = props
+        .snapshots
+        .get(&current_id)
+        .and_then(|snapshot| snapshot.schedule.router_decision.clone());
+    let groups = group_by_query_hash(&routed_ids, &props.snapshots);
+
+    rsx! {
+        div { class: "rounded-lg border border-slate-200 bg-white p-4 shadow-sm space-y-3 text-xs text-slate-700",
+            header { class: "flex flex-wrap items-center justify-between gap-2",
+                h3 { class: "text-sm font-semibold text-slate-800", "路由决策浏览" }
+                select {
+                    class: "rounded border border-slate-300 px-2 py-1 text-[11px] text-slate-600",
+                    value: "{current_id}",
+                    onchange: move |evt| focus_cycle.set(Some(evt.value())),
+                    for id in routed_ids.iter() {
+                        option { value: "{id}", "#{id}" }
+                    }
+                }
+            }
+
+            if let Some(decision) = decision.as_ref() {
+                { render_decision_breakdown(decision) }
+            }
+
+            div { class: "space-y-2",
+                h4 { class: "text-[11px] font-semibold uppercase tracking-wide text-slate-500", "按 Query Hash 聚合" }
+                if groups.is_empty() {
+                    p { class: "text-[11px] text-slate-500 italic", "路由决策中没有 query_hash" }
+                }
+                for group in groups.iter() {
+                    {
+                        let distinct = group.distinct_forks();
+                        let consistency = group.per_config_consistency();
+                        let (badge_class, badge_label) = if distinct <= 1 {
+                            ("rounded bg-emerald-100 px-2 py-0.5 text-emerald-700", "路由一致")
+                        } else {
+                            ("rounded bg-amber-100 px-2 py-0.5 text-amber-700", "路由分歧")
+                        };
+                        rsx! {
+                            div { class: "rounded border border-slate-100 bg-slate-50 p-3 space-y-2",
+                                div { class: "flex flex-wrap items-center justify-between gap-2",
+                                    span { class: "font-mono text-[11px] break-all", "{group.query_hash}" }
+                                    div { class: "flex items-center gap-2 text-[11px]",
+                                        span { class: "text-slate-500", "{group.entries.len()} 个周期 · {consistency.len()} 个配置版本" }
+                                        span { class: badge_class, "{badge_label}" }
+                                    }
+                                }
+                                ul { class: "space-y-1 text-[11px]",
+                                    for (digest, count, forks) in consistency.iter() {
+                                        li { class: "flex flex-wrap items-center gap-2",
+                                            span { class: "font-mono text-slate-500 break-all", "config {digest}" }
+                                            span { "{count} 次" }
+                                            if *forks > 1 {
+                                                span { class: "text-amber-700", "同配置下出现 {forks} 种分叉" }
+                                            }
+                                        }
+                                    }
+                                }
+                                table { class: "w-full text-left text-[11px]",
+                                    tbody {
+                                        for entry in group.entries.iter() {
+                                            tr { class: "border-t border-slate-200",
+                                                td { class: "py-1 font-mono", "#{entry.cycle_id}" }
+                                                td { class: "py-1", "{fork_display_name(&entry.fork)}" }
+                                                td { class: "py-1 font-mono",
+                                                    {entry.score.map(|score| format!("{score:.2}")).unwrap_or_else(|| "--".into())}
+                                                }
+                                                td { class: "py-1 font-mono text-slate-500 break-all", "{entry.router_config_digest}" }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn render_decision_breakdown(decision: &RouterDecisionView) -> Element {
+    let bars = build_fork_bars(decision);
+    let rejections = merged_rejections(decision);
+    let explain = &decision.plan.explain;
+
+    rsx! {
+        div { class: "space-y-3",
+            div { class: "grid gap-1 rounded border border-slate-100 bg-slate-50 p-3 font-mono text-[11px] md:grid-cols-3",
+                p { class: "break-all", "routing_seed: {explain.routing_seed}" }
+                p { class: "break-all", "router_digest: {explain.router_digest}" }
+                p { class: "break-all", "router_config_digest: {explain.router_config_digest}" }
+            }
+            div { class: "space-y-2",
+                for bar in bars.iter() {
+                    div { class: "space-y-0.5",
+                        div { class: "flex items-center justify-between text-[11px]",
+                            span { class: if bar.is_selected { "font-semibold text-slate-900" } else { "text-slate-600" },
+                                "{fork_display_name(&bar.fork)}"
+                                if bar.is_selected { " · 已选" }
+                            }
+                            span { class: "font-mono text-slate-500",
+                                {bar.score.map(|score| format!("{score:.2}")).unwrap_or_else(|| "--".into())}
+                            }
+                        }
+                        div { class: "h-3 w-full rounded bg-slate-100",
+                            div {
+                                class: if bar.is_selected { "h-3 rounded bg-slate-900" } else { "h-3 rounded bg-slate-400" },
+                                style: "width: {bar.width_pct:.1}%",
+                            }
+                        }
+                        if let Some(reason) = bar.degradation.as_ref() {
+                            p { class: "text-[10px] text-amber-600", "降级: {reason}" }
+                        }
+                    }
+                }
+            }
+            if rejections.is_empty() {
+                p { class: "text-[11px] text-slate-500 italic", "没有被拒绝的候选" }
+            } else {
+                div { class: "rounded border border-amber-200 bg-amber-50 p-3 text-[11px] text-amber-700 space-y-1",
+                    h5 { class: "font-semibold", "Rejected 候选 ({rejections.len()})" }
+                    for (code, reason) in rejections.iter() {
+                        p { span { class: "font-mono", "{code}" } ": {reason}" }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,341 @@
+//! 场景栈时间线
+//!
+//! 将自主会话的场景栈绘制为按深度嵌套的入栈/出栈区间，并统计各场景出现频次
+
+use std::collections::HashSet;
+
+use dioxus::prelude::*;
+
+use crate::hooks::autonomous::{use_scenario_stack, ScenarioSpan};
+use crate::state::{use_app_actions, use_app_state};
+use crate::ui::util::{format_clock, render_related_event};
+
+const LANE_HEIGHT_PX: u32 = 22;
+const MIN_SPAN_WIDTH_PCT: f64 = 0.8;
+
+const SCENARIO_PALETTE: &[&str] = &[
+    "bg-blue-500",
+    "bg-emerald-500",
+    "bg-amber-500",
+    "bg-purple-500",
+    "bg-rose-500",
+    "bg-cyan-500",
+    "bg-lime-500",
+    "bg-orange-500",
+];
+
+/// 同一场景在时间线与频次图中使用相同颜色
+fn scenario_color(scenario: &str) -> &'static str {
+    let hash = scenario.bytes().fold(0usize, |acc, byte| {
+        acc.wrapping_mul(31).wrapping_add(byte as usize)
+    });
+    SCENARIO_PALETTE[hash % SCENARIO_PALETTE.len()]
+}
+
+fn format_span_duration(ms: i64) -> String {
+    let secs = ms.max(0) / 1000;
+    if secs < 60 {
+        format!("{secs} 秒")
+    } else if secs < 3600 {
+        format!("{} 分 {} 秒", secs / 60, secs % 60)
+    } else {
+        format!("{:.1} 小时", secs as f64 / 3600.0)
+    }
+}
+
+/// 时间线上一段区间的绘制参数
+struct SpanBar {
+    left_pct: f64,
+    width_pct: f64,
+    class: String,
+    title: String,
+}
+
+fn layout_span(span: &ScenarioSpan, start_ms: i64, end_ms: i64) -> SpanBar {
+    let range = (end_ms - start_ms).max(1) as f64;
+    let exit_ms = span.exited_at_ms.unwrap_or(end_ms);
+    let left_pct = ((span.entered_at_ms - start_ms) as f64 / range * 100.0).clamp(0.0, 100.0);
+    let width_pct = ((exit_ms - span.entered_at_ms) as f64 / range * 100.0)
+        .max(MIN_SPAN_WIDTH_PCT)
+        .min(100.0 - left_pct);
+    let class = if span.exited_at_ms.is_some() {
+        format!(
+            "absolute top-0.5 bottom-0.5 rounded opacity-60 {}",
+            scenario_color(&span.scenario)
+        )
+    } else {
+        format!(
+            "absolute top-0.5 bottom-0.5 rounded ring-2 ring-slate-900 {}",
+            scenario_color(&span.scenario)
+        )
+    };
+    let exit_label = span
+        .exited_at_ms
+        .map(format_clock)
+        .unwrap_or_else(|| "仍在栈中".to_string());
+    SpanBar {
+        left_pct,
+        width_pct,
+        class,
+        title: format!(
+            "{} · 深度 {} · {} → {} · 触发事件 {}",
+            span.scenario,
+            span.depth,
+            format_clock(span.entered_at_ms),
+            exit_label,
+            span.trigger_event_id
+        ),
+    }
+}
+
+/// 自主会话场景栈时间线
+#[component]
+pub fn ScenarioStackTimeline(orchestration_id: String, active: bool) -> Element {
+    let state_store = use_app_state();
+    let actions = use_app_actions();
+    let mut refresh = use_signal(|| 0u32);
+
+    let stack_state = use_scenario_stack(Some(orchestration_id.clone()), active, *refresh.read());
+    let state = stack_state.read();
+
+    let timeline_ids: HashSet<u64> = state_store
+        .read()
+        .timeline
+        .events
+        .iter()
+        .map(|event| event.event_id.as_u64())
+        .collect();
+
+    let refreshed_label = state
+        .last_refreshed_at_ms
+        .map(format_clock)
+        .unwrap_or_else(|| "-".to_string());
+
+    let header = rsx! {
+        div { class: "flex items-center justify-between",
+            div { class: "flex flex-col",
+                h3 { class: "text-sm font-semibold text-slate-800", "场景栈" }
+                span { class: "text-[11px] text-slate-400", "最近刷新 {refreshed_label}" }
+            }
+            button {
+                r#type: "button",
+                class: "rounded border border-slate-300 px-2 py-1 text-xs text-slate-600 hover:bg-slate-100 disabled:opacity-50",
+                disabled: state.loading,
+                onclick: move |_| {
+                    let next = refresh.peek().wrapping_add(1);
+                    refresh.set(next);
+                },
+                {if state.loading { "刷新中…" } else { "刷新" }}
+            }
+        }
+    };
+
+    let Some(stack) = state.stack.clone() else {
+        let placeholder = if let Some(err) = state.error.clone() {
+            rsx! { p { class: "text-xs text-red-600", "{err}" } }
+        } else if state.loading {
+            rsx! { p { class: "text-xs text-slate-500", "正在加载场景栈..." } }
+        } else {
+            rsx! { p { class: "text-xs text-slate-500 italic", "暂无场景栈数据" } }
+        };
+        return rsx! {
+            div { class: "rounded-lg border border-slate-200 bg-white p-4 space-y-3",
+                {header}
+                {placeholder}
+            }
+        };
+    };
+
+    let spans = state.spans.clone();
+    let error = state.error.clone();
+    let start_ms = spans
+        .iter()
+        .map(|span| span.entered_at_ms)
+        .min()
+        .unwrap_or_default();
+    let end_ms = spans
+        .iter()
+        .filter_map(|span| span.exited_at_ms)
+        .chain(state.last_refreshed_at_ms)
+        .max()
+        .unwrap_or(start_ms)
+        .max(start_ms);
+    drop(state);
+
+    // 每个深度占一条泳道，浅层在上，嵌套的子场景落在父场景下方
+    let min_depth = spans.iter().map(|span| span.depth).min().unwrap_or(0);
+    let max_depth = spans.iter().map(|span| span.depth).max().unwrap_or(0);
+    let lanes: Vec<(u32, Vec<SpanBar>)> = (min_depth..=max_depth)
+        .map(|depth| {
+            let bars = spans
+                .iter()
+                .filter(|span| span.depth == depth)
+                .map(|span| layout_span(span, start_ms, end_ms))
+                .collect();
+            (depth, bars)
+        })
+        .collect();
+    let lane_style = format!("height: {LANE_HEIGHT_PX}px");
+    let start_label = format_clock(start_ms);
+    let end_label = format_clock(end_ms);
+
+    let closed_count = spans
+        .iter()
+        .filter(|span| span.exited_at_ms.is_some())
+        .count();
+
+    let mut frequencies: Vec<(String, u32)> = stack
+        .stats
+        .scenario_counts
+        .iter()
+        .map(|(scenario, count)| (scenario.clone(), *count))
+        .collect();
+    frequencies.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    let max_frequency = frequencies
+        .iter()
+        .map(|(_, count)| *count)
+        .max()
+        .unwrap_or(0)
+        .max(1);
+
+    let path_label = if stack.path.is_empty() {
+        "（空栈）".to_string()
+    } else {
+        stack.path.clone()
+    };
+    let unbalanced_note = format!(
+        "入栈 {} 次 / 出栈 {} 次，当前深度 {}",
+        stack.stats.total_pushes, stack.stats.total_pops, stack.depth
+    );
+
+    rsx! {
+        div { class: "rounded-lg border border-slate-200 bg-white p-4 space-y-3",
+            {header}
+
+            if let Some(err) = error {
+                p { class: "text-xs text-red-600", "{err}" }
+            }
+
+            if !stack.is_balanced {
+                div { class: "rounded border border-red-200 bg-red-50 p-2 text-xs text-red-700",
+                    p { class: "font-semibold", "场景栈不平衡" }
+                    p { "{unbalanced_note}" }
+                }
+            }
+
+            div { class: "space-y-1 text-xs",
+                p { class: "text-slate-500", "当前路径" }
+                p { class: "font-mono text-slate-800 break-all", "{path_label}" }
+            }
+
+            div { class: "grid grid-cols-4 gap-2",
+                div { class: "text-center p-2 bg-slate-50 rounded",
+                    p { class: "text-sm font-bold text-blue-600", "{stack.stats.total_pushes}" }
+                    p { class: "text-xs text-slate-500", "入栈" }
+                }
+                div { class: "text-center p-2 bg-slate-50 rounded",
+                    p { class: "text-sm font-bold text-green-600", "{stack.stats.total_pops}" }
+                    p { class: "text-xs text-slate-500", "出栈" }
+                }
+                div { class: "text-center p-2 bg-slate-50 rounded",
+                    p { class: "text-sm font-bold text-purple-600", "{stack.depth}" }
+                    p { class: "text-xs text-slate-500", "当前深度" }
+                }
+                div { class: "text-center p-2 bg-slate-50 rounded",
+                    p { class: "text-sm font-bold text-amber-600", "{stack.stats.max_depth_reached}" }
+                    p { class: "text-xs text-slate-500", "最大深度" }
+                }
+            }
+
+            // 嵌套区间时间线
+            div { class: "space-y-1",
+                p { class: "text-xs font-medium text-slate-600", "入栈 / 出栈时间线" }
+                if spans.is_empty() {
+                    p { class: "text-xs text-slate-500 italic", "尚未观测到场景入栈" }
+                } else {
+                    div { class: "space-y-0.5",
+                        for (depth, bars) in lanes.into_iter() {
+                            div { class: "flex items-center gap-2",
+                                span { class: "w-8 shrink-0 text-right font-mono text-[11px] text-slate-400", "L{depth}" }
+                                div { class: "relative flex-1 rounded bg-slate-50", style: "{lane_style}",
+                                    for bar in bars.into_iter() {
+                                        div {
+                                            class: "{bar.class}",
+                                            style: format!("left: {:.2}%; width: {:.2}%", bar.left_pct, bar.width_pct),
+                                            title: "{bar.title}",
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    div { class: "flex justify-between pl-10 font-mono text-[11px] text-slate-400",
+                        span { "{start_label}" }
+                        span { "{end_label}" }
+                    }
+                    p { class: "text-[11px] text-slate-400",
+                        "已观测 {spans.len()} 段（{closed_count} 段已出栈）；出栈时间为控制台轮询时刻，打开面板前已出栈的场景仅计入统计"
+                    }
+                }
+            }
+
+            // 区间明细与触发事件
+            if !spans.is_empty() {
+                div { class: "space-y-1 max-h-48 overflow-y-auto",
+                    for span in spans.iter().rev() {
+                        {
+                            let color = scenario_color(&span.scenario);
+                            let entered = format_clock(span.entered_at_ms);
+                            let (exited, duration) = match span.exited_at_ms {
+                                Some(exit_ms) => (
+                                    format_clock(exit_ms),
+                                    format_span_duration(exit_ms - span.entered_at_ms),
+                                ),
+                                None => (
+                                    "仍在栈中".to_string(),
+                                    format_span_duration(end_ms - span.entered_at_ms),
+                                ),
+                            };
+                            rsx! {
+                                div { class: "flex flex-wrap items-center gap-2 rounded bg-slate-50 p-1 text-xs",
+                                    span { class: "h-2 w-2 rounded-full {color}" }
+                                    span { class: "font-medium text-slate-800", "{span.scenario}" }
+                                    span { class: "font-mono text-slate-400", "L{span.depth}" }
+                                    span { class: "text-slate-500", "{entered} → {exited}（{duration}）" }
+                                    span { class: "ml-auto flex items-center gap-1 text-[11px]",
+                                        span { class: "text-slate-400", "触发事件" }
+                                        {render_related_event(&span.trigger_event_id, &timeline_ids, &actions)}
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            // 场景频次
+            div { class: "space-y-1",
+                p { class: "text-xs font-medium text-slate-600", "场景频次" }
+                if frequencies.is_empty() {
+                    p { class: "text-xs text-slate-500 italic", "暂无统计" }
+                } else {
+                    for (scenario, count) in frequencies.iter() {
+                        {
+                            let width = f64::from(*count) / f64::from(max_frequency) * 100.0;
+                            let bar_class = format!("h-2 rounded {}", scenario_color(scenario));
+                            rsx! {
+                                div { class: "flex items-center gap-2 text-xs",
+                                    span { class: "w-28 shrink-0 truncate text-slate-700", title: "{scenario}", "{scenario}" }
+                                    div { class: "h-2 flex-1 rounded bg-slate-100",
+                                        div { class: "{bar_class}", style: "width: {width:.1}%" }
+                                    }
+                                    span { class: "w-8 text-right font-mono text-slate-500", "{count}" }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,56 @@
+//! 后端严重度字符串（critical / high / medium / low）的统一解析与展示
+
+/// 严重度等级，按从重到轻排序；无法识别的取值归为 `Unknown` 并排在最后
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) enum Severity {
+    Critical,
+    High,
+    Medium,
+    Low,
+    Unknown,
+}
+
+impl Severity {
+    /// 后端约定的全部已知等级，自上而下严重度递减
+    pub(crate) const KNOWN: [Severity; 4] = [
+        Severity::Critical,
+        Severity::High,
+        Severity::Medium,
+        Severity::Low,
+    ];
+
+    pub(crate) fn parse(value: &str) -> Self {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "critical" => Severity::Critical,
+            "high" => Severity::High,
+            "medium" => Severity::Medium,
+            "low" => Severity::Low,
+            _ => Severity::Unknown,
+        }
+    }
+
+    /// 从 0 开始的序号，与 `KNOWN` 下标一致，`Unknown` 紧随其后
+    pub(crate) fn rank(self) -> usize {
+        self as usize
+    }
+
+    pub(crate) fn label(self) -> &'static str {
+        match self {
+            Severity::Critical => "严重",
+            Severity::High => "高",
+            Severity::Medium => "中",
+            Severity::Low => "低",
+            Severity::Unknown => "未知",
+        }
+    }
+
+    pub(crate) fn badge_class(self) -> &'static str {
+        match self {
+            Severity::Critical => "bg-red-600 text-white",
+            Severity::High => "bg-red-100 text-red-700",
+            Severity::Medium => "bg-amber-100 text-amber-700",
+            Severity::Low => "bg-slate-100 text-slate-600",
+            Severity::Unknown => "bg-slate-100 text-slate-500",
+        }
+    }
+}
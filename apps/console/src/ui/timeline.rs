@@ -6,6 +6,7 @@ use crate::state::{
     normalize_filter_value, to_snake_case, use_app_actions, use_app_state, AppActions,
     AuditActionKind, TimelineFilters, TimelineState,
 };
+use crate::ui::metacognition::open_causal_chain;
 use crate::APP_CONFIG;
 use dioxus::prelude::*;
 use serde_json::{json, Value};
@@ -226,7 +227,12 @@ pub fn TimelineView() -> Element {
                 tags,
                 actions: actions.clone(),
             }
-            AwarenessColumn { awareness, filters }
+            AwarenessColumn {
+                awareness,
+                filters,
+                focused_event_id: timeline.focused_event_id,
+                actions: actions.clone(),
+            }
         }
     }
 }
@@ -590,16 +596,33 @@ fn TimelineColumn(props: TimelineColumnProps) -> Element {
     let tags_map = props.tags.clone();
     let actions = props.actions.clone();
 
+    let focused_event_id = timeline.focused_event_id;
+    let actions_unfocus = actions.clone();
+
+    // 被定位的事件即使不满足当前筛选条件也保留展示
     let filtered_events: Vec<_> = timeline
         .events
         .iter()
-        .filter(|event| filters.matches_event(event))
+        .filter(|event| {
+            filters.matches_event(event) || focused_event_id == Some(event.event_id.as_u64())
+        })
         .cloned()
         .collect();
 
     rsx! {
         div { class: "space-y-3",
             h2 { class: "text-sm font-semibold text-slate-800", "对话事件" }
+            if let Some(event_id) = focused_event_id {
+                div { class: "flex items-center gap-2 rounded bg-emerald-50 px-2 py-1 text-[11px] text-emerald-700",
+                    span { "已定位事件 #{event_id}，不受筛选条件影响" }
+                    button {
+                        r#type: "button",
+                        class: "ml-auto rounded border border-emerald-300 px-2 py-0.5 hover:bg-emerald-100",
+                        onclick: move |_| actions_unfocus.focus_timeline_event(None),
+                        "取消定位"
+                    }
+                }
+            }
             if timeline.is_loading {
                 p { class: "text-xs text-slate-500", "正在加载时间线..." }
             }
@@ -613,6 +636,8 @@ fn TimelineColumn(props: TimelineColumnProps) -> Element {
                     for event in filtered_events.iter() {
                         li {
                             key: format!("event-{}", event.event_id.as_u64()),
+                            id: format!("timeline-event-{}", event.event_id.as_u64()),
+                            class: if focused_event_id == Some(event.event_id.as_u64()) { "rounded-lg ring-2 ring-emerald-400" } else { "" },
                             EventCard {
                                 event: event.clone(),
                                 tags: {
@@ -711,11 +736,21 @@ fn EventCard(props: EventCardProps) -> Element {
         }
     };
 
+    let chain_actions = actions.clone();
+
     rsx! {
         div { class: "rounded-lg border border-slate-200 bg-white p-3 shadow-sm space-y-2",
             div { class: "flex items-center justify-between",
                 span { class: "text-sm font-medium text-slate-900", "{event_type}" }
-                span { class: "text-xs text-slate-500", "#{event_id} · {timestamp_ms}" }
+                div { class: "flex items-center gap-2",
+                    span { class: "text-xs text-slate-500", "#{event_id} · {timestamp_ms}" }
+                    button {
+                        class: "rounded border border-slate-300 px-2 py-0.5 text-[11px] text-slate-700 hover:bg-slate-100",
+                        title: "在因果链探索中查看该事件",
+                        onclick: move |_| open_causal_chain(&chain_actions, event_id.to_string()),
+                        "因果链"
+                    }
+                }
             }
             div { class: "flex flex-wrap gap-2 text-xs text-slate-600",
                 span { class: "rounded bg-slate-100 px-2 py-1 text-slate-700", "{scenario_label}" }
@@ -806,6 +841,8 @@ fn EventTagPill(props: EventTagPillProps) -> Element {
 struct AwarenessColumnProps {
     awareness: Vec<AwarenessEvent>,
     filters: TimelineFilters,
+    focused_event_id: Option<u64>,
+    actions: AppActions,
 }
 
 impl PartialEq for AwarenessColumnProps {
@@ -821,7 +858,10 @@ fn AwarenessColumn(props: AwarenessColumnProps) -> Element {
     let filtered: Vec<_> = props
         .awareness
         .iter()
-        .filter(|item| props.filters.matches_awareness(item))
+        .filter(|item| {
+            props.filters.matches_awareness(item)
+                || props.focused_event_id == Some(item.event_id.as_u64())
+        })
         .cloned()
         .collect();
 
@@ -833,22 +873,41 @@ fn AwarenessColumn(props: AwarenessColumnProps) -> Element {
                     li { class: "text-xs text-slate-500 italic", "当前过滤条件下暂无 Awareness 数据" }
                 } else {
                     for item in filtered.iter() {
-                        li {
-                            key: format!("awareness-{}", item.event_id.as_u64()),
-                            class: "rounded-lg border border-amber-200 bg-amber-50 p-3",
-                            div { class: "flex items-center justify-between",
-                                span { class: "text-xs font-medium text-amber-900", "{format_awareness_event_type(&item.event_type)}" }
-                                span { class: "text-[11px] text-amber-700", "#{item.event_id.as_u64()} · {item.occurred_at_ms}" }
-                            }
-                            if let Some(reason) = item
-                                .degradation_reason
-                                .as_ref()
-                                .map(|reason| format!("降级: {:?}", reason))
-                            {
-                                div { class: "mt-1 text-[11px] text-amber-700", "{reason}" }
-                            }
-                            if let Some(router_view) = render_router_insights(&collect_router_insights(&item.payload)) {
-                                {router_view}
+                        {
+                            let item_id = item.event_id.as_u64();
+                            let actions = props.actions.clone();
+                            rsx! {
+                                li {
+                                    key: "awareness-{item_id}",
+                                    id: "awareness-event-{item_id}",
+                                    class: if props.focused_event_id == Some(item_id) {
+                                        "rounded-lg border border-amber-200 bg-amber-50 p-3 ring-2 ring-emerald-400"
+                                    } else {
+                                        "rounded-lg border border-amber-200 bg-amber-50 p-3"
+                                    },
+                                    div { class: "flex items-center justify-between",
+                                        span { class: "text-xs font-medium text-amber-900", "{format_awareness_event_type(&item.event_type)}" }
+                                        div { class: "flex items-center gap-2",
+                                            span { class: "text-[11px] text-amber-700", "#{item_id} · {item.occurred_at_ms}" }
+                                            button {
+                                                class: "rounded border border-amber-300 px-2 py-0.5 text-[11px] text-amber-800 hover:bg-amber-100",
+                                                title: "在因果链探索中查看该事件",
+                                                onclick: move |_| open_causal_chain(&actions, item_id.to_string()),
+                                                "因果链"
+                                            }
+                                        }
+                                    }
+                                    if let Some(reason) = item
+                                        .degradation_reason
+                                        .as_ref()
+                                        .map(|reason| format!("降级: {:?}", reason))
+                                    {
+                                        div { class: "mt-1 text-[11px] text-amber-700", "{reason}" }
+                                    }
+                                    if let Some(router_view) = render_router_insights(&collect_router_insights(&item.payload)) {
+                                        {router_view}
+                                    }
+                                }
                             }
                         }
                     }
@@ -0,0 +1,314 @@
+use crate::models::ContextBundleView;
+use crate::ui::util::scroll_to_element;
+use dioxus::prelude::*;
+use std::collections::BTreeMap;
+
+const LEVEL_COLORS: &[&str] = &[
+    "bg-sky-300",
+    "bg-emerald-300",
+    "bg-violet-300",
+    "bg-amber-300",
+    "bg-rose-300",
+    "bg-teal-300",
+];
+/// 分区占比条使用的颜色，与汇总层级的浅色区分
+const PARTITION_COLORS: &[&str] = &[
+    "bg-blue-500",
+    "bg-emerald-500",
+    "bg-purple-500",
+    "bg-orange-500",
+    "bg-pink-500",
+    "bg-cyan-600",
+    "bg-lime-600",
+    "bg-slate-500",
+];
+const UNSUMMARIZED: &str = "原文";
+
+#[derive(Clone, PartialEq)]
+struct MapItem {
+    ci_id: String,
+    partition: String,
+    tokens: u32,
+    level: String,
+}
+
+struct MapPartition {
+    name: String,
+    tokens: u32,
+    color: &'static str,
+    items: Vec<MapItem>,
+}
+
+struct LevelBreakdown {
+    level: String,
+    count: usize,
+    tokens: u32,
+}
+
+fn level_of(summary_level: Option<&String>) -> String {
+    summary_level
+        .cloned()
+        .unwrap_or_else(|| UNSUMMARIZED.to_string())
+}
+
+fn build_partitions(bundle: &ContextBundleView) -> Vec<MapPartition> {
+    bundle
+        .segments
+        .iter()
+        .enumerate()
+        .map(|(idx, segment)| {
+            let items: Vec<MapItem> = segment
+                .items
+                .iter()
+                .map(|item| MapItem {
+                    ci_id: item.ci_id.clone(),
+                    partition: segment.partition.clone(),
+                    tokens: item.tokens,
+                    level: level_of(item.summary_level.as_ref()),
+                })
+                .collect();
+            MapPartition {
+                name: segment.partition.clone(),
+                tokens: items.iter().map(|item| item.tokens).sum(),
+                color: PARTITION_COLORS[idx % PARTITION_COLORS.len()],
+                items,
+            }
+        })
+        .collect()
+}
+
+fn build_level_breakdown(partitions: &[MapPartition]) -> Vec<LevelBreakdown> {
+    let mut levels: BTreeMap<String, (usize, u32)> = BTreeMap::new();
+    for item in partitions
+        .iter()
+        .flat_map(|partition| partition.items.iter())
+    {
+        let entry = levels.entry(item.level.clone()).or_default();
+        entry.0 += 1;
+        entry.1 += item.tokens;
+    }
+    levels
+        .into_iter()
+        .map(|(level, (count, tokens))| LevelBreakdown {
+            level,
+            count,
+            tokens,
+        })
+        .collect()
+}
+
+fn pct(part: u32, whole: u32) -> f64 {
+    if whole == 0 {
+        0.0
+    } else {
+        part as f64 / whole as f64 * 100.0
+    }
+}
+
+#[derive(Props, Clone)]
+#[props(no_eq)]
+pub struct TokenUsageMapProps {
+    bundle: ContextBundleView,
+}
+
+impl PartialEq for TokenUsageMapProps {
+    fn eq(&self, _other: &Self) -> bool {
+        false
+    }
+}
+
+impl Eq for TokenUsageMapProps {}
+
+#[component]
+pub fn TokenUsageMap(props: TokenUsageMapProps) -> Element {
+    let mut threshold_input = use_signal(String::new);
+    let mut selected = use_signal(|| Option::<MapItem>::None);
+
+    let partitions = build_partitions(&props.bundle);
+    let total: u32 = partitions.iter().map(|partition| partition.tokens).sum();
+    if total == 0 {
+        return rsx! {};
+    }
+    let target = props
+        .bundle
+        .budget
+        .as_ref()
+        .map(|budget| budget.target_tokens);
+    // 条形图刻度取用量与目标中的较大者，超出部分才能直观显示
+    let scale = target.map_or(total, |target| target.max(total));
+    let overflow = target.map(|target| total.saturating_sub(target));
+    let target_pct = target.map(|target| pct(target, scale));
+
+    let default_threshold = target.map_or(total / 10, |target| target / 10).max(1);
+    let threshold_text = threshold_input.read().clone();
+    let threshold = threshold_text
+        .trim()
+        .parse::<u32>()
+        .unwrap_or(default_threshold);
+
+    let levels = build_level_breakdown(&partitions);
+    let level_colors: BTreeMap<String, &'static str> = levels
+        .iter()
+        .enumerate()
+        .map(|(idx, level)| (level.level.clone(), LEVEL_COLORS[idx % LEVEL_COLORS.len()]))
+        .collect();
+    let heavy_count = partitions
+        .iter()
+        .flat_map(|partition| partition.items.iter())
+        .filter(|item| item.tokens >= threshold)
+        .count();
+    let current = selected.read().clone();
+
+    rsx! {
+        div { class: "rounded-lg border border-slate-200 bg-white p-4 shadow-sm space-y-3 text-xs text-slate-600",
+            header { class: "flex flex-wrap items-center justify-between gap-2",
+                h3 { class: "text-sm font-semibold text-slate-800", "Token 分布" }
+                div { class: "flex flex-wrap items-center gap-2 text-[11px]",
+                    span { "总计 {total}" }
+                    if let Some(target) = target {
+                        span { "目标 {target}" }
+                    }
+                    if let Some(over) = overflow.filter(|over| *over > 0) {
+                        span { class: "rounded bg-red-100 px-2 py-0.5 text-red-700", "超出目标 {over} tokens" }
+                    }
+                }
+            }
+
+            div { class: "relative h-4 w-full overflow-hidden rounded bg-slate-100 flex",
+                for partition in partitions.iter() {
+                    div {
+                        class: "h-4 border-r border-white {partition.color}",
+                        style: "width: {pct(partition.tokens, scale):.2}%",
+                        title: "{partition.name} · {partition.tokens} tokens",
+                    }
+                }
+                if let Some(target_pct) = target_pct {
+                    div {
+                        class: "absolute top-0 h-4 border-l-2 border-red-500",
+                        style: "left: {target_pct:.2}%",
+                        title: "目标 Token",
+                    }
+                }
+            }
+
+            div { class: "flex flex-wrap items-center gap-x-3 gap-y-1 text-[11px]",
+                for partition in partitions.iter() {
+                    span { class: "flex items-center gap-1",
+                        span { class: "inline-block h-2 w-2 rounded-sm {partition.color}" }
+                        span { class: "text-slate-700", "{partition.name}" }
+                        span { class: "font-mono text-slate-400", "{partition.tokens} · {pct(partition.tokens, total):.1}%" }
+                    }
+                }
+            }
+
+            div { class: "flex h-56 w-full gap-0.5",
+                for partition in partitions.iter().filter(|partition| partition.tokens > 0) {
+                    div {
+                        class: "flex h-full flex-col gap-0.5 overflow-hidden",
+                        style: "width: {pct(partition.tokens, total):.2}%",
+                        div { class: "flex items-center gap-1 truncate text-[10px] font-semibold text-slate-700", title: "{partition.name}",
+                            span { class: "inline-block h-2 w-2 shrink-0 rounded-sm {partition.color}" }
+                            "{partition.name}"
+                        }
+                        for item in partition.items.iter().filter(|item| item.tokens > 0) {
+                            {
+                                let color = level_colors.get(&item.level).copied().unwrap_or("bg-slate-300");
+                                let is_heavy = item.tokens >= threshold;
+                                let is_selected = current.as_ref().is_some_and(|sel| sel.ci_id == item.ci_id && sel.partition == item.partition);
+                                let ring = if is_selected {
+                                    "ring-2 ring-slate-900"
+                                } else if is_heavy {
+                                    "ring-2 ring-red-500"
+                                } else {
+                                    ""
+                                };
+                                let item_for_click = item.clone();
+                                rsx! {
+                                    button {
+                                        class: format!("min-h-[2px] w-full overflow-hidden rounded-sm text-left text-[9px] text-slate-800 {color} {ring}"),
+                                        style: "height: {pct(item.tokens, partition.tokens):.2}%",
+                                        title: "{item.ci_id} · {item.tokens} tokens · {item.level}",
+                                        onclick: move |_| selected.set(Some(item_for_click.clone())),
+                                        span { class: "px-1", "{item.ci_id}" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            div { class: "flex flex-wrap items-center gap-2 text-[11px]",
+                label { class: "text-slate-500", "高亮阈值" }
+                input {
+                    class: "w-24 rounded border border-slate-300 px-2 py-1 font-mono",
+                    r#type: "number",
+                    min: "1",
+                    placeholder: "{default_threshold}",
+                    value: "{threshold_text}",
+                    oninput: move |evt| threshold_input.set(evt.value()),
+                }
+                span { class: "text-red-600", "{heavy_count} 个条目 ≥ {threshold} tokens" }
+            }
+
+            table { class: "w-full text-left text-[11px]",
+                thead {
+                    tr { class: "text-slate-500",
+                        th { class: "py-1", "汇总层级" }
+                        th { class: "py-1", "条目" }
+                        th { class: "py-1", "Tokens" }
+                        th { class: "py-1", "占比" }
+                    }
+                }
+                tbody {
+                    for level in levels.iter() {
+                        tr { class: "border-t border-slate-100",
+                            td { class: "py-1",
+                                span { class: format!("mr-1 inline-block h-2 w-2 rounded-sm {}", level_colors.get(&level.level).copied().unwrap_or("bg-slate-300")) }
+                                "{level.level}"
+                            }
+                            td { class: "py-1 font-mono", "{level.count}" }
+                            td { class: "py-1 font-mono", "{level.tokens}" }
+                            td { class: "py-1 font-mono", "{pct(level.tokens, total):.1}%" }
+                        }
+                    }
+                }
+            }
+
+            if let Some(item) = current {
+                {
+                    let dom_id = context_item_dom_id(&item.partition, &item.ci_id);
+                    let share = target.map(|target| format!("{:.1}%", pct(item.tokens, target)));
+                    rsx! {
+                        div { class: "flex flex-wrap items-center justify-between gap-2 rounded border border-slate-200 bg-slate-50 p-3 text-[11px]",
+                            div { class: "space-y-0.5",
+                                p { class: "font-mono font-semibold text-slate-800", "{item.ci_id}" }
+                                p { "分区 {item.partition} · {item.tokens} tokens · 汇总层级 {item.level}" }
+                                if let Some(share) = share {
+                                    p { class: "text-slate-500", "占目标 {share}" }
+                                }
+                            }
+                            div { class: "flex gap-2",
+                                button {
+                                    class: "rounded border border-slate-300 px-2 py-1 text-slate-700 hover:bg-slate-100",
+                                    onclick: move |_| scroll_to_element(dom_id.clone()),
+                                    "定位条目"
+                                }
+                                button {
+                                    class: "rounded border border-slate-300 px-2 py-1 text-slate-700 hover:bg-slate-100",
+                                    onclick: move |_| selected.set(None),
+                                    "关闭"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Segments 列表中条目的 DOM id，分区名参与拼接以区分重复的 ci_id
+pub(crate) fn context_item_dom_id(partition: &str, ci_id: &str) -> String {
+    format!("context-item-{partition}-{ci_id}")
+}
@@ -0,0 +1,135 @@
+//! 多个面板共用的页面跳转、事件链接与时间格式化工具
+
+use std::collections::HashSet;
+
+use dioxus::prelude::*;
+use time::{Date, Month, PrimitiveDateTime, Time, UtcOffset};
+
+use crate::state::AppActions;
+
+/// 滚动到指定 DOM 节点，等待目标视图重新渲染后再执行
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn scroll_to_element(dom_id: String) {
+    wasm_bindgen_futures::spawn_local(async move {
+        gloo_timers::future::TimeoutFuture::new(50).await;
+        if let Some(element) = web_sys::window()
+            .and_then(|window| window.document())
+            .and_then(|document| document.get_element_by_id(&dom_id))
+        {
+            element.scroll_into_view();
+        }
+    });
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn scroll_to_element(_dom_id: String) {}
+
+/// 在时间线中定位事件并滚动到对应条目
+pub(crate) fn focus_timeline_event(actions: &AppActions, event_id: u64) {
+    actions.focus_timeline_event(Some(event_id));
+    scroll_to_element(format!("timeline-event-{event_id}"));
+}
+
+/// 打开关联事件：时间线已加载时定位过去，否则设为因果图 root
+pub(crate) fn open_related_event(actions: &AppActions, event_id: u64, in_timeline: bool) {
+    if in_timeline {
+        focus_timeline_event(actions, event_id);
+    } else {
+        actions.set_graph_root(Some(event_id));
+    }
+}
+
+/// 关联事件的链接按钮，非数字 ID 只展示不可点击
+pub(crate) fn render_related_event(
+    event_id: &str,
+    timeline_ids: &HashSet<u64>,
+    actions: &AppActions,
+) -> Element {
+    let Some(id) = event_id.parse::<u64>().ok() else {
+        return rsx! {
+            span { class: "rounded bg-slate-100 px-1.5 py-0.5 font-mono text-slate-500", "{event_id}" }
+        };
+    };
+    let in_timeline = timeline_ids.contains(&id);
+    let title = if in_timeline {
+        "在时间线中定位"
+    } else {
+        "时间线尚未加载该事件，将设为图谱 root"
+    };
+    let actions = actions.clone();
+
+    rsx! {
+        button {
+            class: "rounded bg-slate-100 px-1.5 py-0.5 font-mono text-blue-700 hover:bg-slate-200",
+            title: title,
+            onclick: move |_| open_related_event(&actions, id, in_timeline),
+            "#{id}"
+        }
+    }
+}
+
+/// 以浏览器本地时区格式化为 `HH:MM:SS`
+pub(crate) fn format_clock(ms: i64) -> String {
+    let date = web_sys::js_sys::Date::new(&wasm_bindgen::JsValue::from_f64(ms as f64));
+    format!(
+        "{:02}:{:02}:{:02}",
+        date.get_hours(),
+        date.get_minutes(),
+        date.get_seconds()
+    )
+}
+
+/// 解析 `YYYY-MM-DDTHH:MM[:SS[.fff]]` 及可选的 `Z` / `±HH:MM` 时区后缀，换算为毫秒。
+/// 既用于 RFC3339 字符串，也用于 `datetime-local` 输入框（无时区后缀时按 UTC）
+pub(crate) fn parse_datetime_ms(value: &str) -> Option<i64> {
+    let (date_part, rest) = value.trim().split_once(['T', 't', ' '])?;
+
+    let mut date_fields = date_part.splitn(3, '-');
+    let year: i32 = date_fields.next()?.parse().ok()?;
+    let month: u8 = date_fields.next()?.parse().ok()?;
+    let day: u8 = date_fields.next()?.parse().ok()?;
+    let date = Date::from_calendar_date(year, Month::try_from(month).ok()?, day).ok()?;
+
+    let (time_part, offset) = split_utc_offset(rest)?;
+    let mut time_fields = time_part.splitn(3, ':');
+    let hour: u8 = time_fields.next()?.parse().ok()?;
+    let minute: u8 = time_fields.next()?.parse().ok()?;
+    let (second, nanos) = match time_fields.next() {
+        Some(seconds) => {
+            let (whole, fraction) = seconds.split_once('.').unwrap_or((seconds, ""));
+            let fraction: String = fraction.chars().take(9).collect();
+            let nanos = if fraction.is_empty() {
+                0
+            } else {
+                format!("{fraction:0<9}").parse().ok()?
+            };
+            (whole.parse().ok()?, nanos)
+        }
+        None => (0, 0),
+    };
+    let time = Time::from_hms_nano(hour, minute, second, nanos).ok()?;
+
+    let nanos = PrimitiveDateTime::new(date, time)
+        .assume_offset(offset)
+        .unix_timestamp_nanos();
+    Some((nanos / 1_000_000) as i64)
+}
+
+/// 拆出时间部分末尾的时区后缀，缺省为 UTC
+fn split_utc_offset(value: &str) -> Option<(&str, UtcOffset)> {
+    if let Some(time_part) = value.strip_suffix(['Z', 'z']) {
+        return Some((time_part, UtcOffset::UTC));
+    }
+    let Some(sign_at) = value.rfind(['+', '-']) else {
+        return Some((value, UtcOffset::UTC));
+    };
+    let (time_part, offset_part) = value.split_at(sign_at);
+    let sign: i8 = if offset_part.starts_with('-') { -1 } else { 1 };
+    let (hours, minutes) = offset_part[1..]
+        .split_once(':')
+        .unwrap_or((&offset_part[1..], "0"));
+    let hours: i8 = hours.parse().ok()?;
+    let minutes: i8 = minutes.parse().ok()?;
+    let offset = UtcOffset::from_hms(sign * hours, sign * minutes, 0).ok()?;
+    Some((time_part, offset))
+}